@@ -1,9 +1,20 @@
-use crate::api::client::ReplyRef;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::client::{BlueskyClient, ImageAttachment, QuoteRef, ReplyRef};
+use crate::drafts::Draft;
+use crate::models::chat::{ConvoViewModel, MessageViewModel};
+use crate::models::feed::{FeedSource, SavedFeed};
+use crate::models::follows::FollowListKind;
+use crate::models::lists::ListViewModel;
+use crate::models::moderation::{ModerationPrefs, MutedWord, Report};
+use crate::models::notification::NotificationViewModel;
 use crate::models::post::PostViewModel;
-use crate::models::profile::ProfileViewModel;
+use crate::models::profile::{AuthorFeedTab, ProfileViewModel};
 use crate::models::thread::ThreadViewModel;
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum Action {
     Quit,
     Tick,
@@ -25,33 +36,135 @@ pub enum Action {
         handle: String,
         password: String,
     },
-    LoginSuccess(String),
+    SubmitOAuthLogin {
+        handle: String,
+    },
     LoginFailed(String),
     Logout,
 
+    // Accounts
+    ShowAccountSwitcher,
+    CloseAccountSwitcher,
+    AddAccount,
+    SwitchAccount(String),
+    AccountSwitched {
+        client: Arc<BlueskyClient>,
+        handle: String,
+    },
+
     // Timeline
     RefreshTimeline,
     LoadMoreTimeline,
     TimelineLoaded {
+        /// Matched by source, as `SavedFeedsLoaded` may replace the feed ids meanwhile.
+        source: FeedSource,
         posts: Vec<PostViewModel>,
         cursor: Option<String>,
         append: bool,
     },
 
+    // Feeds
+    LoadSavedFeeds,
+    SavedFeedsLoaded(Vec<SavedFeed>),
+    NextFeed,
+    PrevFeed,
+    SwitchFeed(String),
+    ShowFeedPicker,
+    CloseFeedPicker,
+    ToggleFeedPin(String),
+    FeedPinUpdated {
+        id: String,
+        pinned: bool,
+    },
+
+    // Notifications
+    RefreshNotifications,
+    LoadMoreNotifications,
+    NotificationsLoaded {
+        notifications: Vec<NotificationViewModel>,
+        subjects: Vec<PostViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    RefreshUnreadCount,
+    UnreadCountLoaded(i64),
+
+    // Search
+    ShowSearchPrompt,
+    CloseSearchPrompt,
+    SubmitSearch(String),
+    ToggleSearchMode,
+    ToggleSearchSort,
+    LoadMoreSearch,
+    SearchPostsLoaded {
+        posts: Vec<PostViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    SearchActorsLoaded {
+        actors: Vec<ProfileViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+
     // Thread
-    ThreadLoaded(Option<ThreadViewModel>),
+    ThreadLoaded(ThreadViewModel),
+    /// The thread couldn't be loaded, or its focal post is deleted or blocked.
+    ThreadFailed(String),
+    /// Collapse/expand the selected reply, fetching its replies past the depth limit.
+    ToggleReplies,
+    RepliesLoaded {
+        uri: String,
+        subtree: Option<ThreadViewModel>,
+    },
 
     // Composer
     OpenComposer {
         reply_to: Option<ReplyRef>,
         reply_to_author: Option<String>,
     },
-    CloseComposer,
+    QuotePost,
+    CloseComposer {
+        save_draft: bool,
+    },
+    /// Suspend the TUI and edit the draft in `$VISUAL`/`$EDITOR`.
+    OpenExternalEditor,
+    /// One post, or a thread when there is more than one segment.
     SubmitPost {
-        text: String,
+        segments: Vec<String>,
         reply_to: Option<ReplyRef>,
+        images: Vec<ImageAttachment>,
+        quote: Option<QuoteRef>,
+        /// Handles picked from autocomplete, mapped to their DIDs.
+        mentions: HashMap<String, String>,
+        /// Self-label value for the first post, e.g. `graphic-media`.
+        self_label: Option<String>,
     },
     PostCreated(String),
+    /// Segment `posted` of a thread failed; earlier ones are live and
+    /// `reply_to` points at the last of them.
+    PostFailed {
+        posted: usize,
+        reply_to: Option<ReplyRef>,
+        error: String,
+        /// The whole submission, kept in case another draft is open by now.
+        draft: Draft,
+    },
+    MentionTypeahead(String),
+    MentionSuggestions {
+        query: String,
+        actors: Vec<ProfileViewModel>,
+    },
+
+    DeletePost,
+    ConfirmDeletePost(String),
+    PostDeleted(String),
+
+    // Drafts
+    ShowDrafts,
+    CloseDrafts,
+    OpenDraft(String),
+    DeleteDraft(String),
 
     // Interactions
     ToggleLike,
@@ -78,10 +191,169 @@ pub enum Action {
         posts: Vec<PostViewModel>,
         cursor: Option<String>,
     },
+    SwitchProfileTab(AuthorFeedTab),
+    ProfileFeedLoaded {
+        actor: String,
+        tab: AuthorFeedTab,
+        posts: Vec<PostViewModel>,
+        cursor: Option<String>,
+    },
+    ShowFollowList(FollowListKind),
+    LoadMoreFollowList,
+    FollowListLoaded {
+        actor: String,
+        kind: FollowListKind,
+        actors: Vec<ProfileViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    ToggleFollow,
+    ToggleMute,
+    ToggleBlock,
+    ConfirmBlock(String),
+    /// Follow state for `did` after a request, or the state to revert to
+    /// when it failed.
+    FollowUpdated {
+        did: String,
+        following: bool,
+        uri: Option<String>,
+    },
+    MuteUpdated {
+        did: String,
+        muted: bool,
+    },
+    BlockUpdated {
+        did: String,
+        blocking: bool,
+        uri: Option<String>,
+    },
+
+    // Lists
+    /// Open the lists of the profile on screen, or our own.
+    ShowLists,
+    LoadMoreLists,
+    ListsLoaded {
+        actor: String,
+        lists: Vec<ListViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    OpenList,
+    ListLoaded {
+        list: ListViewModel,
+        members: Vec<ProfileViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    LoadMoreListMembers,
+    LoadMoreListFeed,
+    ListFeedLoaded {
+        uri: String,
+        posts: Vec<PostViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    /// Subscribe to or unsubscribe from the open moderation list as a mute list.
+    ToggleListMute,
+    ConfirmListMute(String),
+    ListMuteUpdated {
+        uri: String,
+        muted: bool,
+    },
+    /// Subscribe to or unsubscribe from the open moderation list as a block list.
+    ToggleListBlock,
+    ConfirmListBlock(String),
+    ListBlockUpdated {
+        uri: String,
+        blocking_uri: Option<String>,
+    },
+    /// Add the selected post's author, or the profile's account, to our lists.
+    ShowListPicker,
+    CloseListPicker,
+    ListMembershipsLoaded {
+        did: String,
+        lists: Vec<(ListViewModel, Option<String>)>,
+    },
+    ToggleListMembership(String),
+    /// `item_uri` is the `listitem` record, or `None` once removed.
+    ListMembershipUpdated {
+        did: String,
+        list_uri: String,
+        item_uri: Option<String>,
+    },
+    ListMembershipFailed {
+        list_uri: String,
+        error: String,
+    },
+
+    // Messages
+    RefreshConvos,
+    LoadMoreConvos,
+    ConvosLoaded {
+        convos: Vec<ConvoViewModel>,
+        cursor: Option<String>,
+        append: bool,
+    },
+    RefreshChatUnreadCount,
+    ChatUnreadCountLoaded(i64),
+    /// A failed chat call; scope denials are reported once and stop the polls.
+    ChatError(String),
+    OpenConvo,
+    LoadOlderMessages,
+    /// Fetch the newest page of the open conversation.
+    PollConversation,
+    MessagesLoaded {
+        convo_id: String,
+        messages: Vec<MessageViewModel>,
+        cursor: Option<String>,
+        older: bool,
+    },
+    ShowMessageInput,
+    CloseMessageInput,
+    SendMessage(String),
+    MessageSent {
+        convo_id: String,
+        message: MessageViewModel,
+    },
+    MessageFailed {
+        convo_id: String,
+        text: String,
+        error: String,
+    },
+    ToggleConvoMute,
+    ConvoMuteUpdated {
+        convo_id: String,
+        muted: bool,
+    },
+    LeaveConvo,
+    ConfirmLeaveConvo(String),
+    ConvoLeft(String),
+
+    // Moderation
+    LoadModerationPrefs,
+    ModerationPrefsLoaded(ModerationPrefs),
+    /// Show or re-hide the selected post filtered by moderation preferences.
+    ToggleFilteredPost,
+    ShowMutedWords,
+    ShowMutedWordPrompt,
+    CloseMutedWordPrompt,
+    AddMutedWord(MutedWord),
+    RemoveMutedWord,
+    ConfirmRemoveMutedWord(MutedWord),
+    MutedWordsSaved(Vec<MutedWord>),
+    /// Open the report modal for the selected post, or the profile's account.
+    ShowReport,
+    CloseReport,
+    SubmitReport(Report),
+    ReportSubmitted,
+    ReportFailed(String),
 
     // About
     ShowAbout,
 
+    /// Dismiss the yes/no dialog without acting.
+    CloseConfirm,
+
     // Errors
     Error(String),
     ClearError,
@@ -1,24 +1,32 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use tracing::{info, warn};
 
 use super::client::BlueskyClient;
-use super::session::{self, SessionData};
+use super::dpop::DpopKeyPair;
+use super::identity;
+use super::oauth::{OAuthFlow, OAuthSession};
+use super::session::{self, OAuthSessionData, SessionData};
 
 pub enum AuthResult {
     Success(String),
     NeedsLogin,
 }
 
-pub async fn try_restore_session(client: &BlueskyClient) -> AuthResult {
-    match session::load_session() {
+/// Restore the account named by `preferred` (handle or DID) if it is saved,
+/// otherwise the most recently used account.
+pub async fn try_restore_session(client: &BlueskyClient, preferred: Option<&str>) -> AuthResult {
+    let saved = match preferred {
+        Some(identifier) => session::find_account(identifier),
+        None => session::load_session(),
+    };
+
+    match saved {
         Ok(Some(session_data)) => {
             info!("Found saved session for {}", session_data.handle);
-            match client
-                .login_app_password(&session_data.handle, &session_data.access_jwt)
-                .await
-            {
+            match restore_session(client, &session_data).await {
                 Ok(_) => {
                     info!("Session restored for {}", session_data.handle);
+                    let _ = session::set_active_account(&session_data.did);
                     AuthResult::Success(session_data.handle)
                 }
                 Err(e) => {
@@ -38,22 +46,102 @@ pub async fn try_restore_session(client: &BlueskyClient) -> AuthResult {
     }
 }
 
+/// Resume the saved account `did` on `client` and make it the active account.
+pub async fn switch_account(client: &BlueskyClient, did: &str) -> Result<String> {
+    let session_data = session::find_account(did)?
+        .ok_or_else(|| anyhow!("No saved account for {}", did))?;
+    restore_session(client, &session_data).await?;
+    session::set_active_account(&session_data.did)?;
+    Ok(session_data.handle)
+}
+
+async fn restore_session(client: &BlueskyClient, session_data: &SessionData) -> Result<()> {
+    match session_data.oauth {
+        Some(ref oauth_data) => restore_oauth_session(client, session_data, oauth_data).await,
+        None if session_data.refresh_jwt.is_empty() => {
+            // Older versions stored the app password itself in `access_jwt`
+            warn!("Discarding legacy session without a refresh token");
+            session::remove_account(&session_data.did)?;
+            Err(anyhow!("Saved session has expired, please sign in again"))
+        }
+        None => client.resume_session(session_data).await,
+    }
+}
+
+async fn restore_oauth_session(
+    client: &BlueskyClient,
+    session_data: &SessionData,
+    oauth_data: &OAuthSessionData,
+) -> Result<()> {
+    let oauth_session = OAuthSession {
+        did: session_data.did.clone(),
+        dpop: DpopKeyPair::from_secret(&oauth_data.dpop_key)?,
+        access_token: session_data.access_jwt.clone(),
+        refresh_token: Some(session_data.refresh_jwt.clone()).filter(|t| !t.is_empty()),
+        token_endpoint: oauth_data.token_endpoint.clone(),
+        auth_nonce: None,
+        pds_nonce: None,
+    };
+    let pds_endpoint = match session_data.pds_endpoint {
+        Some(ref endpoint) => endpoint.clone(),
+        None => identity::resolve_pds_endpoint(&session_data.did).await?,
+    };
+
+    client
+        .login_oauth(
+            &session_data.handle,
+            &session_data.did,
+            &pds_endpoint,
+            oauth_session,
+        )
+        .await
+}
+
+pub async fn login_with_oauth(client: &BlueskyClient, handle: &str) -> Result<String> {
+    let handle = handle.trim_start_matches('@');
+    let mut flow = OAuthFlow::start(handle, client.service()).await?;
+    let code = flow.authorize().await?;
+    let token = flow.exchange_code(&code).await?;
+
+    let did = token.did.clone();
+    let pds_endpoint = flow.pds_endpoint().to_string();
+    let oauth_session = flow.into_session(token);
+
+    let session_data = SessionData {
+        did: did.clone(),
+        handle: handle.to_string(),
+        access_jwt: oauth_session.access_token.clone(),
+        refresh_jwt: oauth_session.refresh_token.clone().unwrap_or_default(),
+        pds_endpoint: Some(pds_endpoint.clone()),
+        oauth: Some(OAuthSessionData {
+            dpop_key: oauth_session.dpop.secret(),
+            token_endpoint: oauth_session.token_endpoint.clone(),
+        }),
+    };
+
+    client
+        .login_oauth(handle, &did, &pds_endpoint, oauth_session)
+        .await?;
+    session::save_session(&session_data)?;
+
+    Ok(handle.to_string())
+}
+
 pub async fn login_with_app_password(
     client: &BlueskyClient,
     identifier: &str,
     password: &str,
 ) -> Result<String> {
-    client.login_app_password(identifier, password).await?;
-
-    let did = client.did().await.unwrap_or_default();
-    let handle = identifier.to_string();
+    let session = client.login_app_password(identifier, password).await?;
 
+    let handle = session.handle.to_string();
     let session_data = SessionData {
-        did,
+        did: session.did.to_string(),
         handle: handle.clone(),
-        access_jwt: password.to_string(),
-        refresh_jwt: String::new(),
-        pds_endpoint: None,
+        access_jwt: session.access_jwt.clone(),
+        refresh_jwt: session.refresh_jwt.clone(),
+        pds_endpoint: Some(client.endpoint().await),
+        oauth: None,
     };
     session::save_session(&session_data)?;
 
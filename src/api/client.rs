@@ -1,23 +1,127 @@
-use anyhow::Result;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use atrium_api::agent::atp_agent::AtpSession;
+use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+use atrium_api::agent::Configure;
+use atrium_api::app::bsky::actor::defs::{
+    MutedWordsPrefData, Preferences, PreferencesItem, SavedFeedData, SavedFeedsPrefV2Data,
+};
+use atrium_api::types::Union;
+use bsky_sdk::agent::BskyAtpAgentBuilder;
 use bsky_sdk::BskyAgent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use atrium_api::types::string::Datetime;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use super::identity;
+use super::oauth::OAuthSession;
+use super::session::{FileSessionStore, SessionData};
+use super::xrpc::XrpcHttpClient;
 
+use crate::models::chat::{ConvoViewModel, MessageViewModel};
+use crate::models::feed::{FeedSource, FeedState, SavedFeed};
+use crate::models::lists::{ListPurpose, ListViewModel};
+use crate::models::moderation::{
+    LabelDefinition, ModerationPrefs, ModerationService, MutedWord, Report, ReportSubject,
+    BSKY_LABELER_DID,
+};
+use crate::models::notification::NotificationViewModel;
 use crate::models::post::PostViewModel;
-use crate::models::profile::ProfileViewModel;
-use crate::models::thread::ThreadViewModel;
+use crate::models::profile::{AuthorFeedTab, ProfileViewModel};
+use crate::models::search::{SearchQuery, SearchSort};
+use crate::models::thread::{ThreadViewModel, Unavailable, THREAD_DEPTH};
 
 pub struct BlueskyClient {
-    agent: BskyAgent,
+    agent: BskyAgent<XrpcHttpClient, FileSessionStore>,
+    oauth: Arc<RwLock<Option<OAuthSession>>>,
+    service: String,
 }
 
 impl BlueskyClient {
-    pub async fn new() -> Result<Self> {
-        let agent = BskyAgent::builder().build().await?;
-        Ok(BlueskyClient { agent })
+    /// `service` is the PDS or entryway used before a session pins the user's own PDS.
+    pub async fn new(service: &str) -> Result<Self> {
+        let service = service.trim_end_matches('/').to_string();
+        let oauth = Arc::new(RwLock::new(None));
+        let http = XrpcHttpClient::new(&service, oauth.clone());
+        let agent = BskyAtpAgentBuilder::new(http)
+            .config(bsky_sdk::agent::config::Config {
+                endpoint: service.clone(),
+                ..Default::default()
+            })
+            .store(FileSessionStore::default())
+            .build()
+            .await?;
+        Ok(BlueskyClient {
+            agent,
+            oauth,
+            service,
+        })
+    }
+
+    pub fn service(&self) -> &str {
+        &self.service
     }
 
-    pub async fn login_app_password(&self, identifier: &str, password: &str) -> Result<()> {
-        self.agent.login(identifier, password).await?;
+    /// Log in against the identifier's own PDS when it resolves, otherwise against the
+    /// configured service (e.g. when signing in with an email address).
+    pub async fn login_app_password(&self, identifier: &str, password: &str) -> Result<AtpSession> {
+        *self.oauth.write().await = None;
+        let endpoint = match identity::resolve_identity(identifier, &self.service).await {
+            Ok((_, pds)) => pds,
+            Err(e) => {
+                debug!("Could not resolve {} to a PDS: {}", identifier, e);
+                self.service.clone()
+            }
+        };
+        self.agent.configure_endpoint(endpoint);
+        let session = self.agent.login(identifier, password).await?;
+        Ok(session)
+    }
+
+    /// Resume a saved app-password session. An expired access token is refreshed by
+    /// the agent on the first `ExpiredToken` response and written back to disk.
+    pub async fn resume_session(&self, session: &SessionData) -> Result<()> {
+        *self.oauth.write().await = None;
+        if let Some(ref endpoint) = session.pds_endpoint {
+            self.agent.configure_endpoint(endpoint.clone());
+        }
+        let atp_session = atp_session(
+            &session.did,
+            &session.handle,
+            &session.access_jwt,
+            &session.refresh_jwt,
+        )?;
+        self.agent.resume_session(atp_session).await?;
+        Ok(())
+    }
+
+    /// Attach an authorized OAuth session; all further XRPC calls go to `pds_endpoint`
+    /// with DPoP-bound tokens.
+    pub async fn login_oauth(
+        &self,
+        handle: &str,
+        did: &str,
+        pds_endpoint: &str,
+        session: OAuthSession,
+    ) -> Result<()> {
+        let atp_session = atp_session(
+            did,
+            handle,
+            &session.access_token,
+            session.refresh_token.as_deref().unwrap_or_default(),
+        )?;
+
+        *self.oauth.write().await = Some(session);
+        self.agent.configure_endpoint(pds_endpoint.to_string());
+        if let Err(e) = self.agent.resume_session(atp_session).await {
+            *self.oauth.write().await = None;
+            return Err(e.into());
+        }
         Ok(())
     }
 
@@ -25,6 +129,10 @@ impl BlueskyClient {
         self.agent.did().await.map(|d| d.to_string())
     }
 
+    pub async fn endpoint(&self) -> String {
+        self.agent.get_endpoint().await
+    }
+
     pub async fn get_timeline(
         &self,
         cursor: Option<String>,
@@ -53,9 +161,272 @@ impl BlueskyClient {
         Ok((posts, output.cursor.clone()))
     }
 
-    pub async fn get_thread(&self, uri: &str) -> Result<Option<ThreadViewModel>> {
+    pub async fn get_feed(
+        &self,
+        source: &FeedSource,
+        cursor: Option<String>,
+    ) -> Result<(Vec<PostViewModel>, Option<String>)> {
+        let (feed, cursor) = match source {
+            FeedSource::Following => return self.get_timeline(cursor, Some(50u8)).await,
+            FeedSource::Generator(uri) => {
+                let params = atrium_api::app::bsky::feed::get_feed::ParametersData {
+                    cursor,
+                    feed: uri.clone(),
+                    limit: 50u8.try_into().ok(),
+                };
+                let output = self
+                    .agent
+                    .api
+                    .app
+                    .bsky
+                    .feed
+                    .get_feed(params.into())
+                    .await?;
+                (output.data.feed, output.data.cursor)
+            }
+            FeedSource::List(uri) => {
+                let params = atrium_api::app::bsky::feed::get_list_feed::ParametersData {
+                    cursor,
+                    limit: 50u8.try_into().ok(),
+                    list: uri.clone(),
+                };
+                let output = self
+                    .agent
+                    .api
+                    .app
+                    .bsky
+                    .feed
+                    .get_list_feed(params.into())
+                    .await?;
+                (output.data.feed, output.data.cursor)
+            }
+        };
+
+        let posts: Vec<PostViewModel> = feed
+            .iter()
+            .filter_map(PostViewModel::from_feed_view_post)
+            .collect();
+
+        Ok((posts, cursor))
+    }
+
+    /// Saved feeds from the user's preferences, in order, with display names resolved.
+    /// The Following timeline is always included.
+    pub async fn get_saved_feeds(&self) -> Result<Vec<SavedFeed>> {
+        let items = saved_feed_items(&self.get_preferences().await?);
+
+        let generator_uris: Vec<String> = items
+            .iter()
+            .filter(|i| i.r#type == "feed")
+            .map(|i| i.value.clone())
+            .collect();
+        let mut names: HashMap<String, String> = HashMap::new();
+        if !generator_uris.is_empty() {
+            let params = atrium_api::app::bsky::feed::get_feed_generators::ParametersData {
+                feeds: generator_uris,
+            };
+            let output = self
+                .agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_feed_generators(params.into())
+                .await?;
+            for generator in &output.feeds {
+                names.insert(generator.uri.clone(), generator.display_name.clone());
+            }
+        }
+        for item in items.iter().filter(|i| i.r#type == "list") {
+            let params = atrium_api::app::bsky::graph::get_list::ParametersData {
+                cursor: None,
+                limit: 1u8.try_into().ok(),
+                list: item.value.clone(),
+            };
+            match self.agent.api.app.bsky.graph.get_list(params.into()).await {
+                Ok(output) => {
+                    names.insert(item.value.clone(), output.list.name.clone());
+                }
+                Err(e) => debug!("Could not load list {}: {}", item.value, e),
+            }
+        }
+
+        let mut feeds: Vec<SavedFeed> = items
+            .iter()
+            .filter_map(|item| {
+                let source = match item.r#type.as_str() {
+                    "timeline" => FeedSource::Following,
+                    "feed" => FeedSource::Generator(item.value.clone()),
+                    "list" => FeedSource::List(item.value.clone()),
+                    _ => return None,
+                };
+                let name = match source {
+                    FeedSource::Following => "Following".to_string(),
+                    _ => names.get(&item.value)?.clone(),
+                };
+                Some(SavedFeed {
+                    id: item.id.clone(),
+                    source,
+                    name,
+                    pinned: item.pinned,
+                    state: FeedState::new(),
+                })
+            })
+            .collect();
+
+        if !feeds.iter().any(|f| f.source == FeedSource::Following) {
+            feeds.insert(0, SavedFeed::following());
+        }
+        Ok(feeds)
+    }
+
+    /// Pin or unpin a saved feed, keeping every other preference intact.
+    pub async fn set_feed_pinned(&self, id: &str, pinned: bool) -> Result<()> {
+        let mut preferences = self.get_preferences().await?;
+        let mut items = saved_feed_items(&preferences);
+        match items.iter_mut().find(|i| i.id == id) {
+            Some(item) => item.pinned = pinned,
+            None => return Err(anyhow!("Feed is no longer saved")),
+        }
+
+        preferences.retain(|p| !matches!(p, Union::Refs(PreferencesItem::SavedFeedsPrefV2(_))));
+        preferences.push(Union::Refs(PreferencesItem::SavedFeedsPrefV2(Box::new(
+            SavedFeedsPrefV2Data {
+                items: items.into_iter().map(Into::into).collect(),
+            }
+            .into(),
+        ))));
+        self.put_preferences(preferences).await
+    }
+
+    /// Moderation preferences with the subscribed labelers' label definitions. Also
+    /// asks the AppView for those labelers' labels on everything fetched from now on.
+    pub async fn get_moderation_prefs(&self) -> Result<ModerationPrefs> {
+        let mut prefs = ModerationPrefs::from_preferences(&self.get_preferences().await?);
+
+        let dids = prefs
+            .labelers
+            .iter()
+            .filter_map(|did| did.parse().ok())
+            .collect::<Vec<atrium_api::types::string::Did>>();
+        self.agent.configure_labelers_header(Some(
+            dids.iter()
+                .map(|did| (did.clone(), did.as_str() == BSKY_LABELER_DID))
+                .collect(),
+        ));
+
+        let params = atrium_api::app::bsky::labeler::get_services::ParametersData {
+            detailed: Some(true),
+            dids,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .labeler
+            .get_services(params.into())
+            .await?;
+        use atrium_api::app::bsky::labeler::get_services::OutputViewsItem;
+        for view in &output.views {
+            let Union::Refs(OutputViewsItem::AppBskyLabelerDefsLabelerViewDetailed(view)) = view
+            else {
+                continue;
+            };
+            let labeler = view.creator.did.to_string();
+            prefs.services.push(ModerationService {
+                did: labeler.clone(),
+                name: view
+                    .creator
+                    .display_name
+                    .clone()
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| view.creator.handle.to_string()),
+                reason_types: view.reason_types.clone(),
+            });
+            prefs.label_definitions.extend(
+                view.policies
+                    .label_value_definitions
+                    .iter()
+                    .flatten()
+                    .map(|def| LabelDefinition::from_labeler(&labeler, def)),
+            );
+        }
+        prefs.services.sort_by_key(|s| s.did != BSKY_LABELER_DID);
+        Ok(prefs)
+    }
+
+    /// Add a muted word, or replace the rule for the same word. Returns the saved list.
+    pub async fn add_muted_word(&self, word: &MutedWord) -> Result<Vec<MutedWord>> {
+        self.update_muted_words(|items| {
+            items.retain(|w| !w.value.eq_ignore_ascii_case(&word.value));
+            items.push(word.to_data().into());
+        })
+        .await
+    }
+
+    pub async fn remove_muted_word(&self, word: &MutedWord) -> Result<Vec<MutedWord>> {
+        self.update_muted_words(|items| {
+            items.retain(|w| !word.same_rule(&MutedWord::from_data(w)));
+        })
+        .await
+    }
+
+    /// Edit `mutedWordsPref` in place, keeping every other preference intact.
+    async fn update_muted_words(
+        &self,
+        edit: impl FnOnce(&mut Vec<atrium_api::app::bsky::actor::defs::MutedWord>),
+    ) -> Result<Vec<MutedWord>> {
+        let mut preferences = self.get_preferences().await?;
+        let mut items = preferences
+            .iter()
+            .find_map(|p| match p {
+                Union::Refs(PreferencesItem::MutedWordsPref(p)) => Some(p.items.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        edit(&mut items);
+        let words = items.iter().map(|w| MutedWord::from_data(w)).collect();
+
+        preferences.retain(|p| !matches!(p, Union::Refs(PreferencesItem::MutedWordsPref(_))));
+        preferences.push(Union::Refs(PreferencesItem::MutedWordsPref(Box::new(
+            MutedWordsPrefData { items }.into(),
+        ))));
+        self.put_preferences(preferences).await?;
+        Ok(words)
+    }
+
+    async fn get_preferences(&self) -> Result<Preferences> {
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .actor
+            .get_preferences(
+                atrium_api::app::bsky::actor::get_preferences::ParametersData {}.into(),
+            )
+            .await?;
+        Ok(output.data.preferences)
+    }
+
+    async fn put_preferences(&self, preferences: Preferences) -> Result<()> {
+        self.agent
+            .api
+            .app
+            .bsky
+            .actor
+            .put_preferences(
+                atrium_api::app::bsky::actor::put_preferences::InputData { preferences }.into(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Fails with a readable message when the post itself is deleted or blocked.
+    pub async fn get_thread(&self, uri: &str) -> Result<ThreadViewModel> {
         let params = atrium_api::app::bsky::feed::get_post_thread::ParametersData {
-            depth: Some(6u16.try_into().unwrap()),
+            depth: Some(THREAD_DEPTH.try_into().unwrap()),
             parent_height: Some(10u16.try_into().unwrap()),
             uri: uri.to_string(),
         };
@@ -71,22 +442,43 @@ impl BlueskyClient {
         use atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs;
         use atrium_api::types::Union;
 
-        match &output.thread {
+        let hidden = output
+            .threadgate
+            .as_ref()
+            .and_then(|tg| tg.record.as_ref())
+            .and_then(|record| serde_json::to_value(record).ok())
+            .and_then(|record| {
+                serde_json::from_value::<Vec<String>>(record.get("hiddenReplies")?.clone()).ok()
+            })
+            .unwrap_or_default();
+
+        let unavailable = match &output.thread {
             Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(tvp)) => {
-                Ok(ThreadViewModel::from_thread_view_post(tvp))
+                return ThreadViewModel::from_thread_view_post(tvp, &hidden)
+                    .ok_or_else(|| anyhow!("Could not read this post."));
             }
-            _ => Ok(None),
-        }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => Unavailable::NotFound,
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsBlockedPost(_)) => Unavailable::Blocked,
+            Union::Unknown(_) => return Err(anyhow!("Unsupported thread response.")),
+        };
+        Err(anyhow!(unavailable.root_message()))
     }
 
+    /// Returns the new post's `(uri, cid)` so a thread can chain replies onto it.
     pub async fn create_post(
         &self,
         text: String,
         reply_to: Option<ReplyRef>,
-    ) -> Result<String> {
+        images: Vec<ImageAttachment>,
+        quote: Option<QuoteRef>,
+        mentions: &HashMap<String, String>,
+        self_label: Option<String>,
+    ) -> Result<(String, String)> {
         let facets = {
             let rt = bsky_sdk::rich_text::RichText::new_with_detect_facets(&text).await?;
-            rt.facets
+            let mut facets = rt.facets.unwrap_or_default();
+            apply_mentions(&text, &mut facets, mentions);
+            (!facets.is_empty()).then_some(facets)
         };
 
         let reply = reply_to.map(|r| {
@@ -105,12 +497,67 @@ impl BlueskyClient {
             .into()
         });
 
+        use atrium_api::app::bsky::embed::{record, record_with_media};
+        use atrium_api::app::bsky::feed::post::RecordEmbedRefs;
+
+        let quote = quote
+            .map(|q| -> Result<record::Main> {
+                Ok(record::MainData {
+                    record: atrium_api::com::atproto::repo::strong_ref::MainData {
+                        cid: q
+                            .cid
+                            .parse()
+                            .map_err(|_| anyhow!("Invalid quoted post CID: {}", q.cid))?,
+                        uri: q.uri,
+                    }
+                    .into(),
+                }
+                .into())
+            })
+            .transpose()?;
+
+        let embed = match (images.is_empty(), quote) {
+            (true, None) => None,
+            (true, Some(quote)) => Some(Union::Refs(RecordEmbedRefs::AppBskyEmbedRecordMain(
+                Box::new(quote),
+            ))),
+            (false, None) => Some(Union::Refs(RecordEmbedRefs::AppBskyEmbedImagesMain(
+                Box::new(self.upload_images(images).await?),
+            ))),
+            (false, Some(quote)) => {
+                let media = record_with_media::MainMediaRefs::AppBskyEmbedImagesMain(Box::new(
+                    self.upload_images(images).await?,
+                ));
+                Some(Union::Refs(RecordEmbedRefs::AppBskyEmbedRecordWithMediaMain(
+                    Box::new(
+                        record_with_media::MainData {
+                            media: Union::Refs(media),
+                            record: quote,
+                        }
+                        .into(),
+                    ),
+                )))
+            }
+        };
+
+        use atrium_api::app::bsky::feed::post::RecordLabelsRefs;
+        use atrium_api::com::atproto::label::defs::{SelfLabelData, SelfLabelsData};
+
+        let labels = self_label.map(|val| {
+            Union::Refs(RecordLabelsRefs::ComAtprotoLabelDefsSelfLabels(Box::new(
+                SelfLabelsData {
+                    values: vec![SelfLabelData { val }.into()],
+                }
+                .into(),
+            )))
+        });
+
         let record = atrium_api::app::bsky::feed::post::RecordData {
             created_at: Datetime::now(),
-            embed: None,
+            embed,
             entities: None,
             facets,
-            labels: None,
+            labels,
             langs: None,
             reply,
             tags: None,
@@ -118,7 +565,43 @@ impl BlueskyClient {
         };
 
         let result = self.agent.create_record(record).await?;
-        Ok(result.uri.to_string())
+        Ok((result.uri.to_string(), result.cid.as_ref().to_string()))
+    }
+
+    async fn upload_images(
+        &self,
+        images: Vec<ImageAttachment>,
+    ) -> Result<atrium_api::app::bsky::embed::images::Main> {
+        let mut embedded = Vec::with_capacity(images.len());
+        for image in images {
+            debug!("Uploading {} ({} bytes)", image.name, image.data.len());
+            let output = self
+                .agent
+                .api
+                .com
+                .atproto
+                .repo
+                .upload_blob(image.data)
+                .await?;
+            let aspect_ratio = match (
+                std::num::NonZeroU64::new(image.width),
+                std::num::NonZeroU64::new(image.height),
+            ) {
+                (Some(width), Some(height)) => Some(
+                    atrium_api::app::bsky::embed::defs::AspectRatioData { height, width }.into(),
+                ),
+                _ => None,
+            };
+            embedded.push(
+                atrium_api::app::bsky::embed::images::ImageData {
+                    alt: image.alt,
+                    aspect_ratio,
+                    image: output.data.blob,
+                }
+                .into(),
+            );
+        }
+        Ok(atrium_api::app::bsky::embed::images::MainData { images: embedded }.into())
     }
 
     pub async fn like(&self, uri: &str, cid: &str) -> Result<String> {
@@ -143,6 +626,11 @@ impl BlueskyClient {
         Ok(())
     }
 
+    pub async fn delete_post(&self, uri: &str) -> Result<()> {
+        self.agent.delete_record(uri).await?;
+        Ok(())
+    }
+
     pub async fn repost(&self, uri: &str, cid: &str) -> Result<String> {
         let record = atrium_api::app::bsky::feed::repost::RecordData {
             created_at: Datetime::now(),
@@ -165,9 +653,100 @@ impl BlueskyClient {
         Ok(())
     }
 
+    pub async fn follow(&self, did: &str) -> Result<String> {
+        let record = atrium_api::app::bsky::graph::follow::RecordData {
+            created_at: Datetime::now(),
+            subject: did.parse().map_err(|e| anyhow!("invalid DID {}: {}", did, e))?,
+        };
+        let result = self.agent.create_record(record).await?;
+        Ok(result.uri.to_string())
+    }
+
+    pub async fn unfollow(&self, follow_uri: &str) -> Result<()> {
+        self.agent.delete_record(follow_uri).await?;
+        Ok(())
+    }
+
+    pub async fn block(&self, did: &str) -> Result<String> {
+        let record = atrium_api::app::bsky::graph::block::RecordData {
+            created_at: Datetime::now(),
+            subject: did.parse().map_err(|e| anyhow!("invalid DID {}: {}", did, e))?,
+        };
+        let result = self.agent.create_record(record).await?;
+        Ok(result.uri.to_string())
+    }
+
+    pub async fn unblock(&self, block_uri: &str) -> Result<()> {
+        self.agent.delete_record(block_uri).await?;
+        Ok(())
+    }
+
+    /// Send a report to `report.service_did`, proxied through the user's PDS.
+    pub async fn create_report(&self, report: Report) -> Result<()> {
+        use atrium_api::com::atproto::moderation::create_report::{InputData, InputSubjectRefs};
+
+        let subject = match report.subject {
+            ReportSubject::Account { did, .. } => InputSubjectRefs::ComAtprotoAdminDefsRepoRef(
+                Box::new(
+                    atrium_api::com::atproto::admin::defs::RepoRefData {
+                        did: did.parse().map_err(|e| anyhow!("invalid DID {}: {}", did, e))?,
+                    }
+                    .into(),
+                ),
+            ),
+            ReportSubject::Post { uri, cid, .. } => InputSubjectRefs::ComAtprotoRepoStrongRefMain(
+                Box::new(
+                    atrium_api::com::atproto::repo::strong_ref::MainData {
+                        cid: cid.parse().map_err(|e| anyhow!("invalid CID {}: {:?}", cid, e))?,
+                        uri,
+                    }
+                    .into(),
+                ),
+            ),
+        };
+        let service: atrium_api::types::string::Did = report
+            .service_did
+            .parse()
+            .map_err(|e| anyhow!("invalid DID {}: {}", report.service_did, e))?;
+        let input = InputData {
+            mod_tool: None,
+            reason: report.comment,
+            reason_type: report.reason_type,
+            subject: Union::Refs(subject),
+        };
+        self.agent
+            .api_with_proxy(service, AtprotoServiceType::AtprotoLabeler)
+            .com
+            .atproto
+            .moderation
+            .create_report(input.into())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_muted(&self, did: &str, muted: bool) -> Result<()> {
+        let actor: atrium_api::types::string::AtIdentifier =
+            did.parse().map_err(|e| anyhow!("invalid DID {}: {}", did, e))?;
+        let graph = &self.agent.api.app.bsky.graph;
+        if muted {
+            graph
+                .mute_actor(atrium_api::app::bsky::graph::mute_actor::InputData { actor }.into())
+                .await?;
+        } else {
+            graph
+                .unmute_actor(
+                    atrium_api::app::bsky::graph::unmute_actor::InputData { actor }.into(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     pub async fn get_profile(&self, actor: &str) -> Result<ProfileViewModel> {
         let params = atrium_api::app::bsky::actor::get_profile::ParametersData {
-            actor: actor.parse().expect("valid handle or did"),
+            actor: actor
+                .parse()
+                .map_err(|_| anyhow!("Invalid actor: {}", actor))?,
         };
         let output = self
             .agent
@@ -184,13 +763,19 @@ impl BlueskyClient {
     pub async fn get_author_feed(
         &self,
         actor: &str,
+        tab: AuthorFeedTab,
         cursor: Option<String>,
     ) -> Result<(Vec<PostViewModel>, Option<String>)> {
+        let Some(filter) = tab.filter() else {
+            return self.get_actor_likes(actor, cursor).await;
+        };
         let params = atrium_api::app::bsky::feed::get_author_feed::ParametersData {
-            actor: actor.parse().expect("valid handle or did"),
+            actor: actor
+                .parse()
+                .map_err(|_| anyhow!("Invalid actor: {}", actor))?,
             cursor,
-            filter: None,
-            include_pins: None,
+            filter: Some(filter.to_string()),
+            include_pins: Some(tab == AuthorFeedTab::Posts),
             limit: 50u8.try_into().ok(),
         };
         let output = self
@@ -211,15 +796,760 @@ impl BlueskyClient {
         Ok((posts, output.cursor.clone()))
     }
 
-    pub fn agent(&self) -> &BskyAgent {
+    /// Posts `actor` has liked. The AppView only serves this for the signed-in account.
+    pub async fn get_actor_likes(
+        &self,
+        actor: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<PostViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::feed::get_actor_likes::ParametersData {
+            actor: actor.parse().map_err(|e| anyhow!("invalid actor {}: {}", actor, e))?,
+            cursor,
+            limit: 50u8.try_into().ok(),
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_actor_likes(params.into())
+            .await?;
+
+        let posts: Vec<PostViewModel> = output
+            .feed
+            .iter()
+            .filter_map(PostViewModel::from_feed_view_post)
+            .collect();
+
+        Ok((posts, output.cursor.clone()))
+    }
+
+    /// Fetch a page of notifications along with the posts that likes and reposts point at.
+    pub async fn list_notifications(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<NotificationViewModel>, Vec<PostViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::notification::list_notifications::ParametersData {
+            cursor,
+            limit: 50u8.try_into().ok(),
+            priority: None,
+            reasons: None,
+            seen_at: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .notification
+            .list_notifications(params.into())
+            .await?;
+
+        let notifications: Vec<NotificationViewModel> = output
+            .notifications
+            .iter()
+            .filter_map(NotificationViewModel::from_notification)
+            .collect();
+
+        let mut subject_uris: Vec<String> = notifications
+            .iter()
+            .filter_map(|n| n.reason_subject.clone())
+            .collect();
+        subject_uris.sort();
+        subject_uris.dedup();
+        let subjects = self.get_posts(subject_uris).await?;
+
+        Ok((notifications, subjects, output.cursor.clone()))
+    }
+
+    pub async fn get_unread_count(&self) -> Result<i64> {
+        let params = atrium_api::app::bsky::notification::get_unread_count::ParametersData {
+            priority: None,
+            seen_at: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .notification
+            .get_unread_count(params.into())
+            .await?;
+        Ok(output.count)
+    }
+
+    /// Mark notifications indexed up to `seen_at` as read.
+    pub async fn update_seen(&self, seen_at: DateTime<Utc>) -> Result<()> {
+        let input = atrium_api::app::bsky::notification::update_seen::InputData {
+            seen_at: Datetime::new(seen_at.fixed_offset()),
+        };
+        self.agent
+            .api
+            .app
+            .bsky
+            .notification
+            .update_seen(input.into())
+            .await?;
+        Ok(())
+    }
+
+    /// Hydrate posts by URI; `getPosts` accepts at most 25 per call.
+    pub async fn get_posts(&self, uris: Vec<String>) -> Result<Vec<PostViewModel>> {
+        let mut posts = Vec::new();
+        for chunk in uris.chunks(25) {
+            let params = atrium_api::app::bsky::feed::get_posts::ParametersData {
+                uris: chunk.to_vec(),
+            };
+            let output = self
+                .agent
+                .api
+                .app
+                .bsky
+                .feed
+                .get_posts(params.into())
+                .await?;
+            posts.extend(output.posts.iter().filter_map(PostViewModel::from_post_view));
+        }
+        Ok(posts)
+    }
+
+    pub async fn search_posts(
+        &self,
+        query: &SearchQuery,
+        sort: SearchSort,
+        cursor: Option<String>,
+    ) -> Result<(Vec<PostViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::feed::search_posts::ParametersData {
+            author: query
+                .author
+                .as_deref()
+                .map(|a| a.parse().map_err(|_| anyhow!("Invalid author: {}", a)))
+                .transpose()?,
+            cursor,
+            domain: None,
+            lang: query
+                .lang
+                .as_deref()
+                .map(|l| l.parse().map_err(|_| anyhow!("Invalid language: {}", l)))
+                .transpose()?,
+            limit: 25u8.try_into().ok(),
+            mentions: None,
+            q: query.q(),
+            since: query.since.clone(),
+            sort: Some(sort.as_str().to_string()),
+            tag: None,
+            until: query.until.clone(),
+            url: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .feed
+            .search_posts(params.into())
+            .await?;
+
+        let posts: Vec<PostViewModel> = output
+            .posts
+            .iter()
+            .filter_map(PostViewModel::from_post_view)
+            .collect();
+
+        Ok((posts, output.cursor.clone()))
+    }
+
+    /// Handle completion for `@` mentions in the composer.
+    pub async fn search_actors_typeahead(&self, query: &str) -> Result<Vec<ProfileViewModel>> {
+        let params = atrium_api::app::bsky::actor::search_actors_typeahead::ParametersData {
+            limit: 8u8.try_into().ok(),
+            q: Some(query.to_string()),
+            term: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .actor
+            .search_actors_typeahead(params.into())
+            .await?;
+
+        Ok(output
+            .actors
+            .iter()
+            .map(ProfileViewModel::from_profile_view_basic)
+            .collect())
+    }
+
+    pub async fn search_actors(
+        &self,
+        query: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ProfileViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::actor::search_actors::ParametersData {
+            cursor,
+            limit: 25u8.try_into().ok(),
+            q: Some(query.to_string()),
+            term: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .actor
+            .search_actors(params.into())
+            .await?;
+
+        let actors = output
+            .actors
+            .iter()
+            .map(ProfileViewModel::from_profile_view)
+            .collect();
+
+        Ok((actors, output.cursor.clone()))
+    }
+
+    pub async fn get_followers(
+        &self,
+        actor: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ProfileViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::graph::get_followers::ParametersData {
+            actor: actor.parse().map_err(|e| anyhow!("invalid actor {}: {}", actor, e))?,
+            cursor,
+            limit: 50u8.try_into().ok(),
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_followers(params.into())
+            .await?;
+
+        let actors = output
+            .followers
+            .iter()
+            .map(ProfileViewModel::from_profile_view)
+            .collect();
+
+        Ok((actors, output.cursor.clone()))
+    }
+
+    pub async fn get_follows(
+        &self,
+        actor: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ProfileViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::graph::get_follows::ParametersData {
+            actor: actor.parse().map_err(|e| anyhow!("invalid actor {}: {}", actor, e))?,
+            cursor,
+            limit: 50u8.try_into().ok(),
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_follows(params.into())
+            .await?;
+
+        let actors = output
+            .follows
+            .iter()
+            .map(ProfileViewModel::from_profile_view)
+            .collect();
+
+        Ok((actors, output.cursor.clone()))
+    }
+
+    pub async fn get_lists(
+        &self,
+        actor: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ListViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::graph::get_lists::ParametersData {
+            actor: actor.parse().map_err(|e| anyhow!("invalid actor {}: {}", actor, e))?,
+            cursor,
+            limit: 50u8.try_into().ok(),
+            purposes: None,
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_lists(params.into())
+            .await?;
+
+        let lists = output.lists.iter().map(ListViewModel::from_list_view).collect();
+        Ok((lists, output.cursor.clone()))
+    }
+
+    /// The list itself, with a page of its members.
+    pub async fn get_list(
+        &self,
+        uri: &str,
+        cursor: Option<String>,
+    ) -> Result<(ListViewModel, Vec<ProfileViewModel>, Option<String>)> {
+        let params = atrium_api::app::bsky::graph::get_list::ParametersData {
+            cursor,
+            limit: 50u8.try_into().ok(),
+            list: uri.to_string(),
+        };
+        let output = self
+            .agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_list(params.into())
+            .await?;
+
+        let members = output
+            .items
+            .iter()
+            .map(|item| ProfileViewModel::from_profile_view(&item.subject))
+            .collect();
+        Ok((
+            ListViewModel::from_list_view(&output.list),
+            members,
+            output.cursor.clone(),
+        ))
+    }
+
+    /// Our curate and moderation lists, each with the `listitem` URI when `did` is on it.
+    /// Follows the cursor, so lists past the first page are included too.
+    pub async fn get_lists_with_membership(
+        &self,
+        did: &str,
+    ) -> Result<Vec<(ListViewModel, Option<String>)>> {
+        let actor: atrium_api::types::string::AtIdentifier =
+            did.parse().map_err(|e| anyhow!("invalid actor {}: {}", did, e))?;
+        let mut lists = Vec::new();
+        let mut cursor = None;
+        loop {
+            let params = atrium_api::app::bsky::graph::get_lists_with_membership::ParametersData {
+                actor: actor.clone(),
+                cursor,
+                limit: 100u8.try_into().ok(),
+                purposes: Some(vec![
+                    ListPurpose::Curate.as_str().to_string(),
+                    ListPurpose::Moderation.as_str().to_string(),
+                ]),
+            };
+            let output = self
+                .agent
+                .api
+                .app
+                .bsky
+                .graph
+                .get_lists_with_membership(params.into())
+                .await?;
+
+            lists.extend(output.lists_with_membership.iter().map(|m| {
+                (
+                    ListViewModel::from_list_view(&m.list),
+                    m.list_item.as_ref().map(|item| item.uri.clone()),
+                )
+            }));
+            cursor = output.data.cursor;
+            if cursor.is_none() || output.data.lists_with_membership.is_empty() {
+                return Ok(lists);
+            }
+        }
+    }
+
+    /// Returns the new `listitem` record URI.
+    pub async fn add_to_list(&self, list_uri: &str, did: &str) -> Result<String> {
+        let record = atrium_api::app::bsky::graph::listitem::RecordData {
+            created_at: Datetime::now(),
+            list: list_uri.to_string(),
+            subject: did.parse().map_err(|e| anyhow!("invalid DID {}: {}", did, e))?,
+        };
+        let result = self.agent.create_record(record).await?;
+        Ok(result.uri.to_string())
+    }
+
+    pub async fn remove_from_list(&self, item_uri: &str) -> Result<()> {
+        self.agent.delete_record(item_uri).await?;
+        Ok(())
+    }
+
+    pub async fn set_list_muted(&self, list_uri: &str, muted: bool) -> Result<()> {
+        let graph = &self.agent.api.app.bsky.graph;
+        let list = list_uri.to_string();
+        if muted {
+            graph
+                .mute_actor_list(
+                    atrium_api::app::bsky::graph::mute_actor_list::InputData { list }.into(),
+                )
+                .await?;
+        } else {
+            graph
+                .unmute_actor_list(
+                    atrium_api::app::bsky::graph::unmute_actor_list::InputData { list }.into(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Subscribe to a moderation list as a block list. Returns the `listblock` record URI.
+    pub async fn block_list(&self, list_uri: &str) -> Result<String> {
+        let record = atrium_api::app::bsky::graph::listblock::RecordData {
+            created_at: Datetime::now(),
+            subject: list_uri.to_string(),
+        };
+        let result = self.agent.create_record(record).await?;
+        Ok(result.uri.to_string())
+    }
+
+    pub async fn unblock_list(&self, listblock_uri: &str) -> Result<()> {
+        self.agent.delete_record(listblock_uri).await?;
+        Ok(())
+    }
+
+    /// Chat calls go to the PDS, which forwards them to the chat service named in
+    /// the `atproto-proxy` header.
+    fn chat_did() -> atrium_api::types::string::Did {
+        BSKY_CHAT_DID.parse().expect("valid chat service DID")
+    }
+
+    pub async fn list_convos(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ConvoViewModel>, Option<String>)> {
+        let own_did = self.did().await;
+        let params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
+            cursor,
+            limit: 30u8.try_into().ok(),
+            read_state: None,
+            status: None,
+        };
+        let output = self
+            .agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .list_convos(params.into())
+            .await?;
+
+        let convos = output
+            .convos
+            .iter()
+            .map(|c| ConvoViewModel::from_convo_view(c, own_did.as_deref()))
+            .collect();
+
+        Ok((convos, output.cursor.clone()))
+    }
+
+    /// Unread messages across unmuted conversations, for the tab badge.
+    pub async fn get_chat_unread_count(&self) -> Result<i64> {
+        let params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
+            cursor: None,
+            limit: 100u8.try_into().ok(),
+            read_state: Some("unread".to_string()),
+            status: None,
+        };
+        let output = self
+            .agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .list_convos(params.into())
+            .await?;
+
+        Ok(output
+            .convos
+            .iter()
+            .filter(|c| !c.muted)
+            .map(|c| c.unread_count)
+            .sum())
+    }
+
+    /// A page of messages, newest first.
+    pub async fn get_messages(
+        &self,
+        convo_id: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<MessageViewModel>, Option<String>)> {
+        use atrium_api::chat::bsky::convo::get_messages::OutputMessagesItem;
+
+        let params = atrium_api::chat::bsky::convo::get_messages::ParametersData {
+            convo_id: convo_id.to_string(),
+            cursor,
+            limit: 50u8.try_into().ok(),
+        };
+        let output = self
+            .agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .get_messages(params.into())
+            .await?;
+
+        let messages = output
+            .messages
+            .iter()
+            .filter_map(|m| match m {
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsMessageView(m)) => {
+                    Some(MessageViewModel::from_message_view(m))
+                }
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsDeletedMessageView(m)) => {
+                    Some(MessageViewModel::from_deleted(m))
+                }
+                Union::Unknown(_) => None,
+            })
+            .collect();
+
+        Ok((messages, output.cursor.clone()))
+    }
+
+    pub async fn send_message(&self, convo_id: &str, text: String) -> Result<MessageViewModel> {
+        let facets = bsky_sdk::rich_text::RichText::new_with_detect_facets(&text)
+            .await?
+            .facets;
+        let input = atrium_api::chat::bsky::convo::send_message::InputData {
+            convo_id: convo_id.to_string(),
+            message: atrium_api::chat::bsky::convo::defs::MessageInputData {
+                embed: None,
+                facets,
+                text,
+            }
+            .into(),
+        };
+        let output = self
+            .agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .send_message(input.into())
+            .await?;
+
+        Ok(MessageViewModel::from_message_view(&output))
+    }
+
+    /// Mark everything up to `message_id` (or the whole conversation) as read.
+    pub async fn mark_convo_read(&self, convo_id: &str, message_id: Option<String>) -> Result<()> {
+        let input = atrium_api::chat::bsky::convo::update_read::InputData {
+            convo_id: convo_id.to_string(),
+            message_id,
+        };
+        self.agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .update_read(input.into())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_convo_muted(&self, convo_id: &str, muted: bool) -> Result<()> {
+        let chat = self
+            .agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat);
+        let convo_id = convo_id.to_string();
+        if muted {
+            chat.chat
+                .bsky
+                .convo
+                .mute_convo(atrium_api::chat::bsky::convo::mute_convo::InputData { convo_id }.into())
+                .await?;
+        } else {
+            chat.chat
+                .bsky
+                .convo
+                .unmute_convo(
+                    atrium_api::chat::bsky::convo::unmute_convo::InputData { convo_id }.into(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn leave_convo(&self, convo_id: &str) -> Result<()> {
+        let input = atrium_api::chat::bsky::convo::leave_convo::InputData {
+            convo_id: convo_id.to_string(),
+        };
+        self.agent
+            .api_with_proxy(Self::chat_did(), AtprotoServiceType::BskyChat)
+            .chat
+            .bsky
+            .convo
+            .leave_convo(input.into())
+            .await?;
+        Ok(())
+    }
+
+    pub fn agent(&self) -> &BskyAgent<XrpcHttpClient, FileSessionStore> {
         &self.agent
     }
 }
 
-#[derive(Debug, Clone)]
+impl std::fmt::Debug for BlueskyClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlueskyClient")
+            .field("service", &self.service)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether an XRPC error says the session isn't allowed to make the call, like chat
+/// calls under an OAuth grant without the chat scope, or an app password without DM access.
+pub fn is_scope_denied(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("scope") || error.contains("bad token method")
+}
+
+fn atp_session(did: &str, handle: &str, access_jwt: &str, refresh_jwt: &str) -> Result<AtpSession> {
+    Ok(atrium_api::com::atproto::server::create_session::OutputData {
+        access_jwt: access_jwt.to_string(),
+        active: None,
+        did: did.parse().map_err(anyhow::Error::msg)?,
+        did_doc: None,
+        email: None,
+        email_auth_factor: None,
+        email_confirmed: None,
+        handle: handle.parse().map_err(anyhow::Error::msg)?,
+        refresh_jwt: refresh_jwt.to_string(),
+        status: None,
+    }
+    .into())
+}
+
+/// Saved feeds from `savedFeedsPrefV2`, falling back to the legacy `savedFeedsPref`.
+fn saved_feed_items(preferences: &Preferences) -> Vec<SavedFeedData> {
+    let mut legacy = None;
+    for pref in preferences {
+        match pref {
+            Union::Refs(PreferencesItem::SavedFeedsPrefV2(p)) => {
+                return p.items.iter().map(|i| i.data.clone()).collect();
+            }
+            Union::Refs(PreferencesItem::SavedFeedsPref(p)) => legacy = Some(p),
+            _ => {}
+        }
+    }
+
+    let mut items = vec![SavedFeedData {
+        id: "following".to_string(),
+        pinned: true,
+        r#type: "timeline".to_string(),
+        value: "following".to_string(),
+    }];
+    if let Some(legacy) = legacy {
+        items.extend(legacy.saved.iter().map(|uri| SavedFeedData {
+            id: uri.clone(),
+            pinned: legacy.pinned.contains(uri),
+            r#type: if uri.contains("/app.bsky.graph.list/") {
+                "list".to_string()
+            } else {
+                "feed".to_string()
+            },
+            value: uri.clone(),
+        }));
+    }
+    items
+}
+
+/// Point mention facets for handles picked from autocomplete at the DID that
+/// was picked, adding facets where detection missed the handle.
+fn apply_mentions(
+    text: &str,
+    facets: &mut Vec<atrium_api::app::bsky::richtext::facet::Main>,
+    mentions: &HashMap<String, String>,
+) {
+    use atrium_api::app::bsky::richtext::facet::{
+        ByteSliceData, MainData, MainFeaturesItem, MentionData,
+    };
+
+    for (handle, did) in mentions {
+        let Ok(did) = did.parse::<atrium_api::types::string::Did>() else {
+            continue;
+        };
+        let needle = format!("@{}", handle);
+        for (start, _) in text.match_indices(&needle) {
+            let end = start + needle.len();
+            let bounded_before = text[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|c| c.is_whitespace() || c == '(');
+            // A trailing '.' ends the sentence rather than continuing the handle
+            let mut after = text[end..].chars();
+            let bounded_after = match after.next() {
+                None => true,
+                Some('.' | '-') => !after.next().is_some_and(|c| c.is_ascii_alphanumeric()),
+                Some(c) => !c.is_ascii_alphanumeric(),
+            };
+            if !bounded_before || !bounded_after {
+                continue;
+            }
+
+            let mention = Union::Refs(MainFeaturesItem::Mention(Box::new(
+                MentionData { did: did.clone() }.into(),
+            )));
+            match facets.iter_mut().find(|f| f.index.byte_start == start) {
+                Some(facet) => facet.features = vec![mention],
+                None => facets.push(
+                    MainData {
+                        features: vec![mention],
+                        index: ByteSliceData {
+                            byte_end: end,
+                            byte_start: start,
+                        }
+                        .into(),
+                    }
+                    .into(),
+                ),
+            }
+        }
+    }
+    facets.sort_by_key(|f| f.index.byte_start);
+}
+
+/// A local image staged in the composer, already validated and read into memory.
+#[derive(Clone)]
+pub struct ImageAttachment {
+    /// Where it was read from, so drafts can attach it again.
+    pub path: PathBuf,
+    pub name: String,
+    pub data: Vec<u8>,
+    pub width: u64,
+    pub height: u64,
+    pub alt: String,
+}
+
+impl std::fmt::Debug for ImageAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageAttachment")
+            .field("name", &self.name)
+            .field("bytes", &self.data.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplyRef {
     pub parent_uri: String,
     pub parent_cid: String,
     pub root_uri: String,
     pub root_cid: String,
 }
+
+/// The post being quoted, embedded as `app.bsky.embed.record`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteRef {
+    pub uri: String,
+    pub cid: String,
+}
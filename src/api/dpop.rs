@@ -13,7 +13,16 @@ pub struct DpopKeyPair {
 
 impl DpopKeyPair {
     pub fn generate() -> Result<Self> {
-        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        Self::from_signing_key(SigningKey::random(&mut rand::thread_rng()))
+    }
+
+    /// Rebuild a key pair from the base64url secret produced by [`DpopKeyPair::secret`].
+    pub fn from_secret(secret: &str) -> Result<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(secret)?;
+        Self::from_signing_key(SigningKey::from_slice(&bytes)?)
+    }
+
+    fn from_signing_key(signing_key: SigningKey) -> Result<Self> {
         let verifying_key = signing_key.verifying_key();
         let point = verifying_key.to_encoded_point(false);
 
@@ -83,6 +92,11 @@ impl DpopKeyPair {
         Ok(format!("{}.{}.{}", header_b64, payload_b64, sig_b64))
     }
 
+    /// The private scalar, base64url-encoded, so the key can be persisted with the session.
+    pub fn secret(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.signing_key.to_bytes())
+    }
+
     pub fn jwk_thumbprint(&self) -> &str {
         &self.jwk_thumbprint
     }
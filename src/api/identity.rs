@@ -0,0 +1,113 @@
+use anyhow::{anyhow, Result};
+use atrium_api::did_doc::DidDocument;
+use tracing::debug;
+
+const PLC_DIRECTORY: &str = "https://plc.directory";
+
+/// Resolve a handle (or pass through a DID) to a DID.
+///
+/// Tries the handle's own `/.well-known/atproto-did` first, then falls back to asking
+/// `service` via `com.atproto.identity.resolveHandle`, which covers DNS TXT records.
+pub async fn resolve_handle(identifier: &str, service: &str) -> Result<String> {
+    let identifier = identifier.trim_start_matches('@');
+    if identifier.starts_with("did:") {
+        return Ok(identifier.to_string());
+    }
+    if identifier.contains('@') {
+        return Err(anyhow!("{} is an email address, not a handle", identifier));
+    }
+
+    let client = reqwest::Client::new();
+
+    let well_known = format!("https://{}/.well-known/atproto-did", identifier);
+    if let Ok(resp) = client.get(&well_known).send().await {
+        if resp.status().is_success() {
+            if let Ok(body) = resp.text().await {
+                let did = body.trim();
+                if did.starts_with("did:") {
+                    return Ok(did.to_string());
+                }
+            }
+        }
+    }
+    debug!("No well-known DID for {}, asking {}", identifier, service);
+
+    let url = reqwest::Url::parse_with_params(
+        &format!(
+            "{}/xrpc/com.atproto.identity.resolveHandle",
+            service.trim_end_matches('/')
+        ),
+        [("handle", identifier)],
+    )?;
+    let body: serde_json::Value = client.get(url).send().await?.json().await?;
+    body["did"]
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| anyhow!("Could not resolve handle {}", identifier))
+}
+
+/// Fetch the DID document for a did:plc or did:web identifier.
+pub async fn resolve_did_document(did: &str) -> Result<DidDocument> {
+    let url = if did.starts_with("did:plc:") {
+        format!("{}/{}", PLC_DIRECTORY, did)
+    } else if let Some(id) = did.strip_prefix("did:web:") {
+        did_web_url(id)
+    } else {
+        return Err(anyhow!("Unsupported DID method: {}", did));
+    };
+
+    let doc: DidDocument = reqwest::get(&url).await?.json().await?;
+    if doc.id != did {
+        return Err(anyhow!("DID document at {} is for {}, not {}", url, doc.id, did));
+    }
+    Ok(doc)
+}
+
+/// Where a did:web document lives: the first segment is the host (with any
+/// port percent-encoded), the rest are path segments in place of `.well-known`.
+fn did_web_url(id: &str) -> String {
+    let mut segments = id.split(':');
+    let host = segments.next().unwrap_or_default().replace("%3A", ":");
+    let path: Vec<&str> = segments.collect();
+    if path.is_empty() {
+        format!("https://{}/.well-known/did.json", host)
+    } else {
+        format!("https://{}/{}/did.json", host, path.join("/"))
+    }
+}
+
+/// Look up the PDS endpoint advertised in a DID's document.
+pub async fn resolve_pds_endpoint(did: &str) -> Result<String> {
+    resolve_did_document(did)
+        .await?
+        .get_pds_endpoint()
+        .ok_or_else(|| anyhow!("No PDS endpoint in DID document for {}", did))
+}
+
+/// Resolve a handle or DID all the way to `(did, pds_endpoint)`.
+pub async fn resolve_identity(identifier: &str, service: &str) -> Result<(String, String)> {
+    let did = resolve_handle(identifier, service).await?;
+    let pds = resolve_pds_endpoint(&did).await?;
+    Ok((did, pds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn did_web_urls_follow_the_spec() {
+        assert_eq!(
+            did_web_url("example.com"),
+            "https://example.com/.well-known/did.json"
+        );
+        assert_eq!(
+            did_web_url("localhost%3A8080"),
+            "https://localhost:8080/.well-known/did.json"
+        );
+        assert_eq!(
+            did_web_url("example.com:user:alice"),
+            "https://example.com/user/alice/did.json"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 pub mod auth;
 pub mod client;
 pub mod dpop;
+pub mod identity;
 pub mod oauth;
 pub mod session;
+pub mod xrpc;
@@ -7,77 +7,99 @@ use tokio::sync::oneshot;
 use tracing::info;
 
 use super::dpop::DpopKeyPair;
+use super::identity;
 
 const REDIRECT_PORT: u16 = 23847;
 const CLIENT_ID: &str = "http://localhost";
+/// `transition:chat.bsky` is needed for the `chat.bsky.convo` calls proxied through the PDS.
+const SCOPE: &str = "atproto transition:generic transition:chat.bsky";
 
 pub struct OAuthFlow {
+    did: String,
+    pds_endpoint: String,
     dpop: DpopKeyPair,
     code_verifier: String,
     state: String,
     auth_server: String,
     token_endpoint: String,
     par_endpoint: Option<String>,
+    nonce: Option<String>,
 }
 
 impl OAuthFlow {
-    pub async fn start(handle: &str) -> Result<Self> {
+    pub async fn start(handle: &str, service: &str) -> Result<Self> {
         let dpop = DpopKeyPair::generate()?;
         let code_verifier = generate_code_verifier();
         let state = uuid::Uuid::new_v4().to_string();
 
-        let (auth_server, token_endpoint, par_endpoint) = discover_auth_server(handle).await?;
+        let (did, pds_endpoint) = identity::resolve_identity(handle, service).await?;
+        let (auth_server, token_endpoint, par_endpoint) =
+            discover_auth_server(&pds_endpoint).await?;
 
         Ok(OAuthFlow {
+            did,
+            pds_endpoint,
             dpop,
             code_verifier,
             state,
             auth_server,
             token_endpoint,
             par_endpoint,
+            nonce: None,
         })
     }
 
-    pub async fn authorize(&self) -> Result<String> {
+    pub async fn authorize(&mut self) -> Result<String> {
         let code_challenge = {
             let hash = Sha256::digest(self.code_verifier.as_bytes());
             URL_SAFE_NO_PAD.encode(hash)
         };
 
-        let redirect_uri = format!("http://127.0.0.1:{}/callback", REDIRECT_PORT);
+        let redirect_uri = redirect_uri();
+        let client_id = client_id();
 
-        let auth_url = if let Some(ref par_endpoint) = self.par_endpoint {
-            let dpop_proof = self.dpop.create_proof("POST", par_endpoint, None, None)?;
-
-            let client = reqwest::Client::new();
-            let resp = client
-                .post(par_endpoint)
-                .header("DPoP", &dpop_proof)
-                .form(&[
+        let auth_url = if let Some(par_endpoint) = self.par_endpoint.clone() {
+            let body = dpop_post(
+                &self.dpop,
+                &par_endpoint,
+                &[
                     ("response_type", "code"),
-                    ("client_id", CLIENT_ID),
+                    ("client_id", &client_id),
                     ("redirect_uri", &redirect_uri),
                     ("state", &self.state),
                     ("code_challenge", &code_challenge),
                     ("code_challenge_method", "S256"),
-                    ("scope", "atproto transition:generic"),
-                ])
-                .send()
-                .await?;
+                    ("scope", SCOPE),
+                ],
+                &mut self.nonce,
+            )
+            .await?;
 
-            let body: serde_json::Value = resp.json().await?;
             let request_uri = body["request_uri"]
                 .as_str()
                 .ok_or_else(|| anyhow!("No request_uri in PAR response"))?;
 
             format!(
-                "{}?client_id={}&request_uri={}",
-                self.auth_server, CLIENT_ID, request_uri
+                "{}?{}",
+                self.auth_server,
+                url::form_urlencoded::Serializer::new(String::new())
+                    .append_pair("client_id", &client_id)
+                    .append_pair("request_uri", request_uri)
+                    .finish()
             )
         } else {
             format!(
-                "{}?response_type=code&client_id={}&redirect_uri={}&state={}&code_challenge={}&code_challenge_method=S256&scope=atproto+transition:generic",
-                self.auth_server, CLIENT_ID, redirect_uri, self.state, code_challenge
+                "{}?{}",
+                self.auth_server,
+                url::form_urlencoded::Serializer::new(String::new())
+                    .append_pair("response_type", "code")
+                    .append_pair("client_id", &client_id)
+                    .append_pair("redirect_uri", &redirect_uri)
+                    .append_pair("state", &self.state)
+                    .append_pair("code_challenge", &code_challenge)
+                    .append_pair("code_challenge_method", "S256")
+                    .append_pair("scope", SCOPE)
+                    .finish()
             )
         };
 
@@ -88,46 +110,54 @@ impl OAuthFlow {
         Ok(code)
     }
 
-    pub async fn exchange_code(&self, code: &str) -> Result<TokenResponse> {
-        let redirect_uri = format!("http://127.0.0.1:{}/callback", REDIRECT_PORT);
-        let dpop_proof =
-            self.dpop
-                .create_proof("POST", &self.token_endpoint, None, None)?;
-
-        let client = reqwest::Client::new();
-        let resp = client
-            .post(&self.token_endpoint)
-            .header("DPoP", &dpop_proof)
-            .form(&[
+    pub async fn exchange_code(&mut self, code: &str) -> Result<TokenResponse> {
+        let redirect_uri = redirect_uri();
+        let client_id = client_id();
+        let body = dpop_post(
+            &self.dpop,
+            &self.token_endpoint,
+            &[
                 ("grant_type", "authorization_code"),
                 ("code", code),
                 ("redirect_uri", &redirect_uri),
-                ("client_id", CLIENT_ID),
+                ("client_id", &client_id),
                 ("code_verifier", &self.code_verifier),
-            ])
-            .send()
-            .await?;
-
-        let body: serde_json::Value = resp.json().await?;
+            ],
+            &mut self.nonce,
+        )
+        .await?;
+
+        let token = TokenResponse::from_json(&body)?;
+        if token.did != self.did {
+            return Err(anyhow!(
+                "Authorization server issued tokens for {} instead of {}",
+                token.did,
+                self.did
+            ));
+        }
+        Ok(token)
+    }
 
-        Ok(TokenResponse {
-            access_token: body["access_token"]
-                .as_str()
-                .unwrap_or("")
-                .to_string(),
-            refresh_token: body["refresh_token"]
-                .as_str()
-                .map(|s| s.to_string()),
-            did: body["sub"].as_str().unwrap_or("").to_string(),
-            dpop_nonce: body["dpop_nonce"]
-                .as_str()
-                .map(|s| s.to_string()),
-        })
+    /// Consume the flow, keeping its DPoP key and nonce for the authorized session.
+    pub fn into_session(self, token: TokenResponse) -> OAuthSession {
+        OAuthSession {
+            did: token.did,
+            dpop: self.dpop,
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            token_endpoint: self.token_endpoint,
+            auth_nonce: self.nonce,
+            pds_nonce: None,
+        }
     }
 
     pub fn dpop(&self) -> &DpopKeyPair {
         &self.dpop
     }
+
+    pub fn pds_endpoint(&self) -> &str {
+        &self.pds_endpoint
+    }
 }
 
 #[derive(Debug)]
@@ -135,7 +165,77 @@ pub struct TokenResponse {
     pub access_token: String,
     pub refresh_token: Option<String>,
     pub did: String,
-    pub dpop_nonce: Option<String>,
+}
+
+impl TokenResponse {
+    fn from_json(body: &serde_json::Value) -> Result<Self> {
+        let access_token = body["access_token"]
+            .as_str()
+            .ok_or_else(|| anyhow!("No access_token in token response"))?;
+
+        Ok(TokenResponse {
+            access_token: access_token.to_string(),
+            refresh_token: body["refresh_token"].as_str().map(|s| s.to_string()),
+            did: body["sub"].as_str().unwrap_or("").to_string(),
+        })
+    }
+}
+
+/// Tokens and DPoP state for an authorized OAuth session.
+#[derive(Clone)]
+pub struct OAuthSession {
+    pub did: String,
+    pub dpop: DpopKeyPair,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub token_endpoint: String,
+    /// Latest DPoP nonce issued by the authorization server.
+    pub auth_nonce: Option<String>,
+    /// Latest DPoP nonce issued by the PDS.
+    pub pds_nonce: Option<String>,
+}
+
+/// Exchange the session's refresh token for a new access token.
+pub async fn refresh_session(session: &mut OAuthSession) -> Result<()> {
+    let refresh_token = session
+        .refresh_token
+        .clone()
+        .ok_or_else(|| anyhow!("OAuth session has no refresh token"))?;
+    let client_id = client_id();
+    let body = dpop_post(
+        &session.dpop,
+        &session.token_endpoint,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", &refresh_token),
+            ("client_id", &client_id),
+        ],
+        &mut session.auth_nonce,
+    )
+    .await?;
+
+    let token = TokenResponse::from_json(&body)?;
+    session.access_token = token.access_token;
+    if token.refresh_token.is_some() {
+        session.refresh_token = token.refresh_token;
+    }
+    Ok(())
+}
+
+fn redirect_uri() -> String {
+    format!("http://127.0.0.1:{}/callback", REDIRECT_PORT)
+}
+
+/// Loopback clients declare their redirect URI and scope in the client ID itself.
+fn client_id() -> String {
+    format!(
+        "{}?{}",
+        CLIENT_ID,
+        url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("redirect_uri", &redirect_uri())
+            .append_pair("scope", SCOPE)
+            .finish()
+    )
 }
 
 fn generate_code_verifier() -> String {
@@ -143,28 +243,73 @@ fn generate_code_verifier() -> String {
     URL_SAFE_NO_PAD.encode(bytes)
 }
 
-async fn discover_auth_server(
-    handle: &str,
-) -> Result<(String, String, Option<String>)> {
-    let pds_url = format!("https://bsky.social/xrpc/com.atproto.identity.resolveHandle?handle={}", handle);
+/// POST a form to an authorization server endpoint with a DPoP proof, retrying once
+/// when the server asks for a fresh nonce.
+async fn dpop_post(
+    dpop: &DpopKeyPair,
+    url: &str,
+    form: &[(&str, &str)],
+    nonce: &mut Option<String>,
+) -> Result<serde_json::Value> {
+    let client = reqwest::Client::new();
+
+    for _ in 0..2 {
+        let dpop_proof = dpop.create_proof("POST", url, nonce.as_deref(), None)?;
+        let resp = client
+            .post(url)
+            .header("DPoP", &dpop_proof)
+            .form(form)
+            .send()
+            .await?;
+
+        if let Some(new_nonce) = resp.headers().get("DPoP-Nonce").and_then(|v| v.to_str().ok()) {
+            *nonce = Some(new_nonce.to_string());
+        }
+
+        let status = resp.status();
+        let body: serde_json::Value = resp.json().await?;
+        if body["error"] == "use_dpop_nonce" {
+            continue;
+        }
+        if !status.is_success() {
+            let message = body["error_description"]
+                .as_str()
+                .or_else(|| body["error"].as_str())
+                .unwrap_or("OAuth request failed");
+            return Err(anyhow!("{}", message));
+        }
+        return Ok(body);
+    }
+
+    Err(anyhow!("Authorization server rejected the DPoP nonce"))
+}
+
+/// Find the authorization server protecting `pds` and read its endpoints from the
+/// server metadata.
+async fn discover_auth_server(pds: &str) -> Result<(String, String, Option<String>)> {
     let client = reqwest::Client::new();
+    let pds = pds.trim_end_matches('/');
 
-    let resp = client.get(&pds_url).send().await?;
-    let body: serde_json::Value = resp.json().await?;
-    let _did = body["did"].as_str().unwrap_or("");
+    let resource_url = format!("{}/.well-known/oauth-protected-resource", pds);
+    let resource: serde_json::Value = client.get(&resource_url).send().await?.json().await?;
+    let issuer = resource["authorization_servers"][0]
+        .as_str()
+        .unwrap_or(pds)
+        .trim_end_matches('/')
+        .to_string();
 
-    let well_known = "https://bsky.social/.well-known/oauth-authorization-server";
-    let resp = client.get(well_known).send().await?;
+    let well_known = format!("{}/.well-known/oauth-authorization-server", issuer);
+    let resp = client.get(&well_known).send().await?;
     let meta: serde_json::Value = resp.json().await?;
 
     let auth_endpoint = meta["authorization_endpoint"]
         .as_str()
-        .unwrap_or("https://bsky.social/oauth/authorize")
-        .to_string();
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| format!("{}/oauth/authorize", issuer));
     let token_endpoint = meta["token_endpoint"]
         .as_str()
-        .unwrap_or("https://bsky.social/oauth/token")
-        .to_string();
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| format!("{}/oauth/token", issuer));
     let par_endpoint = meta["pushed_authorization_request_endpoint"]
         .as_str()
         .map(|s| s.to_string());
@@ -1,7 +1,15 @@
 use anyhow::Result;
+use atrium_api::agent::atp_agent::store::{AtpSessionStore, MemorySessionStore};
+use atrium_api::agent::atp_agent::AtpSession;
+use atrium_api::agent::AuthorizationProvider;
+use atrium_api::xrpc::types::AuthorizationToken;
+use atrium_common::store::Store;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+use crate::config;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionData {
     pub did: String,
@@ -9,19 +17,56 @@ pub struct SessionData {
     pub access_jwt: String,
     pub refresh_jwt: String,
     pub pds_endpoint: Option<String>,
+    #[serde(default)]
+    pub oauth: Option<OAuthSessionData>,
 }
 
-fn session_dir() -> PathBuf {
-    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
-    PathBuf::from(home).join(".config").join("skyscraper")
+/// Extra state needed to resume an OAuth session; its tokens live in
+/// `access_jwt` / `refresh_jwt`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthSessionData {
+    pub dpop_key: String,
+    pub token_endpoint: String,
 }
 
-fn session_path() -> PathBuf {
-    session_dir().join("session.json")
+/// All saved accounts keyed by DID, plus the one that was used last.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AccountStore {
+    active: Option<String>,
+    accounts: BTreeMap<String, SessionData>,
 }
 
-pub fn save_session(session: &SessionData) -> Result<()> {
-    let dir = session_dir();
+fn accounts_path() -> PathBuf {
+    config::config_dir().join("accounts.json")
+}
+
+/// Single-account file written by earlier versions; imported on first load.
+fn legacy_session_path() -> PathBuf {
+    config::config_dir().join("session.json")
+}
+
+fn load_store() -> Result<AccountStore> {
+    let path = accounts_path();
+    if path.exists() {
+        let json = std::fs::read_to_string(&path)?;
+        return Ok(serde_json::from_str(&json)?);
+    }
+
+    let mut store = AccountStore::default();
+    let legacy = legacy_session_path();
+    if legacy.exists() {
+        let json = std::fs::read_to_string(&legacy)?;
+        let session: SessionData = serde_json::from_str(&json)?;
+        store.active = Some(session.did.clone());
+        store.accounts.insert(session.did.clone(), session);
+        write_store(&store)?;
+        std::fs::remove_file(&legacy)?;
+    }
+    Ok(store)
+}
+
+fn write_store(store: &AccountStore) -> Result<()> {
+    let dir = config::config_dir();
     std::fs::create_dir_all(&dir)?;
 
     // Restrict directory permissions to owner-only
@@ -31,8 +76,8 @@ pub fn save_session(session: &SessionData) -> Result<()> {
         std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
     }
 
-    let json = serde_json::to_string_pretty(session)?;
-    let path = session_path();
+    let json = serde_json::to_string_pretty(store)?;
+    let path = accounts_path();
     std::fs::write(&path, &json)?;
 
     // Restrict file permissions to owner-only
@@ -45,24 +90,116 @@ pub fn save_session(session: &SessionData) -> Result<()> {
     Ok(())
 }
 
+/// Save (or replace) an account's session and make it the active account.
+pub fn save_session(session: &SessionData) -> Result<()> {
+    let mut store = load_store()?;
+    store.active = Some(session.did.clone());
+    store.accounts.insert(session.did.clone(), session.clone());
+    write_store(&store)
+}
+
+/// The session of the most recently used account.
 pub fn load_session() -> Result<Option<SessionData>> {
-    let path = session_path();
-    if !path.exists() {
-        return Ok(None);
+    let store = load_store()?;
+    Ok(store
+        .active
+        .as_ref()
+        .and_then(|did| store.accounts.get(did))
+        .cloned())
+}
+
+/// Find a saved account by DID or handle.
+pub fn find_account(identifier: &str) -> Result<Option<SessionData>> {
+    let identifier = identifier.trim_start_matches('@');
+    let store = load_store()?;
+    Ok(store
+        .accounts
+        .into_values()
+        .find(|s| s.did == identifier || s.handle == identifier))
+}
+
+pub fn list_accounts() -> Result<Vec<SessionData>> {
+    Ok(load_store()?.accounts.into_values().collect())
+}
+
+pub fn set_active_account(did: &str) -> Result<()> {
+    let mut store = load_store()?;
+    if store.accounts.contains_key(did) {
+        store.active = Some(did.to_string());
+        write_store(&store)?;
     }
-    let json = std::fs::read_to_string(&path)?;
-    let session: SessionData = serde_json::from_str(&json)?;
-    Ok(Some(session))
+    Ok(())
 }
 
-pub fn clear_session() -> Result<()> {
-    let path = session_path();
-    if path.exists() {
-        std::fs::remove_file(&path)?;
+/// Write refreshed tokens back to the saved account for `did`, if there is one.
+pub fn update_session_tokens(did: &str, access_jwt: &str, refresh_jwt: Option<&str>) -> Result<()> {
+    let mut store = load_store()?;
+    if let Some(session) = store.accounts.get_mut(did) {
+        session.access_jwt = access_jwt.to_string();
+        if let Some(refresh_jwt) = refresh_jwt {
+            session.refresh_jwt = refresh_jwt.to_string();
+        }
+        write_store(&store)?;
     }
     Ok(())
 }
 
+/// Forget an account. If it was active, no account is active afterwards.
+pub fn remove_account(did: &str) -> Result<()> {
+    let mut store = load_store()?;
+    store.accounts.remove(did);
+    if store.active.as_deref() == Some(did) {
+        store.active = None;
+    }
+    write_store(&store)
+}
+
+/// Forget the active account (logout).
+pub fn clear_session() -> Result<()> {
+    let store = load_store()?;
+    match store.active {
+        Some(did) => remove_account(&did),
+        None => Ok(()),
+    }
+}
+
 pub fn get_last_handle() -> Option<String> {
     load_session().ok()?.map(|s| s.handle)
 }
+
+/// Session store for `BskyAgent` that keeps the live session in memory and persists
+/// every token rotation (login, resume, refresh) to the saved account.
+#[derive(Default)]
+pub struct FileSessionStore {
+    inner: MemorySessionStore,
+}
+
+impl Store<(), AtpSession> for FileSessionStore {
+    type Error = std::io::Error;
+
+    async fn get(&self, key: &()) -> Result<Option<AtpSession>, Self::Error> {
+        self.inner.get(key).await.map_err(std::io::Error::other)
+    }
+
+    async fn set(&self, key: (), value: AtpSession) -> Result<(), Self::Error> {
+        update_session_tokens(value.did.as_str(), &value.access_jwt, Some(&value.refresh_jwt))
+            .map_err(std::io::Error::other)?;
+        self.inner.set(key, value).await.map_err(std::io::Error::other)
+    }
+
+    async fn del(&self, key: &()) -> Result<(), Self::Error> {
+        self.inner.del(key).await.map_err(std::io::Error::other)
+    }
+
+    async fn clear(&self) -> Result<(), Self::Error> {
+        self.inner.clear().await.map_err(std::io::Error::other)
+    }
+}
+
+impl AuthorizationProvider for FileSessionStore {
+    async fn authorization_token(&self, is_refresh: bool) -> Option<AuthorizationToken> {
+        self.inner.authorization_token(is_refresh).await
+    }
+}
+
+impl AtpSessionStore for FileSessionStore {}
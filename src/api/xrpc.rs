@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use atrium_api::xrpc::http::{header, Request, Response, StatusCode};
+use atrium_api::xrpc::{HttpClient, XrpcClient};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::oauth::{self, OAuthSession};
+use super::session;
+
+type HttpResult =
+    Result<Response<Vec<u8>>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+/// HTTP transport underneath `BskyAgent`.
+///
+/// App-password sessions pass straight through with the agent's Bearer token. Once an
+/// OAuth session is installed, each request is re-authorized with the DPoP-bound access
+/// token and a fresh proof, and an expired token is refreshed before retrying.
+pub struct XrpcHttpClient {
+    base_uri: String,
+    http: reqwest::Client,
+    oauth: Arc<RwLock<Option<OAuthSession>>>,
+}
+
+impl XrpcHttpClient {
+    pub fn new(base_uri: &str, oauth: Arc<RwLock<Option<OAuthSession>>>) -> Self {
+        XrpcHttpClient {
+            base_uri: base_uri.to_string(),
+            http: reqwest::Client::new(),
+            oauth,
+        }
+    }
+
+    async fn execute(&self, request: Request<Vec<u8>>) -> HttpResult {
+        let response = self.http.execute(request.try_into()?).await?;
+        let mut builder = Response::builder().status(response.status());
+        for (k, v) in response.headers() {
+            builder = builder.header(k, v);
+        }
+        builder
+            .body(response.bytes().await?.to_vec())
+            .map_err(Into::into)
+    }
+
+    async fn send_dpop(&self, request: Request<Vec<u8>>) -> HttpResult {
+        let (parts, body) = request.into_parts();
+        let uri = parts.uri.to_string();
+        let htu = uri.split('?').next().unwrap_or(&uri).to_string();
+        let mut refreshed = false;
+
+        for _ in 0..3 {
+            let (access_token, proof) = {
+                let guard = self.oauth.read().await;
+                let session = guard
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("OAuth session was cleared"))?;
+                let proof = session.dpop.create_proof(
+                    parts.method.as_str(),
+                    &htu,
+                    session.pds_nonce.as_deref(),
+                    Some(&session.access_token),
+                )?;
+                (session.access_token.clone(), proof)
+            };
+
+            let mut builder = Request::builder()
+                .method(parts.method.clone())
+                .uri(parts.uri.clone());
+            for (k, v) in &parts.headers {
+                if k != header::AUTHORIZATION {
+                    builder = builder.header(k, v);
+                }
+            }
+            let request = builder
+                .header(header::AUTHORIZATION, format!("DPoP {}", access_token))
+                .header("DPoP", proof)
+                .body(body.clone())?;
+
+            let response = self.execute(request).await?;
+
+            if let Some(nonce) = response
+                .headers()
+                .get("DPoP-Nonce")
+                .and_then(|v| v.to_str().ok())
+            {
+                if let Some(session) = self.oauth.write().await.as_mut() {
+                    session.pds_nonce = Some(nonce.to_string());
+                }
+            }
+
+            if response.status() != StatusCode::UNAUTHORIZED {
+                return Ok(response);
+            }
+
+            let challenge = response
+                .headers()
+                .get(header::WWW_AUTHENTICATE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("");
+            if challenge.contains("use_dpop_nonce") {
+                continue;
+            }
+            if challenge.contains("invalid_token") && !refreshed {
+                refreshed = true;
+                self.refresh().await?;
+                continue;
+            }
+            return Ok(response);
+        }
+
+        Err(anyhow!("PDS kept rejecting the DPoP proof").into())
+    }
+
+    async fn refresh(&self) -> anyhow::Result<()> {
+        let mut guard = self.oauth.write().await;
+        let session = guard
+            .as_mut()
+            .ok_or_else(|| anyhow!("OAuth session was cleared"))?;
+        oauth::refresh_session(session).await?;
+        info!("Refreshed OAuth access token");
+        session::update_session_tokens(
+            &session.did,
+            &session.access_token,
+            session.refresh_token.as_deref(),
+        )?;
+        Ok(())
+    }
+}
+
+impl HttpClient for XrpcHttpClient {
+    async fn send_http(&self, request: Request<Vec<u8>>) -> HttpResult {
+        if self.oauth.read().await.is_some() {
+            self.send_dpop(request).await
+        } else {
+            self.execute(request).await
+        }
+    }
+}
+
+impl XrpcClient for XrpcHttpClient {
+    fn base_uri(&self) -> String {
+        self.base_uri.clone()
+    }
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use crossterm::event::{Event, KeyCode, KeyModifiers};
@@ -9,17 +10,35 @@ use tracing::error;
 
 use crate::action::Action;
 use crate::api::auth;
-use crate::api::client::{BlueskyClient, ReplyRef};
+use crate::api::client::{self, BlueskyClient, QuoteRef, ReplyRef};
 use crate::api::session;
 use crate::config::AppConfig;
+use crate::drafts;
 use crate::event::{self, EventHandler};
-use crate::models::feed::FeedState;
-use crate::models::profile::ProfileViewModel;
+use crate::models::chat::{ConversationState, ConvoListState};
+use crate::models::feed::{FeedSource, FeedState, SavedFeed};
+use crate::models::follows::{FollowListKind, FollowListState};
+use crate::models::lists::{ListDetailState, ListPurpose, ListTab, ListViewModel, ListsState};
+use crate::models::moderation::{ModerationPrefs, ReportSubject};
+use crate::models::notification::{NotificationReason, NotificationState};
+use crate::models::post::FacetKind;
+use crate::models::profile::{AuthorFeedTab, ProfileViewModel};
+use crate::models::search::{SearchMode, SearchQuery, SearchSort, SearchState};
 use crate::models::thread::ThreadViewModel;
-use crate::tui::Tui;
+use crate::tui::{self, Tui};
+use crate::ui::accounts::AccountPicker;
 use crate::ui::composer::Composer;
+use crate::ui::confirm::ConfirmDialog;
+use crate::ui::drafts::DraftPicker;
+use crate::ui::feeds::FeedPicker;
 use crate::ui::login::LoginForm;
+use crate::ui::messages::MessageInput;
+use crate::ui::lists::ListPicker;
+use crate::ui::muted_words::MutedWordPrompt;
+use crate::ui::report::ReportDialog;
+use crate::ui::search::SearchPrompt;
 use crate::ui::Component;
+use crate::utils::editor;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
@@ -27,9 +46,22 @@ pub enum Screen {
     Timeline,
     Thread,
     Profile,
+    Notifications,
+    Search,
+    FollowList,
+    Lists,
+    ListDetail,
+    Messages,
+    Conversation,
+    MutedWords,
     About,
 }
 
+const UNREAD_POLL_INTERVAL: Duration = Duration::from_secs(60);
+/// How often an open conversation checks for new messages.
+const CHAT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+const DRAFT_AUTOSAVE_INTERVAL: Duration = Duration::from_secs(2);
+
 pub struct App {
     should_quit: bool,
     screen: Screen,
@@ -39,13 +71,31 @@ pub struct App {
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
     handle: Option<String>,
+    /// DID of the signed-in account, for checking post ownership.
+    did: Option<String>,
     prefer_app_password: bool,
 
     // State
-    timeline: FeedState,
+    /// Saved feeds from preferences; the timeline screen shows `feeds[active_feed]`.
+    feeds: Vec<SavedFeed>,
+    active_feed: usize,
     thread: Option<ThreadViewModel>,
+    /// Why the thread on screen couldn't be loaded.
+    thread_error: Option<String>,
     profile: Option<ProfileViewModel>,
     profile_feed: FeedState,
+    profile_tab: AuthorFeedTab,
+    notifications: NotificationState,
+    search: SearchState,
+    /// Followers/follows of the profile it was opened from.
+    follow_list: Option<FollowListState>,
+    lists: Option<ListsState>,
+    list_detail: Option<ListDetailState>,
+    convos: ConvoListState,
+    conversation: Option<ConversationState>,
+    /// Muted words and hidden posts, applied to posts as they load.
+    moderation: ModerationPrefs,
+    muted_word_selected: usize,
     error_message: Option<String>,
 
     // Active data-loading task (aborted when a new load starts or on navigation)
@@ -53,8 +103,25 @@ pub struct App {
 
     // Modals
     login_form: LoginForm,
+    account_picker: AccountPicker,
+    show_account_picker: bool,
+    feed_picker: FeedPicker,
+    show_feed_picker: bool,
+    draft_picker: DraftPicker,
+    show_draft_picker: bool,
+    confirm: Option<ConfirmDialog>,
+    search_prompt: SearchPrompt,
+    show_search_prompt: bool,
+    message_input: MessageInput,
+    show_message_input: bool,
+    muted_word_prompt: MutedWordPrompt,
+    show_muted_word_prompt: bool,
+    report: Option<ReportDialog>,
+    list_picker: Option<ListPicker>,
     composer: Composer,
     show_composer: bool,
+    /// Set by `OpenExternalEditor`; the run loop owns the terminal and does the handoff.
+    editor_requested: bool,
     show_promo: bool,
 }
 
@@ -63,7 +130,7 @@ impl App {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
         let default_handle = handle
             .clone()
-            .or_else(|| session::get_last_handle());
+            .or_else(session::get_last_handle);
 
         let show_promo = !AppConfig::load()
             .map(|c| c.promo_dismissed)
@@ -78,34 +145,73 @@ impl App {
             action_tx,
             action_rx,
             handle: handle.clone(),
+            did: None,
             prefer_app_password,
-            timeline: FeedState::new(),
+            feeds: vec![SavedFeed::following()],
+            active_feed: 0,
             thread: None,
+            thread_error: None,
             profile: None,
             profile_feed: FeedState::new(),
+            profile_tab: AuthorFeedTab::Posts,
+            notifications: NotificationState::new(),
+            search: SearchState::new(),
+            follow_list: None,
+            lists: None,
+            list_detail: None,
+            convos: ConvoListState::new(),
+            conversation: None,
+            moderation: ModerationPrefs::default(),
+            muted_word_selected: 0,
             error_message: None,
             active_load: None,
-            login_form: LoginForm::new(default_handle),
+            login_form: LoginForm::new(default_handle, prefer_app_password),
+            account_picker: AccountPicker::new(),
+            show_account_picker: false,
+            feed_picker: FeedPicker::new(),
+            show_feed_picker: false,
+            draft_picker: DraftPicker::new(),
+            show_draft_picker: false,
+            confirm: None,
+            search_prompt: SearchPrompt::new(""),
+            show_search_prompt: false,
+            message_input: MessageInput::new(""),
+            show_message_input: false,
+            muted_word_prompt: MutedWordPrompt::new(),
+            show_muted_word_prompt: false,
+            report: None,
+            list_picker: None,
             composer: Composer::new(),
             show_composer: false,
+            editor_requested: false,
             show_promo,
         }
     }
 
     pub async fn run(&mut self, terminal: &mut Tui) -> Result<()> {
-        // Try to restore session
-        match auth::try_restore_session(&self.client).await {
+        // Try to restore the requested (or last used) account
+        match auth::try_restore_session(&self.client, self.handle.as_deref()).await {
             auth::AuthResult::Success(handle) => {
                 self.screen = Screen::Timeline;
                 self.handle = Some(handle);
+                self.did = self.client.did().await;
                 self.dispatch(Action::RefreshTimeline);
+                self.dispatch(Action::LoadSavedFeeds);
+                self.dispatch(Action::LoadModerationPrefs);
             }
             auth::AuthResult::NeedsLogin => {
+                self.handle = None;
+                self.did = None;
                 self.screen = Screen::Login;
+                self.account_picker.reload(None);
+                self.show_account_picker = !self.account_picker.is_empty();
             }
         }
 
         let mut events = EventHandler::new();
+        let mut unread_poll = tokio::time::interval(UNREAD_POLL_INTERVAL);
+        let mut draft_autosave = tokio::time::interval(DRAFT_AUTOSAVE_INTERVAL);
+        let mut chat_poll = tokio::time::interval(CHAT_POLL_INTERVAL);
 
         loop {
             terminal.draw(|frame| self.draw(frame))?;
@@ -117,6 +223,30 @@ impl App {
                 Some(action) = self.action_rx.recv() => {
                     self.update(action).await;
                 }
+                _ = unread_poll.tick() => {
+                    if self.screen != Screen::Login {
+                        self.dispatch(Action::RefreshUnreadCount);
+                        if !self.convos.unavailable {
+                            self.dispatch(Action::RefreshChatUnreadCount);
+                        }
+                    }
+                }
+                _ = chat_poll.tick() => {
+                    if self.screen == Screen::Conversation && !self.convos.unavailable {
+                        self.dispatch(Action::PollConversation);
+                    }
+                }
+                _ = draft_autosave.tick() => {
+                    self.autosave_draft();
+                }
+            }
+
+            if self.editor_requested {
+                self.editor_requested = false;
+                // Stop reading stdin so the editor gets every keystroke
+                drop(events);
+                self.edit_in_external_editor(terminal)?;
+                events = EventHandler::new();
             }
 
             if self.should_quit {
@@ -127,6 +257,19 @@ impl App {
         Ok(())
     }
 
+    fn edit_in_external_editor(&mut self, terminal: &mut Tui) -> Result<()> {
+        tui::restore()?;
+        let edited = editor::edit(&self.composer.draft_text());
+        *terminal = tui::init()?;
+        terminal.clear()?;
+
+        match edited {
+            Ok(text) => self.composer.set_draft_text(&text),
+            Err(e) => self.dispatch(Action::Error(e.to_string())),
+        }
+        Ok(())
+    }
+
     fn handle_event(&mut self, event: Event) {
         match event {
             Event::Key(key) => {
@@ -140,6 +283,13 @@ impl App {
                 }
 
                 // Let modals handle keys first
+                if let Some(ref mut confirm) = self.confirm {
+                    if let Some(action) = confirm.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
                 if self.show_composer {
                     if let Some(action) = self.composer.handle_key_event(key) {
                         self.dispatch(action);
@@ -147,7 +297,68 @@ impl App {
                     return;
                 }
 
+                if self.show_account_picker {
+                    if let Some(action) = self.account_picker.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if self.show_feed_picker {
+                    if let Some(action) = self.feed_picker.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if self.show_draft_picker {
+                    if let Some(action) = self.draft_picker.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if self.show_search_prompt {
+                    if let Some(action) = self.search_prompt.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if self.show_muted_word_prompt {
+                    if let Some(action) = self.muted_word_prompt.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if let Some(ref mut report) = self.report {
+                    if let Some(action) = report.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if let Some(ref mut picker) = self.list_picker {
+                    if let Some(action) = picker.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
+                if self.show_message_input && self.screen == Screen::Conversation {
+                    if let Some(action) = self.message_input.handle_key_event(key) {
+                        self.dispatch(action);
+                    }
+                    return;
+                }
+
                 if self.screen == Screen::Login {
+                    // When adding an account while signed in, Esc returns instead of quitting
+                    if key.code == KeyCode::Esc && self.handle.is_some() {
+                        self.dispatch(Action::GoBack);
+                        return;
+                    }
                     if let Some(action) = self.login_form.handle_key_event(key) {
                         self.dispatch(action);
                     }
@@ -160,14 +371,26 @@ impl App {
                     return;
                 }
 
-                // Global key handling
+                // Screen keys first, then the global ones
+                let screen_action = match self.screen {
+                    Screen::Conversation => crate::ui::messages::conversation_key_to_action(key),
+                    Screen::Messages => crate::ui::messages::convo_list_key_to_action(key),
+                    Screen::Notifications => crate::ui::notifications::key_to_action(key),
+                    Screen::MutedWords => crate::ui::muted_words::key_to_action(key),
+                    Screen::Lists | Screen::ListDetail => crate::ui::lists::key_to_action(key),
+                    _ => None,
+                };
+                if let Some(action) = screen_action {
+                    self.dispatch(action);
+                    return;
+                }
+
                 if let Some(action) =
                     event::key_to_action(key, self.show_composer, self.screen == Screen::Login)
                 {
-                    // Special handling for 'r' to populate reply_to
-                    let action = if matches!(key.code, KeyCode::Char('r'))
-                        && key.modifiers == KeyModifiers::NONE
-                    {
+                    let is_reply = key.code == KeyCode::Char('r') && key.modifiers == KeyModifiers::NONE;
+                    let action = if is_reply {
+                        // Special handling for 'r' to populate reply_to
                         self.make_reply_action()
                             .unwrap_or(Action::OpenComposer {
                                 reply_to: None,
@@ -179,28 +402,115 @@ impl App {
                     self.dispatch(action);
                 }
             }
+            Event::Paste(text) => {
+                if self.show_composer {
+                    self.composer.handle_paste(&text);
+                } else if self.show_search_prompt {
+                    self.search_prompt.handle_paste(&text);
+                } else if self.show_muted_word_prompt {
+                    self.muted_word_prompt.handle_paste(&text);
+                } else if let Some(ref mut report) = self.report {
+                    report.handle_paste(&text);
+                } else if self.show_message_input && self.screen == Screen::Conversation {
+                    self.message_input.handle_paste(&text);
+                } else if self.screen == Screen::Login && !self.show_account_picker {
+                    self.login_form.handle_paste(&text);
+                }
+            }
             Event::Resize(_, _) => {}
             _ => {}
         }
     }
 
     fn make_reply_action(&self) -> Option<Action> {
-        let post = match self.screen {
-            Screen::Timeline => self.timeline.selected_post(),
-            Screen::Thread => self.thread.as_ref().map(|t| &t.focal),
+        self.selected_post().map(|p| {
+            let (root_uri, root_cid) = p
+                .reply_root
+                .clone()
+                .unwrap_or_else(|| (p.uri.clone(), p.cid.clone()));
+            Action::OpenComposer {
+                reply_to: Some(ReplyRef {
+                    parent_uri: p.uri.clone(),
+                    parent_cid: p.cid.clone(),
+                    root_uri,
+                    root_cid,
+                }),
+                reply_to_author: Some(p.author_display_name.clone()),
+            }
+        })
+    }
+
+    /// The post under the cursor on screens that list posts.
+    fn selected_post(&self) -> Option<&crate::models::post::PostViewModel> {
+        match self.screen {
+            Screen::Timeline => self.timeline().selected_post(),
+            Screen::Thread => self.thread.as_ref().and_then(|t| t.selected_post()),
             Screen::Profile => self.profile_feed.selected_post(),
+            Screen::Search => self.selected_search_post(),
+            Screen::ListDetail => self
+                .list_detail
+                .as_ref()
+                .filter(|d| d.tab == ListTab::Posts)
+                .and_then(|d| d.feed.selected_post()),
             _ => None,
+        }
+    }
+
+    /// Show `composer`, picking up a saved draft for the same reply or quote target.
+    fn show_composer_with_draft(&mut self, mut composer: Composer) {
+        composer.set_known_tags(self.recent_tags());
+        if let Some(draft) = drafts::load(self.drafts_owner(), &composer.draft_key()) {
+            composer.load_draft(draft);
+        }
+        self.composer = composer;
+        self.show_composer = true;
+    }
+
+    /// Write the open draft to disk if it changed since the last save.
+    fn autosave_draft(&mut self) {
+        if !self.show_composer || !self.composer.take_dirty() {
+            return;
+        }
+        let draft = self.composer.to_draft();
+        let result = if draft.is_empty() {
+            drafts::delete(self.drafts_owner(), &draft.key)
+        } else {
+            drafts::save(self.drafts_owner(), &draft)
         };
+        if let Err(e) = result {
+            error!("Failed to autosave draft: {}", e);
+        }
+    }
 
-        post.map(|p| Action::OpenComposer {
-            reply_to: Some(ReplyRef {
-                parent_uri: p.uri.clone(),
-                parent_cid: p.cid.clone(),
-                root_uri: p.uri.clone(),
-                root_cid: p.cid.clone(),
-            }),
-            reply_to_author: Some(p.author_display_name.clone()),
-        })
+    /// Hashtags seen in loaded posts, for `#` completion in the composer.
+    fn recent_tags(&self) -> Vec<String> {
+        let thread_posts = self
+            .thread
+            .iter()
+            .flat_map(|t| {
+                t.parents
+                    .iter()
+                    .chain(t.replies.iter().map(|n| &n.post))
+                    .filter_map(|p| p.post())
+                    .chain([&t.focal])
+            });
+        let posts = self
+            .feeds
+            .iter()
+            .flat_map(|f| &f.state.posts)
+            .chain(&self.profile_feed.posts)
+            .chain(&self.search.posts.posts)
+            .chain(thread_posts);
+
+        let mut tags: Vec<String> = Vec::new();
+        for facet in posts.flat_map(|p| &p.facets) {
+            if let FacetKind::Tag(ref tag) = facet.kind {
+                if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    tags.push(tag.clone());
+                }
+            }
+        }
+        tags
     }
 
     fn dismiss_promo(&mut self) {
@@ -229,6 +539,7 @@ impl App {
     async fn update(&mut self, action: Action) {
         match action {
             Action::Quit => {
+                self.autosave_draft();
                 self.cancel_load();
                 self.should_quit = true;
             }
@@ -236,25 +547,34 @@ impl App {
             Action::ShowLogin => self.screen = Screen::Login,
 
             Action::SubmitLogin { handle, password } => {
-                let client = self.client.clone();
+                let service = self.client.service().to_string();
                 let tx = self.action_tx.clone();
                 tokio::spawn(async move {
-                    match auth::login_with_app_password(&client, &handle, &password).await {
-                        Ok(h) => {
-                            let _ = tx.send(Action::LoginSuccess(h));
-                        }
-                        Err(e) => {
-                            let _ = tx.send(Action::LoginFailed(e.to_string()));
-                        }
+                    // Signed in on a fresh client so a failed or cancelled add leaves the
+                    // active account untouched
+                    let result = async {
+                        let client = BlueskyClient::new(&service).await?;
+                        let handle =
+                            auth::login_with_app_password(&client, &handle, &password).await?;
+                        Ok::<_, anyhow::Error>((client, handle))
                     }
+                    .await;
+                    let _ = tx.send(Self::login_result(result));
                 });
             }
 
-            Action::LoginSuccess(handle) => {
-                self.handle = Some(handle);
-                self.screen = Screen::Timeline;
-                self.login_form.clear_error();
-                self.dispatch(Action::RefreshTimeline);
+            Action::SubmitOAuthLogin { handle } => {
+                let service = self.client.service().to_string();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    let result = async {
+                        let client = BlueskyClient::new(&service).await?;
+                        let handle = auth::login_with_oauth(&client, &handle).await?;
+                        Ok::<_, anyhow::Error>((client, handle))
+                    }
+                    .await;
+                    let _ = tx.send(Self::login_result(result));
+                });
             }
 
             Action::LoginFailed(msg) => {
@@ -263,19 +583,84 @@ impl App {
 
             Action::Logout => {
                 let _ = auth::logout();
+                self.reset_account_state();
                 self.handle = None;
+                self.did = None;
+                self.screen = Screen::Login;
+                self.account_picker.reload(None);
+                self.show_account_picker = !self.account_picker.is_empty();
+            }
+
+            Action::ShowAccountSwitcher => {
+                let did = self.client.did().await;
+                self.account_picker.reload(did);
+                self.show_account_picker = true;
+            }
+
+            Action::CloseAccountSwitcher => {
+                self.show_account_picker = false;
+            }
+
+            Action::AddAccount => {
+                self.show_account_picker = false;
+                if self.screen != Screen::Login {
+                    self.screen_stack.push(self.screen.clone());
+                }
+                self.login_form = LoginForm::new(None, self.prefer_app_password);
                 self.screen = Screen::Login;
-                self.timeline = FeedState::new();
+            }
+
+            Action::SwitchAccount(did) => {
+                self.show_account_picker = false;
+                let service = self.client.service().to_string();
+                let on_login = self.screen == Screen::Login;
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    let result = async {
+                        let client = BlueskyClient::new(&service).await?;
+                        let handle = auth::switch_account(&client, &did).await?;
+                        Ok::<_, anyhow::Error>((client, handle))
+                    }
+                    .await;
+                    match result {
+                        Ok((client, handle)) => {
+                            let _ = tx.send(Action::AccountSwitched {
+                                client: Arc::new(client),
+                                handle,
+                            });
+                        }
+                        Err(e) if on_login => {
+                            let _ = tx.send(Action::LoginFailed(e.to_string()));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::AccountSwitched { client, handle } => {
+                self.client = client;
+                self.reset_account_state();
+                self.handle = Some(handle);
+                self.did = self.client.did().await;
+                self.screen = Screen::Timeline;
+                self.login_form.clear_error();
+                self.dispatch(Action::RefreshTimeline);
+                self.dispatch(Action::LoadSavedFeeds);
+                self.dispatch(Action::LoadModerationPrefs);
             }
 
             Action::RefreshTimeline => {
-                self.timeline.loading = true;
+                self.timeline_mut().loading = true;
+                let source = self.feeds[self.active_feed].source.clone();
                 let client = self.client.clone();
                 let tx = self.action_tx.clone();
                 self.spawn_load(async move {
-                    match client.get_timeline(None, Some(50u8)).await {
+                    match client.get_feed(&source, None).await {
                         Ok((posts, cursor)) => {
                             let _ = tx.send(Action::TimelineLoaded {
+                                source,
                                 posts,
                                 cursor,
                                 append: false,
@@ -289,17 +674,19 @@ impl App {
             }
 
             Action::LoadMoreTimeline => {
-                if self.timeline.loading || self.timeline.cursor.is_none() {
+                if self.timeline().loading || self.timeline().cursor.is_none() {
                     return;
                 }
-                self.timeline.loading = true;
+                self.timeline_mut().loading = true;
+                let source = self.feeds[self.active_feed].source.clone();
                 let client = self.client.clone();
-                let cursor = self.timeline.cursor.clone();
+                let cursor = self.timeline().cursor.clone();
                 let tx = self.action_tx.clone();
                 self.spawn_load(async move {
-                    match client.get_timeline(cursor, Some(50u8)).await {
+                    match client.get_feed(&source, cursor).await {
                         Ok((posts, cursor)) => {
                             let _ = tx.send(Action::TimelineLoaded {
+                                source,
                                 posts,
                                 cursor,
                                 append: true,
@@ -313,131 +700,129 @@ impl App {
             }
 
             Action::TimelineLoaded {
-                posts,
+                source,
+                mut posts,
                 cursor,
                 append,
             } => {
-                if append {
-                    self.timeline.append_posts(posts, cursor);
-                } else {
-                    self.timeline.replace_posts(posts, cursor);
+                self.moderate(&mut posts, false);
+                if let Some(feed) = self.feeds.iter_mut().find(|f| f.source == source) {
+                    if append {
+                        feed.state.append_posts(posts, cursor);
+                    } else {
+                        feed.state.replace_posts(posts, cursor);
+                    }
                 }
             }
 
-            Action::SelectNext => match self.screen {
-                Screen::Timeline => {
-                    self.timeline.select_next();
-                    if self.timeline.near_bottom(20) {
-                        self.dispatch(Action::LoadMoreTimeline);
+            Action::LoadSavedFeeds => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.get_saved_feeds().await {
+                        Ok(feeds) => {
+                            let _ = tx.send(Action::SavedFeedsLoaded(feeds));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
                     }
-                }
-                Screen::Profile => {
-                    self.profile_feed.select_next();
-                }
-                Screen::Thread => {
-                    // Thread navigation is handled at draw level
-                }
-                _ => {}
-            },
-
-            Action::SelectPrev => match self.screen {
-                Screen::Timeline => self.timeline.select_prev(),
-                Screen::Profile => self.profile_feed.select_prev(),
-                _ => {}
-            },
-
-            Action::ScrollToTop => match self.screen {
-                Screen::Timeline => self.timeline.select_first(),
-                Screen::Profile => self.profile_feed.select_first(),
-                _ => {}
-            },
-
-            Action::ScrollToBottom => match self.screen {
-                Screen::Timeline => self.timeline.select_last(),
-                Screen::Profile => self.profile_feed.select_last(),
-                _ => {}
-            },
+                });
+            }
 
-            Action::OpenThread => {
-                let uri = match self.screen {
-                    Screen::Timeline => {
-                        self.timeline.selected_post().map(|p| p.uri.clone())
-                    }
-                    Screen::Profile => {
-                        self.profile_feed.selected_post().map(|p| p.uri.clone())
+            Action::SavedFeedsLoaded(mut feeds) => {
+                // Keep posts already loaded for feeds that are still saved
+                let active_source = self.feeds[self.active_feed].source.clone();
+                for feed in &mut feeds {
+                    if let Some(old) = self.feeds.iter_mut().find(|f| f.source == feed.source) {
+                        feed.state = std::mem::take(&mut old.state);
                     }
-                    _ => None,
-                };
-
-                if let Some(uri) = uri {
-                    let client = self.client.clone();
-                    let tx = self.action_tx.clone();
-                    self.screen_stack.push(self.screen.clone());
-                    self.screen = Screen::Thread;
-                    self.spawn_load(async move {
-                        match client.get_thread(&uri).await {
-                            Ok(thread) => {
-                                let _ = tx.send(Action::ThreadLoaded(thread));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(Action::Error(e.to_string()));
-                            }
-                        }
-                    });
                 }
+                self.active_feed = feeds
+                    .iter()
+                    .position(|f| f.source == active_source)
+                    .unwrap_or(0);
+                self.feeds = feeds;
+                self.feed_picker.set_feeds(&self.feeds, self.active_feed);
             }
 
-            Action::ThreadLoaded(thread) => {
-                self.thread = thread;
+            Action::NextFeed | Action::PrevFeed if self.screen == Screen::Profile => {
+                let Some(ref profile) = self.profile else {
+                    return;
+                };
+                let tabs = AuthorFeedTab::available(self.did.as_deref() == Some(profile.did.as_str()));
+                let pos = tabs.iter().position(|&t| t == self.profile_tab).unwrap_or(0);
+                let next = if matches!(action, Action::NextFeed) {
+                    (pos + 1) % tabs.len()
+                } else {
+                    (pos + tabs.len() - 1) % tabs.len()
+                };
+                self.dispatch(Action::SwitchProfileTab(tabs[next]));
             }
 
-            Action::GoBack => {
-                self.cancel_load();
-                if let Some(prev) = self.screen_stack.pop() {
-                    self.screen = prev;
-                    self.thread = None;
+            Action::NextFeed | Action::PrevFeed if self.screen == Screen::ListDetail => {
+                let Some(ref mut detail) = self.list_detail else {
+                    return;
+                };
+                if detail.has_feed() {
+                    detail.tab = match detail.tab {
+                        ListTab::Posts => ListTab::Members,
+                        ListTab::Members => ListTab::Posts,
+                    };
                 }
             }
 
-            Action::SwitchTab(idx) => {
-                self.active_tab = idx;
-                match idx {
-                    0 => {
-                        self.screen = Screen::Timeline;
-                        if self.timeline.posts.is_empty() {
-                            self.dispatch(Action::RefreshTimeline);
-                        }
-                    }
-                    1 => {
-                        if let Some(handle) = self.handle.clone() {
-                            self.dispatch(Action::LoadProfile(handle));
-                        }
+            Action::NextFeed | Action::PrevFeed => {
+                if self.screen != Screen::Timeline {
+                    return;
+                }
+                let pinned: Vec<usize> = (0..self.feeds.len())
+                    .filter(|&i| self.feeds[i].pinned || i == self.active_feed)
+                    .collect();
+                let pos = pinned.iter().position(|&i| i == self.active_feed).unwrap_or(0);
+                let next = if matches!(action, Action::NextFeed) {
+                    (pos + 1) % pinned.len()
+                } else {
+                    (pos + pinned.len() - 1) % pinned.len()
+                };
+                self.dispatch(Action::SwitchFeed(self.feeds[pinned[next]].id.clone()));
+            }
+
+            Action::SwitchFeed(id) => {
+                self.show_feed_picker = false;
+                if let Some(idx) = self.feeds.iter().position(|f| f.id == id) {
+                    self.cancel_load();
+                    self.timeline_mut().loading = false;
+                    self.active_feed = idx;
+                    self.active_tab = 0;
+                    self.screen = Screen::Timeline;
+                    if self.timeline().posts.is_empty() {
+                        self.dispatch(Action::RefreshTimeline);
                     }
-                    _ => {}
                 }
             }
 
-            Action::OpenComposer {
-                reply_to,
-                reply_to_author,
-            } => {
-                self.composer = Composer::new();
-                self.composer.set_reply(reply_to, reply_to_author);
-                self.show_composer = true;
+            Action::ShowFeedPicker => {
+                self.feed_picker.set_feeds(&self.feeds, self.active_feed);
+                self.feed_picker.select_active();
+                self.show_feed_picker = true;
             }
 
-            Action::CloseComposer => {
-                self.show_composer = false;
+            Action::CloseFeedPicker => {
+                self.show_feed_picker = false;
             }
 
-            Action::SubmitPost { text, reply_to } => {
-                self.show_composer = false;
+            Action::ToggleFeedPin(id) => {
+                let Some(feed) = self.feeds.iter().find(|f| f.id == id) else {
+                    return;
+                };
+                let pinned = !feed.pinned;
                 let client = self.client.clone();
                 let tx = self.action_tx.clone();
                 tokio::spawn(async move {
-                    match client.create_post(text, reply_to).await {
-                        Ok(uri) => {
-                            let _ = tx.send(Action::PostCreated(uri));
+                    match client.set_feed_pinned(&id, pinned).await {
+                        Ok(()) => {
+                            let _ = tx.send(Action::FeedPinUpdated { id, pinned });
                         }
                         Err(e) => {
                             let _ = tx.send(Action::Error(e.to_string()));
@@ -446,45 +831,238 @@ impl App {
                 });
             }
 
-            Action::PostCreated(_uri) => {
-                self.dispatch(Action::RefreshTimeline);
+            Action::FeedPinUpdated { id, pinned } => {
+                if let Some(feed) = self.feeds.iter_mut().find(|f| f.id == id) {
+                    feed.pinned = pinned;
+                }
+                self.feed_picker.set_feeds(&self.feeds, self.active_feed);
             }
 
-            Action::ToggleLike => {
-                let post = match self.screen {
-                    Screen::Timeline => self.timeline.selected_post().cloned(),
-                    Screen::Thread => self.thread.as_ref().map(|t| t.focal.clone()),
-                    Screen::Profile => self.profile_feed.selected_post().cloned(),
-                    _ => None,
-                };
+            Action::RefreshNotifications => {
+                self.notifications.groups.loading = true;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.list_notifications(None).await {
+                        Ok((notifications, subjects, cursor)) => {
+                            let _ = tx.send(Action::NotificationsLoaded {
+                                notifications,
+                                subjects,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
 
-                if let Some(post) = post {
-                    let client = self.client.clone();
-                    let tx = self.action_tx.clone();
-                    if post.is_liked {
-                        if let Some(like_uri) = post.like_uri.clone() {
-                            let post_uri = post.uri.clone();
-                            tokio::spawn(async move {
-                                match client.unlike(&like_uri).await {
-                                    Ok(_) => {
-                                        let _ =
-                                            tx.send(Action::UnlikeSuccess { post_uri });
-                                    }
-                                    Err(e) => {
-                                        let _ = tx.send(Action::Error(e.to_string()));
-                                    }
-                                }
+            Action::LoadMoreNotifications => {
+                let groups = &self.notifications.groups;
+                if groups.loading || groups.cursor.is_none() {
+                    return;
+                }
+                self.notifications.groups.loading = true;
+                let client = self.client.clone();
+                let cursor = self.notifications.groups.cursor.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.list_notifications(cursor).await {
+                        Ok((notifications, subjects, cursor)) => {
+                            let _ = tx.send(Action::NotificationsLoaded {
+                                notifications,
+                                subjects,
+                                cursor,
+                                append: true,
                             });
                         }
-                    } else {
-                        let uri = post.uri.clone();
-                        let cid = post.cid.clone();
-                        tokio::spawn(async move {
-                            match client.like(&uri, &cid).await {
-                                Ok(like_uri) => {
-                                    let _ = tx.send(Action::LikeSuccess {
-                                        post_uri: uri,
-                                        like_uri,
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::NotificationsLoaded {
+                notifications,
+                subjects,
+                cursor,
+                append,
+            } => {
+                if append {
+                    self.notifications.append(notifications, subjects, cursor);
+                } else {
+                    self.notifications.replace(notifications, subjects, cursor);
+                    if self.screen == Screen::Notifications {
+                        self.mark_notifications_seen();
+                    }
+                }
+            }
+
+            Action::RefreshUnreadCount => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    // Polled in the background; a failed poll isn't worth an error bar
+                    match client.get_unread_count().await {
+                        Ok(count) => {
+                            let _ = tx.send(Action::UnreadCountLoaded(count));
+                        }
+                        Err(e) => error!("Failed to fetch unread count: {}", e),
+                    }
+                });
+            }
+
+            Action::UnreadCountLoaded(count) => {
+                self.notifications.unread_count = count;
+                // Already looking at them; they're marked seen once the refresh lands
+                if self.screen == Screen::Notifications && count > 0 {
+                    self.dispatch(Action::RefreshNotifications);
+                }
+            }
+
+            Action::ShowSearchPrompt => {
+                self.search_prompt = SearchPrompt::new(&self.search.input);
+                self.show_search_prompt = true;
+            }
+
+            Action::CloseSearchPrompt => {
+                self.show_search_prompt = false;
+            }
+
+            Action::SubmitSearch(input) => {
+                self.show_search_prompt = false;
+                self.search.query = SearchQuery::parse(&input);
+                self.search.input = input;
+                if self.screen != Screen::Search {
+                    self.screen_stack.push(self.screen.clone());
+                    self.screen = Screen::Search;
+                }
+                self.search.posts = FeedState::new();
+                self.search.posts.loading = true;
+                self.search.actors.replace(Vec::new(), None);
+                self.search.actors.loading = !self.search.query.text.is_empty();
+
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                let query = self.search.query.clone();
+                let sort = self.search.sort;
+                self.spawn_load(async move {
+                    let people = async {
+                        if query.text.is_empty() {
+                            Ok((Vec::new(), None))
+                        } else {
+                            client.search_actors(&query.text, None).await
+                        }
+                    };
+                    let (posts_result, actors_result) =
+                        tokio::join!(client.search_posts(&query, sort, None), people);
+                    match posts_result {
+                        Ok((posts, cursor)) => {
+                            let _ = tx.send(Action::SearchPostsLoaded {
+                                posts,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                    match actors_result {
+                        Ok((actors, cursor)) => {
+                            let _ = tx.send(Action::SearchActorsLoaded {
+                                actors,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ToggleSearchMode if self.screen == Screen::Search => {
+                self.search.mode = match self.search.mode {
+                    SearchMode::Posts => SearchMode::People,
+                    SearchMode::People => SearchMode::Posts,
+                };
+            }
+
+            Action::ToggleSearchSort
+                if self.screen == Screen::Search && self.search.mode == SearchMode::Posts =>
+            {
+                self.search.sort = match self.search.sort {
+                    SearchSort::Top => SearchSort::Latest,
+                    SearchSort::Latest => SearchSort::Top,
+                };
+                self.search.posts = FeedState::new();
+                self.search.posts.loading = true;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                let query = self.search.query.clone();
+                let sort = self.search.sort;
+                self.spawn_load(async move {
+                    match client.search_posts(&query, sort, None).await {
+                        Ok((posts, cursor)) => {
+                            let _ = tx.send(Action::SearchPostsLoaded {
+                                posts,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadMoreSearch => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                match self.search.mode {
+                    SearchMode::Posts => {
+                        if self.search.posts.loading || self.search.posts.cursor.is_none() {
+                            return;
+                        }
+                        self.search.posts.loading = true;
+                        let query = self.search.query.clone();
+                        let sort = self.search.sort;
+                        let cursor = self.search.posts.cursor.clone();
+                        self.spawn_load(async move {
+                            match client.search_posts(&query, sort, cursor).await {
+                                Ok((posts, cursor)) => {
+                                    let _ = tx.send(Action::SearchPostsLoaded {
+                                        posts,
+                                        cursor,
+                                        append: true,
+                                    });
+                                }
+                                Err(e) => {
+                                    let _ = tx.send(Action::Error(e.to_string()));
+                                }
+                            }
+                        });
+                    }
+                    SearchMode::People => {
+                        if self.search.actors.loading || self.search.actors.cursor.is_none() {
+                            return;
+                        }
+                        self.search.actors.loading = true;
+                        let text = self.search.query.text.clone();
+                        let cursor = self.search.actors.cursor.clone();
+                        self.spawn_load(async move {
+                            match client.search_actors(&text, cursor).await {
+                                Ok((actors, cursor)) => {
+                                    let _ = tx.send(Action::SearchActorsLoaded {
+                                        actors,
+                                        cursor,
+                                        append: true,
                                     });
                                 }
                                 Err(e) => {
@@ -493,141 +1071,2139 @@ impl App {
                             }
                         });
                     }
-                }
+                }
+            }
+
+            Action::SearchPostsLoaded {
+                mut posts,
+                cursor,
+                append,
+            } => {
+                self.moderate(&mut posts, false);
+                if append {
+                    self.search.posts.append_posts(posts, cursor);
+                } else {
+                    self.search.posts.replace_posts(posts, cursor);
+                }
+            }
+
+            Action::SearchActorsLoaded {
+                actors,
+                cursor,
+                append,
+            } => {
+                if append {
+                    self.search.actors.append(actors, cursor);
+                } else {
+                    self.search.actors.replace(actors, cursor);
+                }
+            }
+
+            Action::SelectNext => match self.screen {
+                Screen::Timeline => {
+                    self.timeline_mut().select_next();
+                    if self.timeline().near_bottom(20) {
+                        self.dispatch(Action::LoadMoreTimeline);
+                    }
+                }
+                Screen::Profile => {
+                    self.profile_feed.select_next();
+                }
+                Screen::Notifications => {
+                    self.notifications.groups.select_next();
+                    if self.notifications.groups.near_bottom() {
+                        self.dispatch(Action::LoadMoreNotifications);
+                    }
+                }
+                Screen::Search => {
+                    self.search.select_next();
+                    if self.search.near_bottom() {
+                        self.dispatch(Action::LoadMoreSearch);
+                    }
+                }
+                Screen::FollowList => {
+                    if let Some(ref mut list) = self.follow_list {
+                        list.actors.select_next();
+                        if list.actors.near_bottom() {
+                            self.dispatch(Action::LoadMoreFollowList);
+                        }
+                    }
+                }
+                Screen::Lists => {
+                    if let Some(ref mut lists) = self.lists {
+                        lists.lists.select_next();
+                        if lists.lists.near_bottom() {
+                            self.dispatch(Action::LoadMoreLists);
+                        }
+                    }
+                }
+                Screen::ListDetail => {
+                    if let Some(ref mut detail) = self.list_detail {
+                        detail.select_next();
+                        match detail.tab {
+                            ListTab::Posts if detail.feed.near_bottom(20) => {
+                                self.dispatch(Action::LoadMoreListFeed);
+                            }
+                            ListTab::Members if detail.members.near_bottom() => {
+                                self.dispatch(Action::LoadMoreListMembers);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Screen::Thread => {
+                    if let Some(ref mut thread) = self.thread {
+                        thread.select_next();
+                    }
+                }
+                Screen::MutedWords
+                    if self.muted_word_selected + 1 < self.moderation.muted_words.len() =>
+                {
+                    self.muted_word_selected += 1;
+                }
+                Screen::Messages => {
+                    self.convos.convos.select_next();
+                    if self.convos.convos.near_bottom() {
+                        self.dispatch(Action::LoadMoreConvos);
+                    }
+                }
+                Screen::Conversation => {
+                    if let Some(ref mut conversation) = self.conversation {
+                        conversation.messages.select_next();
+                    }
+                }
+                _ => {}
+            },
+
+            Action::SelectPrev => match self.screen {
+                Screen::Timeline => self.timeline_mut().select_prev(),
+                Screen::Profile => self.profile_feed.select_prev(),
+                Screen::Notifications => self.notifications.groups.select_prev(),
+                Screen::Search => self.search.select_prev(),
+                Screen::Thread => {
+                    if let Some(ref mut thread) = self.thread {
+                        thread.select_prev();
+                    }
+                }
+                Screen::FollowList => {
+                    if let Some(ref mut list) = self.follow_list {
+                        list.actors.select_prev();
+                    }
+                }
+                Screen::Lists => {
+                    if let Some(ref mut lists) = self.lists {
+                        lists.lists.select_prev();
+                    }
+                }
+                Screen::ListDetail => {
+                    if let Some(ref mut detail) = self.list_detail {
+                        detail.select_prev();
+                    }
+                }
+                Screen::Messages => self.convos.convos.select_prev(),
+                Screen::MutedWords => {
+                    self.muted_word_selected = self.muted_word_selected.saturating_sub(1);
+                }
+                Screen::Conversation => {
+                    if let Some(ref mut conversation) = self.conversation {
+                        conversation.messages.select_prev();
+                        if conversation.near_top() {
+                            self.dispatch(Action::LoadOlderMessages);
+                        }
+                    }
+                }
+                _ => {}
+            },
+
+            Action::ScrollToTop => match self.screen {
+                Screen::Timeline => self.timeline_mut().select_first(),
+                Screen::Profile => self.profile_feed.select_first(),
+                Screen::Notifications => self.notifications.groups.select_first(),
+                Screen::Search => self.search.select_first(),
+                Screen::Thread => {
+                    if let Some(ref mut thread) = self.thread {
+                        thread.select_first();
+                    }
+                }
+                Screen::FollowList => {
+                    if let Some(ref mut list) = self.follow_list {
+                        list.actors.select_first();
+                    }
+                }
+                Screen::Lists => {
+                    if let Some(ref mut lists) = self.lists {
+                        lists.lists.select_first();
+                    }
+                }
+                Screen::ListDetail => {
+                    if let Some(ref mut detail) = self.list_detail {
+                        detail.select_first();
+                    }
+                }
+                Screen::Messages => self.convos.convos.select_first(),
+                Screen::MutedWords => self.muted_word_selected = 0,
+                Screen::Conversation => {
+                    if let Some(ref mut conversation) = self.conversation {
+                        conversation.messages.select_first();
+                        if conversation.near_top() {
+                            self.dispatch(Action::LoadOlderMessages);
+                        }
+                    }
+                }
+                _ => {}
+            },
+
+            Action::ScrollToBottom => match self.screen {
+                Screen::Timeline => self.timeline_mut().select_last(),
+                Screen::Profile => self.profile_feed.select_last(),
+                Screen::Notifications => self.notifications.groups.select_last(),
+                Screen::Search => self.search.select_last(),
+                Screen::Thread => {
+                    if let Some(ref mut thread) = self.thread {
+                        thread.select_last();
+                    }
+                }
+                Screen::FollowList => {
+                    if let Some(ref mut list) = self.follow_list {
+                        list.actors.select_last();
+                    }
+                }
+                Screen::Lists => {
+                    if let Some(ref mut lists) = self.lists {
+                        lists.lists.select_last();
+                    }
+                }
+                Screen::ListDetail => {
+                    if let Some(ref mut detail) = self.list_detail {
+                        detail.select_last();
+                    }
+                }
+                Screen::Messages => self.convos.convos.select_last(),
+                Screen::MutedWords => {
+                    self.muted_word_selected = self.moderation.muted_words.len().saturating_sub(1);
+                }
+                Screen::Conversation => {
+                    if let Some(ref mut conversation) = self.conversation {
+                        conversation.messages.select_last();
+                    }
+                }
+                _ => {}
+            },
+
+            Action::OpenThread => {
+                // A follow has no post to open; show who followed instead
+                if let Some(group) = self
+                    .notifications
+                    .groups.selected_item()
+                    .filter(|_| self.screen == Screen::Notifications)
+                    .filter(|g| g.reason == NotificationReason::Follow)
+                {
+                    if let Some(did) = group.first_author_did() {
+                        let did = did.to_string();
+                        self.screen_stack.push(self.screen.clone());
+                        self.dispatch(Action::LoadProfile(did));
+                    }
+                    return;
+                }
+
+                if self.screen == Screen::Messages {
+                    self.dispatch(Action::OpenConvo);
+                    return;
+                }
+
+                if self.screen == Screen::FollowList {
+                    if let Some(did) = self
+                        .follow_list
+                        .as_ref()
+                        .and_then(|l| l.actors.selected_item())
+                        .map(|a| a.did.clone())
+                    {
+                        self.screen_stack.push(self.screen.clone());
+                        self.dispatch(Action::LoadProfile(did));
+                    }
+                    return;
+                }
+
+                if self.screen == Screen::Lists {
+                    self.dispatch(Action::OpenList);
+                    return;
+                }
+
+                if let Some(detail) = self
+                    .list_detail
+                    .as_ref()
+                    .filter(|d| self.screen == Screen::ListDetail && d.tab == ListTab::Members)
+                {
+                    if let Some(did) = detail.members.selected_item().map(|a| a.did.clone()) {
+                        self.screen_stack.push(self.screen.clone());
+                        self.dispatch(Action::LoadProfile(did));
+                    }
+                    return;
+                }
+
+                // People results open the profile rather than a thread
+                if self.screen == Screen::Search && self.search.mode == SearchMode::People {
+                    if let Some(did) = self.search.actors.selected_item().map(|a| a.did.clone()) {
+                        self.screen_stack.push(self.screen.clone());
+                        self.dispatch(Action::LoadProfile(did));
+                    }
+                    return;
+                }
+
+                let uri = match self.screen {
+                    Screen::Timeline => {
+                        self.timeline().selected_post().map(|p| p.uri.clone())
+                    }
+                    Screen::Profile => {
+                        self.profile_feed.selected_post().map(|p| p.uri.clone())
+                    }
+                    Screen::Notifications => self
+                        .notifications
+                        .groups.selected_item()
+                        .and_then(|g| g.subject_uri.clone()),
+                    Screen::Search => self.selected_search_post().map(|p| p.uri.clone()),
+                    Screen::ListDetail => self.selected_post().map(|p| p.uri.clone()),
+                    _ => None,
+                };
+
+                if let Some(uri) = uri {
+                    let client = self.client.clone();
+                    let tx = self.action_tx.clone();
+                    self.screen_stack.push(self.screen.clone());
+                    self.screen = Screen::Thread;
+                    self.thread_error = None;
+                    self.spawn_load(async move {
+                        match client.get_thread(&uri).await {
+                            Ok(thread) => {
+                                let _ = tx.send(Action::ThreadLoaded(thread));
+                            }
+                            Err(e) => {
+                                let _ = tx.send(Action::ThreadFailed(e.to_string()));
+                            }
+                        }
+                    });
+                }
+            }
+
+            Action::ToggleReplies if self.screen == Screen::Thread => {
+                let Some(uri) = self.thread.as_mut().and_then(|t| t.toggle_selected()) else {
+                    return;
+                };
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.get_thread(&uri).await {
+                        Ok(subtree) => {
+                            let _ = tx.send(Action::RepliesLoaded {
+                                uri,
+                                subtree: Some(subtree),
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::RepliesLoaded { uri, subtree: None });
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::RepliesLoaded { uri, mut subtree } => {
+                if let Some(ref mut subtree) = subtree {
+                    self.moderate(subtree.posts_mut(), true);
+                }
+                if let Some(ref mut thread) = self.thread {
+                    thread.insert_subtree(&uri, subtree);
+                }
+            }
+
+            Action::ThreadLoaded(mut thread) => {
+                self.moderate(thread.posts_mut(), true);
+                self.thread = Some(thread);
+            }
+
+            Action::ThreadFailed(error) => {
+                self.thread_error = Some(error);
+            }
+
+            Action::GoBack => {
+                self.cancel_load();
+                if let Some(prev) = self.screen_stack.pop() {
+                    let left = std::mem::replace(&mut self.screen, prev);
+                    self.thread = None;
+                    self.thread_error = None;
+                    if left == Screen::Conversation {
+                        self.conversation = None;
+                        self.show_message_input = false;
+                    }
+                    // Profiles opened from the list replaced the one the list came from
+                    if left == Screen::FollowList {
+                        if let Some(list) = self.follow_list.take() {
+                            let same = self.profile.as_ref().is_some_and(|p| p.did == list.actor);
+                            if self.screen == Screen::Profile && !same {
+                                self.dispatch(Action::LoadProfile(list.actor));
+                            }
+                        }
+                    }
+                    if left == Screen::ListDetail {
+                        self.list_detail = None;
+                    }
+                    if left == Screen::Lists {
+                        if let Some(lists) = self.lists.take() {
+                            let same = self.profile.as_ref().is_some_and(|p| p.did == lists.actor);
+                            if self.screen == Screen::Profile && !same {
+                                self.dispatch(Action::LoadProfile(lists.actor));
+                            }
+                        }
+                    }
+                }
+            }
+
+            Action::SwitchTab(idx) => {
+                self.active_tab = idx;
+                match idx {
+                    0 => {
+                        self.screen = Screen::Timeline;
+                        if self.timeline().posts.is_empty() {
+                            self.dispatch(Action::RefreshTimeline);
+                        }
+                    }
+                    1 => {
+                        if let Some(handle) = self.handle.clone() {
+                            self.dispatch(Action::LoadProfile(handle));
+                        }
+                    }
+                    2 => {
+                        self.screen = Screen::Notifications;
+                        if self.notifications.groups.is_empty()
+                            || self.notifications.unread_count > 0
+                        {
+                            self.dispatch(Action::RefreshNotifications);
+                        }
+                    }
+                    3 => {
+                        self.screen = Screen::Messages;
+                        self.conversation = None;
+                        self.show_message_input = false;
+                        self.dispatch(Action::RefreshConvos);
+                    }
+                    _ => {}
+                }
+            }
+
+            Action::OpenComposer {
+                reply_to,
+                reply_to_author,
+            } => {
+                let mut composer = Composer::new();
+                composer.set_reply(reply_to, reply_to_author);
+                self.show_composer_with_draft(composer);
+            }
+
+            Action::QuotePost => {
+                if let Some(post) = self.selected_post() {
+                    let quote = QuoteRef {
+                        uri: post.uri.clone(),
+                        cid: post.cid.clone(),
+                    };
+                    let author = post.author_display_name.clone();
+                    let preview = post.text.clone();
+                    let mut composer = Composer::new();
+                    composer.set_quote(quote, author, preview);
+                    self.show_composer_with_draft(composer);
+                }
+            }
+
+            Action::OpenExternalEditor => {
+                self.editor_requested = true;
+            }
+
+            Action::CloseComposer { save_draft } => {
+                let result = if save_draft {
+                    drafts::save(self.drafts_owner(), &self.composer.to_draft())
+                } else {
+                    drafts::delete(self.drafts_owner(), &self.composer.draft_key())
+                };
+                if let Err(e) = result {
+                    self.dispatch(Action::Error(format!("Could not update drafts: {}", e)));
+                }
+                self.show_composer = false;
+            }
+
+            Action::DeletePost => {
+                let Some(post) = self.selected_post() else {
+                    return;
+                };
+                if self.did.as_deref() != Some(post.author_did.as_str()) {
+                    self.dispatch(Action::Error(
+                        "You can only delete your own posts".to_string(),
+                    ));
+                    return;
+                }
+                let preview: String = post.text.chars().take(120).collect();
+                self.confirm = Some(ConfirmDialog::new(
+                    "Delete post",
+                    format!("Delete this post? This can't be undone.\n\n{}", preview),
+                    Action::ConfirmDeletePost(post.uri.clone()),
+                ));
+            }
+
+            Action::ConfirmDeletePost(uri) => {
+                self.confirm = None;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.delete_post(&uri).await {
+                        Ok(()) => {
+                            let _ = tx.send(Action::PostDeleted(uri));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::PostDeleted(uri) => {
+                self.remove_post(&uri);
+            }
+
+            Action::CloseConfirm => {
+                self.confirm = None;
+            }
+
+            Action::ShowDrafts => match drafts::load_all(self.drafts_owner()) {
+                Ok(list) => {
+                    self.draft_picker.set_drafts(list);
+                    self.show_draft_picker = true;
+                }
+                Err(e) => self.dispatch(Action::Error(format!("Could not load drafts: {}", e))),
+            },
+
+            Action::CloseDrafts => {
+                self.show_draft_picker = false;
+            }
+
+            Action::OpenDraft(key) => {
+                self.show_draft_picker = false;
+                if let Some(draft) = drafts::load(self.drafts_owner(), &key) {
+                    let mut composer = Composer::new();
+                    composer.set_known_tags(self.recent_tags());
+                    composer.load_draft(draft);
+                    self.composer = composer;
+                    self.show_composer = true;
+                }
+            }
+
+            Action::DeleteDraft(key) => {
+                if let Err(e) = drafts::delete(self.drafts_owner(), &key) {
+                    self.dispatch(Action::Error(format!("Could not delete draft: {}", e)));
+                }
+                self.draft_picker
+                    .set_drafts(drafts::load_all(self.drafts_owner()).unwrap_or_default());
+            }
+
+            Action::SubmitPost {
+                segments,
+                reply_to,
+                images,
+                quote,
+                mentions,
+                self_label,
+            } => {
+                self.show_composer = false;
+                // A failed post reopens the composer, or is saved as a draft again
+                let draft = self.composer.to_draft();
+                let _ = drafts::delete(self.drafts_owner(), &draft.key);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    // Each segment replies to the previous one; images, the quote
+                    // and the self-label belong to the first post.
+                    let mut root =
+                        reply_to.as_ref().map(|r| (r.root_uri.clone(), r.root_cid.clone()));
+                    let mut reply_to = reply_to;
+                    let mut images = images;
+                    let mut quote = quote;
+                    let mut self_label = self_label;
+                    let mut last_uri = String::new();
+                    for (posted, text) in segments.into_iter().enumerate() {
+                        let images = std::mem::take(&mut images);
+                        let result = client
+                            .create_post(
+                                text,
+                                reply_to.clone(),
+                                images,
+                                quote.take(),
+                                &mentions,
+                                self_label.take(),
+                            )
+                            .await;
+                        match result {
+                            Ok((uri, cid)) => {
+                                let (root_uri, root_cid) = root
+                                    .get_or_insert_with(|| (uri.clone(), cid.clone()))
+                                    .clone();
+                                reply_to = Some(ReplyRef {
+                                    parent_uri: uri.clone(),
+                                    parent_cid: cid,
+                                    root_uri,
+                                    root_cid,
+                                });
+                                last_uri = uri;
+                            }
+                            Err(e) => {
+                                let _ = tx.send(Action::PostFailed {
+                                    posted,
+                                    reply_to,
+                                    error: e.to_string(),
+                                    draft,
+                                });
+                                return;
+                            }
+                        }
+                    }
+                    let _ = tx.send(Action::PostCreated(last_uri));
+                });
+            }
+
+            Action::PostCreated(_uri) => {
+                self.dispatch(Action::RefreshTimeline);
+            }
+
+            Action::MentionTypeahead(query) => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.search_actors_typeahead(&query).await {
+                        Ok(actors) => {
+                            let _ = tx.send(Action::MentionSuggestions { query, actors });
+                        }
+                        Err(e) => error!("Typeahead failed: {}", e),
+                    }
+                });
+            }
+
+            Action::MentionSuggestions { query, actors } => {
+                self.composer.set_mention_suggestions(&query, actors);
+            }
+
+            Action::PostFailed {
+                posted,
+                reply_to,
+                error,
+                draft,
+            } => {
+                if posted > 0 {
+                    self.dispatch(Action::RefreshTimeline);
+                }
+                if self.show_composer {
+                    // A new draft is open; don't clobber it with the failed one
+                    let draft = draft.unposted(posted, reply_to);
+                    let message = match drafts::save(self.drafts_owner(), &draft) {
+                        Ok(()) => format!("Post failed: {}. Saved it to drafts", error),
+                        Err(e) => format!("Post failed: {}. Could not save it: {}", error, e),
+                    };
+                    self.dispatch(Action::Error(message));
+                } else {
+                    let total = draft.segments.len();
+                    let mut composer = Composer::new();
+                    composer.set_known_tags(self.recent_tags());
+                    composer.load_draft(draft.unposted(posted, reply_to));
+                    composer.set_failure(posted, total, &error);
+                    self.composer = composer;
+                    self.show_composer = true;
+                }
+            }
+
+            Action::ToggleLike => {
+                let post = self.selected_post().cloned();
+
+                if let Some(post) = post {
+                    let client = self.client.clone();
+                    let tx = self.action_tx.clone();
+                    if post.is_liked {
+                        if let Some(like_uri) = post.like_uri.clone() {
+                            let post_uri = post.uri.clone();
+                            tokio::spawn(async move {
+                                match client.unlike(&like_uri).await {
+                                    Ok(_) => {
+                                        let _ =
+                                            tx.send(Action::UnlikeSuccess { post_uri });
+                                    }
+                                    Err(e) => {
+                                        let _ = tx.send(Action::Error(e.to_string()));
+                                    }
+                                }
+                            });
+                        }
+                    } else {
+                        let uri = post.uri.clone();
+                        let cid = post.cid.clone();
+                        tokio::spawn(async move {
+                            match client.like(&uri, &cid).await {
+                                Ok(like_uri) => {
+                                    let _ = tx.send(Action::LikeSuccess {
+                                        post_uri: uri,
+                                        like_uri,
+                                    });
+                                }
+                                Err(e) => {
+                                    let _ = tx.send(Action::Error(e.to_string()));
+                                }
+                            }
+                        });
+                    }
+                }
+            }
+
+            Action::LikeSuccess { post_uri, like_uri } => {
+                self.update_post(&post_uri, |p| {
+                    p.is_liked = true;
+                    p.like_uri = Some(like_uri.clone());
+                    p.like_count += 1;
+                });
+            }
+
+            Action::UnlikeSuccess { post_uri } => {
+                self.update_post(&post_uri, |p| {
+                    p.is_liked = false;
+                    p.like_uri = None;
+                    p.like_count = (p.like_count - 1).max(0);
+                });
+            }
+
+            Action::ToggleRepost => {
+                let post = self.selected_post().cloned();
+
+                if let Some(post) = post {
+                    let client = self.client.clone();
+                    let tx = self.action_tx.clone();
+                    if post.is_reposted {
+                        if let Some(repost_uri) = post.repost_uri.clone() {
+                            let post_uri = post.uri.clone();
+                            tokio::spawn(async move {
+                                match client.unrepost(&repost_uri).await {
+                                    Ok(_) => {
+                                        let _ = tx.send(Action::UnrepostSuccess {
+                                            post_uri,
+                                        });
+                                    }
+                                    Err(e) => {
+                                        let _ = tx.send(Action::Error(e.to_string()));
+                                    }
+                                }
+                            });
+                        }
+                    } else {
+                        let uri = post.uri.clone();
+                        let cid = post.cid.clone();
+                        tokio::spawn(async move {
+                            match client.repost(&uri, &cid).await {
+                                Ok(repost_uri) => {
+                                    let _ = tx.send(Action::RepostSuccess {
+                                        post_uri: uri,
+                                        repost_uri,
+                                    });
+                                }
+                                Err(e) => {
+                                    let _ = tx.send(Action::Error(e.to_string()));
+                                }
+                            }
+                        });
+                    }
+                }
+            }
+
+            Action::RepostSuccess {
+                post_uri,
+                repost_uri,
+            } => {
+                self.update_post(&post_uri, |p| {
+                    p.is_reposted = true;
+                    p.repost_uri = Some(repost_uri.clone());
+                    p.repost_count += 1;
+                });
+            }
+
+            Action::UnrepostSuccess { post_uri } => {
+                self.update_post(&post_uri, |p| {
+                    p.is_reposted = false;
+                    p.repost_uri = None;
+                    p.repost_count = (p.repost_count - 1).max(0);
+                });
+            }
+
+            Action::ViewAuthorProfile => {
+                let did = match self.screen {
+                    Screen::Timeline => {
+                        self.timeline().selected_post().map(|p| p.author_did.clone())
+                    }
+                    Screen::Thread => {
+                        self.thread
+                            .as_ref()
+                            .and_then(|t| t.selected_post())
+                            .map(|p| p.author_did.clone())
+                    }
+                    Screen::Notifications => self
+                        .notifications
+                        .groups.selected_item()
+                        .and_then(|g| g.first_author_did())
+                        .map(|d| d.to_string()),
+                    Screen::Search => match self.search.mode {
+                        SearchMode::Posts => {
+                            self.selected_search_post().map(|p| p.author_did.clone())
+                        }
+                        SearchMode::People => {
+                            self.search.actors.selected_item().map(|a| a.did.clone())
+                        }
+                    },
+                    Screen::FollowList => self
+                        .follow_list
+                        .as_ref()
+                        .and_then(|l| l.actors.selected_item())
+                        .map(|a| a.did.clone()),
+                    Screen::ListDetail => self.list_detail.as_ref().and_then(|d| match d.tab {
+                        ListTab::Posts => d.feed.selected_post().map(|p| p.author_did.clone()),
+                        ListTab::Members => d.members.selected_item().map(|a| a.did.clone()),
+                    }),
+                    _ => None,
+                };
+                if let Some(did) = did {
+                    self.screen_stack.push(self.screen.clone());
+                    self.dispatch(Action::LoadProfile(did));
+                }
+            }
+
+            Action::LoadProfile(actor) => {
+                self.screen = Screen::Profile;
+                self.profile = None;
+                self.profile_tab = AuthorFeedTab::Posts;
+                self.profile_feed = FeedState::new();
+                self.profile_feed.loading = true;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    let profile_result = client.get_profile(&actor).await;
+                    let feed_result =
+                        client.get_author_feed(&actor, AuthorFeedTab::Posts, None).await;
+                    match (profile_result, feed_result) {
+                        (Ok(profile), Ok((posts, cursor))) => {
+                            let _ = tx.send(Action::ProfileLoaded {
+                                profile,
+                                posts,
+                                cursor,
+                            });
+                        }
+                        // A block in either direction hides the feed, not the profile
+                        (Ok(profile), Err(_))
+                            if profile.viewer.blocking || profile.viewer.blocked_by =>
+                        {
+                            let _ = tx.send(Action::ProfileLoaded {
+                                profile,
+                                posts: Vec::new(),
+                                cursor: None,
+                            });
+                        }
+                        (Err(e), _) | (_, Err(e)) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ProfileLoaded {
+                profile,
+                mut posts,
+                cursor,
+            } => {
+                self.moderate(&mut posts, false);
+                self.profile = Some(profile);
+                self.profile_feed.replace_posts(posts, cursor);
+            }
+
+            Action::SwitchProfileTab(tab) => {
+                let Some(actor) = self.profile.as_ref().map(|p| p.did.clone()) else {
+                    return;
+                };
+                self.profile_tab = tab;
+                self.profile_feed = FeedState::new();
+                self.profile_feed.loading = true;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_author_feed(&actor, tab, None).await {
+                        Ok((posts, cursor)) => {
+                            let _ = tx.send(Action::ProfileFeedLoaded {
+                                actor,
+                                tab,
+                                posts,
+                                cursor,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ProfileFeedLoaded {
+                actor,
+                tab,
+                mut posts,
+                cursor,
+            } => {
+                self.moderate(&mut posts, false);
+                let current = self.profile.as_ref().is_some_and(|p| p.did == actor);
+                if current && tab == self.profile_tab {
+                    self.profile_feed.replace_posts(posts, cursor);
+                }
+            }
+
+            Action::ShowFollowList(kind) => {
+                let (actor, handle) = match self.screen {
+                    Screen::Profile => match self.profile {
+                        Some(ref p) => (p.did.clone(), p.handle.clone()),
+                        None => return,
+                    },
+                    Screen::FollowList => match self.follow_list {
+                        Some(ref l) if l.kind != kind => (l.actor.clone(), l.actor_handle.clone()),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                if self.screen == Screen::Profile {
+                    self.screen_stack.push(self.screen.clone());
+                    self.screen = Screen::FollowList;
+                }
+                let mut list = FollowListState::new(kind, actor.clone(), handle);
+                list.actors.loading = true;
+                self.follow_list = Some(list);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    let result = match kind {
+                        FollowListKind::Followers => client.get_followers(&actor, None).await,
+                        FollowListKind::Follows => client.get_follows(&actor, None).await,
+                    };
+                    match result {
+                        Ok((actors, cursor)) => {
+                            let _ = tx.send(Action::FollowListLoaded {
+                                actor,
+                                kind,
+                                actors,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadMoreFollowList => {
+                let Some(ref mut list) = self.follow_list else {
+                    return;
+                };
+                if list.actors.loading || list.actors.cursor.is_none() {
+                    return;
+                }
+                list.actors.loading = true;
+                let kind = list.kind;
+                let actor = list.actor.clone();
+                let cursor = list.actors.cursor.clone();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    let result = match kind {
+                        FollowListKind::Followers => client.get_followers(&actor, cursor).await,
+                        FollowListKind::Follows => client.get_follows(&actor, cursor).await,
+                    };
+                    match result {
+                        Ok((actors, cursor)) => {
+                            let _ = tx.send(Action::FollowListLoaded {
+                                actor,
+                                kind,
+                                actors,
+                                cursor,
+                                append: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::FollowListLoaded {
+                actor,
+                kind,
+                actors,
+                cursor,
+                append,
+            } => {
+                let Some(ref mut list) = self.follow_list else {
+                    return;
+                };
+                if list.actor != actor || list.kind != kind {
+                    return;
+                }
+                if append {
+                    list.actors.append(actors, cursor);
+                } else {
+                    list.actors.replace(actors, cursor);
+                }
+            }
+
+            Action::ToggleFollow => {
+                let Some(profile) = self.other_profile() else {
+                    return;
+                };
+                let did = profile.did.clone();
+                let was_following = profile.viewer.following;
+                let old_uri = profile.viewer.following_uri.clone();
+                if profile.viewer.blocking {
+                    self.dispatch(Action::Error("Unblock this account to follow it".to_string()));
+                    return;
+                }
+                // Optimistic; the record URI arrives with FollowUpdated
+                profile.set_following(!was_following, None);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    let result = match (was_following, old_uri.as_deref()) {
+                        (true, Some(uri)) => client.unfollow(uri).await.map(|_| None),
+                        (true, None) => Err(anyhow::anyhow!("Follow is still being saved")),
+                        (false, _) => client.follow(&did).await.map(Some),
+                    };
+                    match result {
+                        Ok(uri) => {
+                            let _ = tx.send(Action::FollowUpdated {
+                                did,
+                                following: !was_following,
+                                uri,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::FollowUpdated {
+                                did,
+                                following: was_following,
+                                uri: old_uri,
+                            });
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::FollowUpdated {
+                did,
+                following,
+                uri,
+            } => {
+                if let Some(ref mut profile) = self.profile {
+                    if profile.did == did {
+                        profile.set_following(following, uri);
+                    }
+                }
+            }
+
+            Action::ToggleMute => {
+                let Some(profile) = self.other_profile() else {
+                    return;
+                };
+                let did = profile.did.clone();
+                let muted = !profile.viewer.muted;
+                profile.viewer.muted = muted;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = client.set_muted(&did, muted).await {
+                        let _ = tx.send(Action::MuteUpdated { did, muted: !muted });
+                        let _ = tx.send(Action::Error(e.to_string()));
+                    }
+                });
+            }
+
+            Action::MuteUpdated { did, muted } => {
+                if let Some(ref mut profile) = self.profile {
+                    if profile.did == did {
+                        profile.viewer.muted = muted;
+                    }
+                }
+            }
+
+            Action::ToggleBlock => {
+                let Some(profile) = self.other_profile() else {
+                    return;
+                };
+                if !profile.viewer.blocking {
+                    self.confirm = Some(ConfirmDialog::new(
+                        "Block account",
+                        format!(
+                            "Block @{}? They won't be able to see or interact with your posts.",
+                            profile.handle
+                        ),
+                        Action::ConfirmBlock(profile.did.clone()),
+                    ));
+                    return;
+                }
+                let did = profile.did.clone();
+                let Some(uri) = profile.viewer.blocking_uri.clone() else {
+                    self.dispatch(Action::Error("Block is still being saved".to_string()));
+                    return;
+                };
+                profile.viewer.blocking = false;
+                profile.viewer.blocking_uri = None;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.unblock(&uri).await {
+                        Ok(()) => {
+                            let _ = tx.send(Action::BlockUpdated {
+                                did,
+                                blocking: false,
+                                uri: None,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::BlockUpdated {
+                                did,
+                                blocking: true,
+                                uri: Some(uri),
+                            });
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ConfirmBlock(did) => {
+                self.confirm = None;
+                let Some(profile) = self.other_profile().filter(|p| p.did == did) else {
+                    return;
+                };
+                profile.viewer.blocking = true;
+                profile.viewer.blocking_uri = None;
+                // Blocking removes any follow in either direction
+                profile.set_following(false, None);
+                profile.viewer.followed_by = false;
+                self.profile_feed = FeedState::new();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.block(&did).await {
+                        Ok(uri) => {
+                            let _ = tx.send(Action::BlockUpdated {
+                                did,
+                                blocking: true,
+                                uri: Some(uri),
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                            let _ = tx.send(Action::LoadProfile(did));
+                        }
+                    }
+                });
+            }
+
+            Action::BlockUpdated { did, blocking, uri } => {
+                if let Some(ref mut profile) = self.profile {
+                    if profile.did == did {
+                        profile.viewer.blocking = blocking;
+                        profile.viewer.blocking_uri = uri;
+                        // Unblocked: fetch the author feed that the block hid
+                        if !blocking && self.screen == Screen::Profile {
+                            self.dispatch(Action::LoadProfile(did));
+                        }
+                    }
+                }
+            }
+
+            Action::RefreshConvos => {
+                self.convos.convos.loading = true;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.list_convos(None).await {
+                        Ok((convos, cursor)) => {
+                            let _ = tx.send(Action::ConvosLoaded {
+                                convos,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadMoreConvos => {
+                if self.convos.convos.loading || self.convos.convos.cursor.is_none() {
+                    return;
+                }
+                self.convos.convos.loading = true;
+                let client = self.client.clone();
+                let cursor = self.convos.convos.cursor.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.list_convos(cursor).await {
+                        Ok((convos, cursor)) => {
+                            let _ = tx.send(Action::ConvosLoaded {
+                                convos,
+                                cursor,
+                                append: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ConvosLoaded {
+                convos,
+                cursor,
+                append,
+            } => {
+                if append {
+                    self.convos.append_convos(convos, cursor);
+                } else {
+                    self.convos.replace_convos(convos, cursor);
+                }
+            }
+
+            Action::RefreshChatUnreadCount => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.get_chat_unread_count().await {
+                        Ok(count) => {
+                            let _ = tx.send(Action::ChatUnreadCountLoaded(count));
+                        }
+                        Err(e) if client::is_scope_denied(&e.to_string()) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                        Err(e) => error!("Failed to fetch unread messages: {}", e),
+                    }
+                });
+            }
+
+            Action::ChatError(error) => {
+                self.convos.convos.loading = false;
+                if let Some(ref mut conversation) = self.conversation {
+                    conversation.messages.loading = false;
+                }
+                if !client::is_scope_denied(&error) {
+                    self.dispatch(Action::Error(error));
+                    return;
+                }
+                // Say so once; the polls stop asking until the next sign-in
+                if !self.convos.unavailable {
+                    self.convos.unavailable = true;
+                    self.dispatch(Action::Error(
+                        "This session has no chat access. Sign in again to use messages."
+                            .to_string(),
+                    ));
+                }
+            }
+
+            Action::ChatUnreadCountLoaded(count) => {
+                self.convos.unread_count = count;
+            }
+
+            Action::OpenConvo => {
+                let Some(convo) = self.convos.convos.selected_item().cloned() else {
+                    return;
+                };
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                let convo_id = convo.id.clone();
+                self.screen_stack.push(self.screen.clone());
+                self.screen = Screen::Conversation;
+                self.conversation = Some(ConversationState::new(convo));
+                self.message_input = MessageInput::new("");
+                self.show_message_input = false;
+                self.spawn_load(async move {
+                    match client.get_messages(&convo_id, None).await {
+                        Ok((messages, cursor)) => {
+                            let _ = tx.send(Action::MessagesLoaded {
+                                convo_id,
+                                messages,
+                                cursor,
+                                older: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadOlderMessages => {
+                let Some(ref mut conversation) = self.conversation else {
+                    return;
+                };
+                if conversation.messages.loading || conversation.messages.cursor.is_none() {
+                    return;
+                }
+                conversation.messages.loading = true;
+                let client = self.client.clone();
+                let convo_id = conversation.convo.id.clone();
+                let cursor = conversation.messages.cursor.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_messages(&convo_id, cursor).await {
+                        Ok((messages, cursor)) => {
+                            let _ = tx.send(Action::MessagesLoaded {
+                                convo_id,
+                                messages,
+                                cursor,
+                                older: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::PollConversation => {
+                let Some(ref conversation) = self.conversation else {
+                    return;
+                };
+                let client = self.client.clone();
+                let convo_id = conversation.convo.id.clone();
+                let tx = self.action_tx.clone();
+                // Not spawn_load: a poll mustn't cancel a page of older messages
+                tokio::spawn(async move {
+                    match client.get_messages(&convo_id, None).await {
+                        Ok((messages, cursor)) => {
+                            let _ = tx.send(Action::MessagesLoaded {
+                                convo_id,
+                                messages,
+                                cursor,
+                                older: false,
+                            });
+                        }
+                        Err(e) if client::is_scope_denied(&e.to_string()) => {
+                            let _ = tx.send(Action::ChatError(e.to_string()));
+                        }
+                        Err(e) => error!("Failed to poll conversation: {}", e),
+                    }
+                });
+            }
+
+            Action::MessagesLoaded {
+                convo_id,
+                messages,
+                cursor,
+                older,
+            } => {
+                let Some(ref mut conversation) = self.conversation else {
+                    return;
+                };
+                if conversation.convo.id != convo_id {
+                    return;
+                }
+                if older {
+                    conversation.prepend_older(messages, cursor);
+                    return;
+                }
+                let arrived = conversation.merge_latest(messages, cursor);
+                let newest = conversation.newest_id().map(String::from);
+                let unread = self
+                    .convos
+                    .convo_mut(&convo_id)
+                    .map_or(0, |c| std::mem::take(&mut c.unread_count));
+                self.convos.recount_unread();
+                // Reading the conversation on screen marks it read up to the newest message
+                if arrived || unread > 0 {
+                    let client = self.client.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = client.mark_convo_read(&convo_id, newest).await {
+                            error!("Failed to mark conversation read: {}", e);
+                        }
+                    });
+                }
+            }
+
+            Action::ShowMessageInput if self.screen == Screen::Conversation => {
+                self.show_message_input = true;
+            }
+
+            Action::CloseMessageInput => {
+                self.show_message_input = false;
+            }
+
+            Action::SendMessage(text) => {
+                let Some(ref conversation) = self.conversation else {
+                    return;
+                };
+                let client = self.client.clone();
+                let convo_id = conversation.convo.id.clone();
+                let tx = self.action_tx.clone();
+                self.message_input = MessageInput::new("");
+                tokio::spawn(async move {
+                    match client.send_message(&convo_id, text.clone()).await {
+                        Ok(message) => {
+                            let _ = tx.send(Action::MessageSent { convo_id, message });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::MessageFailed {
+                                convo_id,
+                                text,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                });
+            }
+
+            Action::MessageSent { convo_id, message } => {
+                if let Some(convo) = self.convos.convo_mut(&convo_id) {
+                    convo.last_message = Some(message.text.clone());
+                    convo.last_message_at = Some(message.sent_at);
+                }
+                if let Some(ref mut conversation) = self.conversation {
+                    if conversation.convo.id == convo_id {
+                        conversation.merge_latest(vec![message], None);
+                        conversation.messages.select_last();
+                    }
+                }
+            }
+
+            Action::MessageFailed {
+                convo_id,
+                text,
+                error,
+            } => {
+                // Give the text back so it can be retried
+                let open = self.conversation.as_ref().is_some_and(|c| c.convo.id == convo_id);
+                if open && self.screen == Screen::Conversation {
+                    self.message_input = MessageInput::new(&text);
+                    self.show_message_input = true;
+                }
+                self.error_message = Some(error);
+            }
+
+            Action::ToggleConvoMute => {
+                let convo = match self.screen {
+                    Screen::Messages => self.convos.convos.selected_item(),
+                    Screen::Conversation => self.conversation.as_ref().map(|c| &c.convo),
+                    _ => None,
+                };
+                let Some(convo) = convo else {
+                    return;
+                };
+                let convo_id = convo.id.clone();
+                let muted = !convo.muted;
+                self.dispatch(Action::ConvoMuteUpdated {
+                    convo_id: convo_id.clone(),
+                    muted,
+                });
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = client.set_convo_muted(&convo_id, muted).await {
+                        let _ = tx.send(Action::ConvoMuteUpdated {
+                            convo_id,
+                            muted: !muted,
+                        });
+                        let _ = tx.send(Action::ChatError(e.to_string()));
+                    }
+                });
+            }
+
+            Action::ConvoMuteUpdated { convo_id, muted } => {
+                if let Some(convo) = self.convos.convo_mut(&convo_id) {
+                    convo.muted = muted;
+                }
+                self.convos.recount_unread();
+                if let Some(ref mut conversation) = self.conversation {
+                    if conversation.convo.id == convo_id {
+                        conversation.convo.muted = muted;
+                    }
+                }
+            }
+
+            Action::LeaveConvo => {
+                let convo = match self.screen {
+                    Screen::Messages => self.convos.convos.selected_item(),
+                    Screen::Conversation => self.conversation.as_ref().map(|c| &c.convo),
+                    _ => None,
+                };
+                let Some(convo) = convo else {
+                    return;
+                };
+                self.confirm = Some(ConfirmDialog::new(
+                    "Leave conversation",
+                    format!(
+                        "Leave your conversation with {}? It will be removed from your messages.",
+                        convo.title()
+                    ),
+                    Action::ConfirmLeaveConvo(convo.id.clone()),
+                ));
+            }
+
+            Action::ConfirmLeaveConvo(convo_id) => {
+                self.confirm = None;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.leave_convo(&convo_id).await {
+                        Ok(()) => {
+                            let _ = tx.send(Action::ConvoLeft(convo_id));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ConvoLeft(convo_id) => {
+                self.convos.remove_convo(&convo_id);
+                let open = self.conversation.as_ref().is_some_and(|c| c.convo.id == convo_id);
+                if open && self.screen == Screen::Conversation {
+                    self.dispatch(Action::GoBack);
+                }
+            }
+
+            Action::LoadModerationPrefs => {
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.get_moderation_prefs().await {
+                        Ok(prefs) => {
+                            let _ = tx.send(Action::ModerationPrefsLoaded(prefs));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ModerationPrefsLoaded(prefs) => {
+                // Posts may have loaded first; filter what's already on screen
+                let labelers_changed = prefs.labelers != self.moderation.labelers;
+                self.moderation = prefs;
+                self.moderate_loaded();
+                // Third-party labels only come back once the labelers header is set
+                if labelers_changed
+                    && self.moderation.labelers.len() > 1
+                    && self.screen == Screen::Timeline
+                    && !self.timeline().posts.is_empty()
+                {
+                    self.dispatch(Action::RefreshTimeline);
+                }
+            }
+
+            Action::ToggleFilteredPost => {
+                let Some(post) = self.selected_post() else {
+                    return;
+                };
+                if post.no_override {
+                    self.dispatch(Action::Error(
+                        "This post can't be shown with your content settings".to_string(),
+                    ));
+                    return;
+                }
+                if !post.can_reveal() {
+                    return;
+                }
+                let uri = post.uri.clone();
+                self.update_post(&uri, |p| p.revealed = !p.revealed);
+            }
+
+            Action::ShowMutedWords => {
+                if self.screen != Screen::MutedWords {
+                    self.screen_stack.push(self.screen.clone());
+                    self.screen = Screen::MutedWords;
+                }
+                self.muted_word_selected = 0;
+                self.dispatch(Action::LoadModerationPrefs);
+            }
+
+            Action::ShowMutedWordPrompt if self.screen == Screen::MutedWords => {
+                self.muted_word_prompt = MutedWordPrompt::new();
+                self.show_muted_word_prompt = true;
+            }
+
+            Action::CloseMutedWordPrompt => {
+                self.show_muted_word_prompt = false;
+            }
+
+            Action::AddMutedWord(word) => {
+                self.show_muted_word_prompt = false;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.add_muted_word(&word).await {
+                        Ok(words) => {
+                            let _ = tx.send(Action::MutedWordsSaved(words));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::RemoveMutedWord if self.screen == Screen::MutedWords => {
+                let Some(word) = self.moderation.muted_words.get(self.muted_word_selected) else {
+                    return;
+                };
+                self.confirm = Some(ConfirmDialog::new(
+                    "Unmute word",
+                    format!("Stop muting \"{}\"?", word.value),
+                    Action::ConfirmRemoveMutedWord(word.clone()),
+                ));
+            }
+
+            Action::ConfirmRemoveMutedWord(word) => {
+                self.confirm = None;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.remove_muted_word(&word).await {
+                        Ok(words) => {
+                            let _ = tx.send(Action::MutedWordsSaved(words));
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::MutedWordsSaved(words) => {
+                self.moderation.muted_words = words;
+                self.muted_word_selected = self
+                    .muted_word_selected
+                    .min(self.moderation.muted_words.len().saturating_sub(1));
+                self.moderate_loaded();
+            }
+
+            Action::ShowLists => {
+                if self.screen == Screen::Lists {
+                    return;
+                }
+                let own = match (&self.did, &self.handle) {
+                    (Some(did), Some(handle)) => Some((did.clone(), handle.clone())),
+                    _ => None,
+                };
+                let profile = self
+                    .profile
+                    .as_ref()
+                    .filter(|_| self.screen == Screen::Profile)
+                    .map(|p| (p.did.clone(), p.handle.clone()));
+                let Some((actor, handle)) = profile.or(own) else {
+                    return;
+                };
+                self.screen_stack.push(self.screen.clone());
+                self.screen = Screen::Lists;
+                let mut lists = ListsState::new(actor.clone(), handle);
+                lists.lists.loading = true;
+                self.lists = Some(lists);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_lists(&actor, None).await {
+                        Ok((lists, cursor)) => {
+                            let _ = tx.send(Action::ListsLoaded {
+                                actor,
+                                lists,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadMoreLists => {
+                let Some(ref mut lists) = self.lists else {
+                    return;
+                };
+                if lists.lists.loading || lists.lists.cursor.is_none() {
+                    return;
+                }
+                lists.lists.loading = true;
+                let actor = lists.actor.clone();
+                let cursor = lists.lists.cursor.clone();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_lists(&actor, cursor).await {
+                        Ok((lists, cursor)) => {
+                            let _ = tx.send(Action::ListsLoaded {
+                                actor,
+                                lists,
+                                cursor,
+                                append: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ListsLoaded {
+                actor,
+                lists: loaded,
+                cursor,
+                append,
+            } => {
+                let Some(ref mut lists) = self.lists else {
+                    return;
+                };
+                if lists.actor != actor {
+                    return;
+                }
+                if append {
+                    lists.lists.append(loaded, cursor);
+                } else {
+                    lists.lists.replace(loaded, cursor);
+                }
+            }
+
+            Action::OpenList => {
+                let Some(list) = self
+                    .lists
+                    .as_ref()
+                    .and_then(|l| l.lists.selected_item())
+                    .cloned()
+                else {
+                    return;
+                };
+                self.screen_stack.push(self.screen.clone());
+                self.screen = Screen::ListDetail;
+                let mut detail = ListDetailState::new(list.clone());
+                detail.members.loading = true;
+                detail.feed.loading = detail.has_feed();
+                self.list_detail = Some(detail);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_list(&list.uri, None).await {
+                        Ok((list, members, cursor)) => {
+                            let _ = tx.send(Action::ListLoaded {
+                                list,
+                                members,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                            return;
+                        }
+                    }
+                    if list.purpose != ListPurpose::Curate {
+                        return;
+                    }
+                    let source = FeedSource::List(list.uri.clone());
+                    match client.get_feed(&source, None).await {
+                        Ok((posts, cursor)) => {
+                            let _ = tx.send(Action::ListFeedLoaded {
+                                uri: list.uri,
+                                posts,
+                                cursor,
+                                append: false,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ListLoaded {
+                list,
+                members,
+                cursor,
+                append,
+            } => {
+                if let Some(entry) = self.lists.as_mut().and_then(|l| l.list_mut(&list.uri)) {
+                    *entry = list.clone();
+                }
+                let Some(ref mut detail) = self.list_detail else {
+                    return;
+                };
+                if detail.list.uri != list.uri {
+                    return;
+                }
+                detail.list = list;
+                if append {
+                    detail.members.append(members, cursor);
+                } else {
+                    detail.members.replace(members, cursor);
+                }
+            }
+
+            Action::LoadMoreListMembers => {
+                let Some(ref mut detail) = self.list_detail else {
+                    return;
+                };
+                if detail.members.loading || detail.members.cursor.is_none() {
+                    return;
+                }
+                detail.members.loading = true;
+                let uri = detail.list.uri.clone();
+                let cursor = detail.members.cursor.clone();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_list(&uri, cursor).await {
+                        Ok((list, members, cursor)) => {
+                            let _ = tx.send(Action::ListLoaded {
+                                list,
+                                members,
+                                cursor,
+                                append: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::LoadMoreListFeed => {
+                let Some(ref mut detail) = self.list_detail else {
+                    return;
+                };
+                if detail.feed.loading || detail.feed.cursor.is_none() {
+                    return;
+                }
+                detail.feed.loading = true;
+                let uri = detail.list.uri.clone();
+                let cursor = detail.feed.cursor.clone();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                self.spawn_load(async move {
+                    match client.get_feed(&FeedSource::List(uri.clone()), cursor).await {
+                        Ok((posts, cursor)) => {
+                            let _ = tx.send(Action::ListFeedLoaded {
+                                uri,
+                                posts,
+                                cursor,
+                                append: true,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
+
+            Action::ListFeedLoaded {
+                uri,
+                mut posts,
+                cursor,
+                append,
+            } => {
+                self.moderate(&mut posts, false);
+                let Some(ref mut detail) = self.list_detail else {
+                    return;
+                };
+                if detail.list.uri != uri {
+                    return;
+                }
+                if append {
+                    detail.feed.append_posts(posts, cursor);
+                } else {
+                    detail.feed.replace_posts(posts, cursor);
+                }
+            }
+
+            Action::ToggleListMute => {
+                let Some(list) = self.current_moderation_list() else {
+                    return;
+                };
+                let uri = list.uri.clone();
+                if !list.muted {
+                    self.confirm = Some(ConfirmDialog::new(
+                        "Mute list",
+                        format!("Mute everyone on \"{}\"? You won't see their posts.", list.name),
+                        Action::ConfirmListMute(uri),
+                    ));
+                    return;
+                }
+                self.update_list(&uri, |l| l.muted = false);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = client.set_list_muted(&uri, false).await {
+                        let _ = tx.send(Action::ListMuteUpdated { uri, muted: true });
+                        let _ = tx.send(Action::Error(e.to_string()));
+                    }
+                });
+            }
+
+            Action::ConfirmListMute(uri) => {
+                self.confirm = None;
+                self.update_list(&uri, |l| l.muted = true);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = client.set_list_muted(&uri, true).await {
+                        let _ = tx.send(Action::ListMuteUpdated { uri, muted: false });
+                        let _ = tx.send(Action::Error(e.to_string()));
+                    }
+                });
+            }
+
+            Action::ListMuteUpdated { uri, muted } => {
+                self.update_list(&uri, |l| l.muted = muted);
+            }
+
+            Action::ToggleListBlock => {
+                let Some(list) = self.current_moderation_list() else {
+                    return;
+                };
+                let uri = list.uri.clone();
+                let Some(blocking_uri) = list.blocking_uri.clone() else {
+                    self.confirm = Some(ConfirmDialog::new(
+                        "Block list",
+                        format!(
+                            "Block everyone on \"{}\"? They won't be able to see or interact with your posts.",
+                            list.name
+                        ),
+                        Action::ConfirmListBlock(uri),
+                    ));
+                    return;
+                };
+                self.update_list(&uri, |l| l.blocking_uri = None);
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = client.unblock_list(&blocking_uri).await {
+                        let _ = tx.send(Action::ListBlockUpdated {
+                            uri,
+                            blocking_uri: Some(blocking_uri),
+                        });
+                        let _ = tx.send(Action::Error(e.to_string()));
+                    }
+                });
             }
 
-            Action::LikeSuccess { post_uri, like_uri } => {
-                self.update_post(&post_uri, |p| {
-                    p.is_liked = true;
-                    p.like_uri = Some(like_uri.clone());
-                    p.like_count += 1;
+            Action::ConfirmListBlock(uri) => {
+                self.confirm = None;
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.block_list(&uri).await {
+                        Ok(blocking_uri) => {
+                            let _ = tx.send(Action::ListBlockUpdated {
+                                uri,
+                                blocking_uri: Some(blocking_uri),
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
                 });
             }
 
-            Action::UnlikeSuccess { post_uri } => {
-                self.update_post(&post_uri, |p| {
-                    p.is_liked = false;
-                    p.like_uri = None;
-                    p.like_count = (p.like_count - 1).max(0);
-                });
+            Action::ListBlockUpdated { uri, blocking_uri } => {
+                self.update_list(&uri, |l| l.blocking_uri = blocking_uri.clone());
             }
 
-            Action::ToggleRepost => {
-                let post = match self.screen {
-                    Screen::Timeline => self.timeline.selected_post().cloned(),
-                    Screen::Thread => self.thread.as_ref().map(|t| t.focal.clone()),
-                    Screen::Profile => self.profile_feed.selected_post().cloned(),
-                    _ => None,
+            Action::ShowListPicker => {
+                let member = self
+                    .list_detail
+                    .as_ref()
+                    .filter(|d| self.screen == Screen::ListDetail && d.tab == ListTab::Members)
+                    .and_then(|d| d.members.selected_item())
+                    .map(|a| (a.did.clone(), a.handle.clone()));
+                let subject = match self.screen {
+                    Screen::Profile => self.profile.as_ref().map(|p| (p.did.clone(), p.handle.clone())),
+                    _ => member.or_else(|| {
+                        self.selected_post()
+                            .map(|p| (p.author_did.clone(), p.author_handle.clone()))
+                    }),
+                };
+                let Some((did, handle)) = subject else {
+                    return;
                 };
+                self.list_picker = Some(ListPicker::new(did.clone(), handle));
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    match client.get_lists_with_membership(&did).await {
+                        Ok(lists) => {
+                            let _ = tx.send(Action::ListMembershipsLoaded { did, lists });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::CloseListPicker);
+                            let _ = tx.send(Action::Error(e.to_string()));
+                        }
+                    }
+                });
+            }
 
-                if let Some(post) = post {
-                    let client = self.client.clone();
-                    let tx = self.action_tx.clone();
-                    if post.is_reposted {
-                        if let Some(repost_uri) = post.repost_uri.clone() {
-                            let post_uri = post.uri.clone();
-                            tokio::spawn(async move {
-                                match client.unrepost(&repost_uri).await {
-                                    Ok(_) => {
-                                        let _ = tx.send(Action::UnrepostSuccess {
-                                            post_uri,
-                                        });
-                                    }
-                                    Err(e) => {
-                                        let _ = tx.send(Action::Error(e.to_string()));
-                                    }
-                                }
+            Action::CloseListPicker => {
+                self.list_picker = None;
+            }
+
+            Action::ListMembershipsLoaded { did, lists } => {
+                if let Some(picker) = self.list_picker.as_mut().filter(|p| p.did() == did) {
+                    picker.set_lists(lists);
+                }
+            }
+
+            Action::ToggleListMembership(list_uri) => {
+                let Some(ref mut picker) = self.list_picker else {
+                    return;
+                };
+                let Some(item_uri) = picker.begin_toggle(&list_uri) else {
+                    return;
+                };
+                let did = picker.did().to_string();
+                let client = self.client.clone();
+                let tx = self.action_tx.clone();
+                tokio::spawn(async move {
+                    let result = match item_uri {
+                        Some(ref item_uri) => client.remove_from_list(item_uri).await.map(|()| None),
+                        None => client.add_to_list(&list_uri, &did).await.map(Some),
+                    };
+                    match result {
+                        Ok(item_uri) => {
+                            let _ = tx.send(Action::ListMembershipUpdated {
+                                did,
+                                list_uri,
+                                item_uri,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Action::ListMembershipFailed {
+                                list_uri,
+                                error: e.to_string(),
                             });
                         }
-                    } else {
-                        let uri = post.uri.clone();
-                        let cid = post.cid.clone();
-                        tokio::spawn(async move {
-                            match client.repost(&uri, &cid).await {
-                                Ok(repost_uri) => {
-                                    let _ = tx.send(Action::RepostSuccess {
-                                        post_uri: uri,
-                                        repost_uri,
-                                    });
-                                }
-                                Err(e) => {
-                                    let _ = tx.send(Action::Error(e.to_string()));
-                                }
-                            }
-                        });
                     }
-                }
+                });
             }
 
-            Action::RepostSuccess {
-                post_uri,
-                repost_uri,
+            Action::ListMembershipUpdated {
+                did,
+                list_uri,
+                item_uri,
             } => {
-                self.update_post(&post_uri, |p| {
-                    p.is_reposted = true;
-                    p.repost_uri = Some(repost_uri.clone());
-                    p.repost_count += 1;
+                let added = item_uri.is_some();
+                if let Some(picker) = self.list_picker.as_mut().filter(|p| p.did() == did) {
+                    picker.set_membership(&list_uri, item_uri);
+                }
+                self.update_list(&list_uri, |l| {
+                    l.member_count = if added {
+                        l.member_count + 1
+                    } else {
+                        l.member_count.saturating_sub(1)
+                    };
                 });
+                // New members show up the next time the list is opened
+                if let Some(detail) = self.list_detail.as_mut().filter(|d| d.list.uri == list_uri) {
+                    if !added {
+                        detail.members.retain(|m| m.did != did);
+                    }
+                }
             }
 
-            Action::UnrepostSuccess { post_uri } => {
-                self.update_post(&post_uri, |p| {
-                    p.is_reposted = false;
-                    p.repost_uri = None;
-                    p.repost_count = (p.repost_count - 1).max(0);
-                });
+            Action::ListMembershipFailed { list_uri, error } => {
+                if let Some(ref mut picker) = self.list_picker {
+                    picker.clear_pending(&list_uri);
+                }
+                self.dispatch(Action::Error(error));
             }
 
-            Action::ViewAuthorProfile => {
-                let did = match self.screen {
-                    Screen::Timeline => {
-                        self.timeline.selected_post().map(|p| p.author_did.clone())
-                    }
-                    Screen::Thread => {
-                        self.thread.as_ref().map(|t| t.focal.author_did.clone())
-                    }
-                    _ => None,
+            Action::ShowReport => {
+                let own = self.did.clone();
+                let post = self
+                    .selected_post()
+                    .filter(|p| own.as_deref() != Some(p.author_did.as_str()));
+                let post_subject = post.map(|p| ReportSubject::Post {
+                    uri: p.uri.clone(),
+                    cid: p.cid.clone(),
+                    author_handle: p.author_handle.clone(),
+                });
+                // On a profile the account comes first; elsewhere the post's author
+                let profile = self
+                    .profile
+                    .as_ref()
+                    .filter(|_| self.screen == Screen::Profile)
+                    .filter(|p| own.as_deref() != Some(p.did.as_str()));
+                let subjects: Vec<ReportSubject> = match profile {
+                    Some(profile) => std::iter::once(ReportSubject::Account {
+                        did: profile.did.clone(),
+                        handle: profile.handle.clone(),
+                    })
+                    .chain(post_subject)
+                    .collect(),
+                    None => post_subject
+                        .into_iter()
+                        .chain(post.map(|p| ReportSubject::Account {
+                            did: p.author_did.clone(),
+                            handle: p.author_handle.clone(),
+                        }))
+                        .collect(),
                 };
-                if let Some(did) = did {
-                    self.screen_stack.push(self.screen.clone());
-                    self.dispatch(Action::LoadProfile(did));
+                if subjects.is_empty() {
+                    return;
                 }
+                self.report = Some(ReportDialog::new(subjects, self.moderation.services.clone()));
             }
 
-            Action::LoadProfile(actor) => {
-                self.screen = Screen::Profile;
-                self.profile = None;
-                self.profile_feed = FeedState::new();
-                self.profile_feed.loading = true;
+            Action::CloseReport => {
+                self.report = None;
+            }
+
+            Action::SubmitReport(report) => {
                 let client = self.client.clone();
                 let tx = self.action_tx.clone();
-                self.spawn_load(async move {
-                    let profile_result = client.get_profile(&actor).await;
-                    let feed_result = client.get_author_feed(&actor, None).await;
-                    match (profile_result, feed_result) {
-                        (Ok(profile), Ok((posts, cursor))) => {
-                            let _ = tx.send(Action::ProfileLoaded {
-                                profile,
-                                posts,
-                                cursor,
-                            });
+                tokio::spawn(async move {
+                    match client.create_report(report).await {
+                        Ok(()) => {
+                            let _ = tx.send(Action::ReportSubmitted);
                         }
-                        (Err(e), _) | (_, Err(e)) => {
-                            let _ = tx.send(Action::Error(e.to_string()));
+                        Err(e) => {
+                            let _ = tx.send(Action::ReportFailed(e.to_string()));
                         }
                     }
                 });
             }
 
-            Action::ProfileLoaded {
-                profile,
-                posts,
-                cursor,
-            } => {
-                self.profile = Some(profile);
-                self.profile_feed.replace_posts(posts, cursor);
+            Action::ReportSubmitted => {
+                if let Some(ref mut report) = self.report {
+                    report.set_sent();
+                }
             }
 
+            Action::ReportFailed(error) => match self.report {
+                Some(ref mut report) => report.set_failed(error),
+                None => self.dispatch(Action::Error(format!("Report failed: {}", error))),
+            },
+
             Action::ShowAbout => {
                 self.screen_stack.push(self.screen.clone());
                 self.screen = Screen::About;
@@ -646,30 +3222,196 @@ impl App {
         }
     }
 
+    fn selected_search_post(&self) -> Option<&crate::models::post::PostViewModel> {
+        match self.search.mode {
+            SearchMode::Posts => self.search.posts.selected_post(),
+            SearchMode::People => None,
+        }
+    }
+
+    fn timeline(&self) -> &FeedState {
+        &self.feeds[self.active_feed].state
+    }
+
+    fn timeline_mut(&mut self) -> &mut FeedState {
+        &mut self.feeds[self.active_feed].state
+    }
+
+    /// Drop everything loaded for the previous account.
+    fn reset_account_state(&mut self) {
+        self.cancel_load();
+        self.screen_stack.clear();
+        self.active_tab = 0;
+        self.feeds = vec![SavedFeed::following()];
+        self.active_feed = 0;
+        self.show_feed_picker = false;
+        self.show_draft_picker = false;
+        self.confirm = None;
+        self.thread = None;
+        self.thread_error = None;
+        self.profile = None;
+        self.profile_feed = FeedState::new();
+        self.notifications = NotificationState::new();
+        self.search = SearchState::new();
+        self.follow_list = None;
+        self.lists = None;
+        self.list_detail = None;
+        self.list_picker = None;
+        self.convos = ConvoListState::new();
+        self.conversation = None;
+        self.show_message_input = false;
+        self.moderation = ModerationPrefs::default();
+        self.muted_word_selected = 0;
+        self.show_muted_word_prompt = false;
+        self.report = None;
+        self.show_search_prompt = false;
+        self.error_message = None;
+    }
+
+    /// Drafts are kept per account.
+    fn drafts_owner(&self) -> &str {
+        self.did.as_deref().unwrap_or_default()
+    }
+
+    fn login_result(result: anyhow::Result<(BlueskyClient, String)>) -> Action {
+        match result {
+            Ok((client, handle)) => Action::AccountSwitched {
+                client: Arc::new(client),
+                handle,
+            },
+            Err(e) => Action::LoginFailed(e.to_string()),
+        }
+    }
+
+    /// Clear the unread badge and tell the server everything up to the newest
+    /// loaded notification has been seen, so later arrivals stay unread.
+    fn mark_notifications_seen(&mut self) {
+        let unread = self.notifications.unread_count > 0
+            || self.notifications.notifications.iter().any(|n| !n.is_read);
+        self.notifications.unread_count = 0;
+        let Some(seen_at) = self.notifications.newest_at().filter(|_| unread) else {
+            return;
+        };
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            if let Err(e) = client.update_seen(seen_at).await {
+                error!("Failed to update seen notifications: {}", e);
+            }
+        });
+    }
+
     fn update_post(&mut self, uri: &str, f: impl Fn(&mut crate::models::post::PostViewModel)) {
-        for post in &mut self.timeline.posts {
+        self.for_each_post(|post, _| {
             if post.uri == uri {
                 f(post);
             }
+        });
+    }
+
+    /// Every loaded post, with whether it's part of the open thread.
+    fn for_each_post(&mut self, mut f: impl FnMut(&mut crate::models::post::PostViewModel, bool)) {
+        for feed in &mut self.feeds {
+            for post in &mut feed.state.posts {
+                f(post, false);
+            }
         }
         for post in &mut self.profile_feed.posts {
-            if post.uri == uri {
-                f(post);
+            f(post, false);
+        }
+        for post in &mut self.search.posts.posts {
+            f(post, false);
+        }
+        if let Some(ref mut detail) = self.list_detail {
+            for post in &mut detail.feed.posts {
+                f(post, false);
             }
         }
         if let Some(ref mut thread) = self.thread {
-            if thread.focal.uri == uri {
-                f(&mut thread.focal);
+            for post in thread.posts_mut() {
+                f(post, true);
             }
-            for post in &mut thread.parents {
-                if post.uri == uri {
-                    f(post);
-                }
+        }
+    }
+
+    fn moderate<'a>(
+        &self,
+        posts: impl IntoIterator<Item = &'a mut crate::models::post::PostViewModel>,
+        in_thread: bool,
+    ) {
+        for post in posts {
+            self.moderation.apply(post, self.did.as_deref(), in_thread);
+        }
+    }
+
+    /// Re-filter everything loaded after the moderation preferences change.
+    fn moderate_loaded(&mut self) {
+        let moderation = self.moderation.clone();
+        let did = self.did.clone();
+        self.for_each_post(|post, in_thread| moderation.apply(post, did.as_deref(), in_thread));
+    }
+
+    /// The profile on screen, when it belongs to someone other than us.
+    fn other_profile(&mut self) -> Option<&mut ProfileViewModel> {
+        if self.screen != Screen::Profile {
+            return None;
+        }
+        let own = self.did.clone();
+        self.profile
+            .as_mut()
+            .filter(|p| own.as_deref() != Some(p.did.as_str()))
+    }
+
+    /// The moderation list under the cursor on the lists screens.
+    fn current_moderation_list(&mut self) -> Option<ListViewModel> {
+        let list = match self.screen {
+            Screen::Lists => self.lists.as_ref().and_then(|l| l.lists.selected_item()),
+            Screen::ListDetail => self.list_detail.as_ref().map(|d| &d.list),
+            _ => None,
+        }?
+        .clone();
+        if list.purpose != ListPurpose::Moderation {
+            self.dispatch(Action::Error(
+                "Only moderation lists can be muted or blocked".to_string(),
+            ));
+            return None;
+        }
+        Some(list)
+    }
+
+    /// Apply `f` to every loaded copy of the list at `uri`.
+    fn update_list(&mut self, uri: &str, f: impl Fn(&mut ListViewModel)) {
+        if let Some(list) = self.lists.as_mut().and_then(|l| l.list_mut(uri)) {
+            f(list);
+        }
+        if let Some(detail) = self.list_detail.as_mut().filter(|d| d.list.uri == uri) {
+            f(&mut detail.list);
+        }
+    }
+
+    /// Drop a deleted post from every loaded list and the open thread.
+    fn remove_post(&mut self, uri: &str) {
+        for feed in &mut self.feeds {
+            feed.state.remove_post(uri);
+        }
+        self.profile_feed.remove_post(uri);
+        self.search.posts.remove_post(uri);
+        if let Some(ref mut detail) = self.list_detail {
+            detail.feed.remove_post(uri);
+        }
+
+        if let Some(ref mut profile) = self.profile {
+            if self.did.as_deref() == Some(profile.did.as_str()) {
+                profile.posts_count = (profile.posts_count - 1).max(0);
             }
-            for post in &mut thread.replies {
-                if post.uri == uri {
-                    f(post);
+        }
+
+        if let Some(ref mut thread) = self.thread {
+            if thread.focal.uri == uri {
+                if self.screen == Screen::Thread {
+                    self.dispatch(Action::GoBack);
                 }
+            } else {
+                thread.mark_deleted(uri);
             }
         }
     }
@@ -687,7 +3429,14 @@ impl App {
             .split(area);
 
         // Tab bar
-        crate::ui::tabs::draw_tabs(frame, chunks[0], self.active_tab);
+        crate::ui::tabs::draw_tabs(
+            frame,
+            chunks[0],
+            self.active_tab,
+            self.handle.as_deref(),
+            self.notifications.unread_count,
+            self.convos.unread_count,
+        );
 
         // Main content
         match self.screen {
@@ -695,17 +3444,87 @@ impl App {
                 self.login_form.draw(frame, chunks[1]);
             }
             Screen::Timeline => {
-                crate::ui::timeline::draw_timeline(frame, chunks[1], &self.timeline);
+                let [feed_bar, content] = Layout::vertical([
+                    Constraint::Length(1),
+                    Constraint::Min(1),
+                ])
+                .areas(chunks[1]);
+                crate::ui::timeline::draw_feed_bar(frame, feed_bar, &self.feeds, self.active_feed);
+                crate::ui::timeline::draw_timeline(frame, content, self.timeline());
             }
             Screen::Thread => {
-                crate::ui::thread::draw_thread(frame, chunks[1], self.thread.as_ref());
+                crate::ui::thread::draw_thread(
+                    frame,
+                    chunks[1],
+                    self.thread.as_ref(),
+                    self.thread_error.as_deref(),
+                );
             }
             Screen::Profile => {
+                let own = self
+                    .profile
+                    .as_ref()
+                    .is_some_and(|p| self.did.as_deref() == Some(p.did.as_str()));
                 crate::ui::profile::draw_profile(
                     frame,
                     chunks[1],
                     self.profile.as_ref(),
                     &self.profile_feed,
+                    self.profile_tab,
+                    own,
+                );
+            }
+            Screen::Notifications => {
+                crate::ui::notifications::draw_notifications(
+                    frame,
+                    chunks[1],
+                    &self.notifications,
+                );
+            }
+            Screen::Search => {
+                crate::ui::search::draw_search(frame, chunks[1], &self.search);
+            }
+            Screen::FollowList => {
+                crate::ui::follows::draw_follow_list(frame, chunks[1], self.follow_list.as_ref());
+            }
+            Screen::Messages => {
+                crate::ui::messages::draw_convo_list(frame, chunks[1], &self.convos);
+            }
+            Screen::Conversation => {
+                if let Some(ref conversation) = self.conversation {
+                    let input_height = if self.show_message_input {
+                        self.message_input.height()
+                    } else {
+                        0
+                    };
+                    let [messages, input] = Layout::vertical([
+                        Constraint::Min(1),
+                        Constraint::Length(input_height),
+                    ])
+                    .areas(chunks[1]);
+                    crate::ui::messages::draw_conversation(
+                        frame,
+                        messages,
+                        conversation,
+                        self.did.as_deref(),
+                    );
+                    if self.show_message_input {
+                        self.message_input.draw(frame, input);
+                    }
+                }
+            }
+            Screen::Lists => {
+                crate::ui::lists::draw_lists(frame, chunks[1], self.lists.as_ref());
+            }
+            Screen::ListDetail => {
+                crate::ui::lists::draw_list_detail(frame, chunks[1], self.list_detail.as_ref());
+            }
+            Screen::MutedWords => {
+                crate::ui::muted_words::draw_muted_words(
+                    frame,
+                    chunks[1],
+                    &self.moderation.muted_words,
+                    self.muted_word_selected,
                 );
             }
             Screen::About => {
@@ -727,6 +3546,46 @@ impl App {
             self.composer.draw(frame, area);
         }
 
+        // Search prompt overlay
+        if self.show_search_prompt {
+            self.search_prompt.draw(frame, area);
+        }
+
+        // Muted word prompt overlay
+        if self.show_muted_word_prompt {
+            self.muted_word_prompt.draw(frame, area);
+        }
+
+        // Feed picker overlay
+        if self.show_feed_picker {
+            self.feed_picker.draw(frame, area);
+        }
+
+        // Drafts overlay
+        if self.show_draft_picker {
+            self.draft_picker.draw(frame, area);
+        }
+
+        // Account picker overlay
+        if self.show_account_picker {
+            self.account_picker.draw(frame, area);
+        }
+
+        // Report overlay
+        if let Some(ref report) = self.report {
+            report.draw(frame, area);
+        }
+
+        // List picker overlay
+        if let Some(ref picker) = self.list_picker {
+            picker.draw(frame, area);
+        }
+
+        // Confirmation dialog
+        if let Some(ref confirm) = self.confirm {
+            confirm.draw(frame, area);
+        }
+
         // Promo popover overlay
         if self.show_promo {
             self.draw_promo_popover(frame, area);
@@ -766,3 +3625,51 @@ Enter: open App Store  |  any key: dismiss";
         frame.render_widget(popup, popup_area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::post::{PostLabel, PostViewModel};
+    use crate::models::profile::Relationship;
+
+    fn labeled(uri: &str, val: &str) -> PostViewModel {
+        let mut post = PostViewModel::sample(uri, "did:plc:author", "hello");
+        post.labels = vec![PostLabel {
+            src: "did:plc:labeler".to_string(),
+            val: val.to_string(),
+        }];
+        post
+    }
+
+    #[tokio::test]
+    async fn profile_loads_are_moderated() {
+        let client = Arc::new(BlueskyClient::new("https://bsky.social").await.unwrap());
+        let mut app = App::new(None, false, client);
+        let profile = ProfileViewModel {
+            did: "did:plc:author".to_string(),
+            handle: "author.test".to_string(),
+            display_name: "Author".to_string(),
+            description: String::new(),
+            avatar: None,
+            banner: None,
+            followers_count: 0,
+            follows_count: 0,
+            posts_count: 0,
+            viewer: Relationship::default(),
+        };
+        let posts = vec![
+            labeled("at://did:plc:author/app.bsky.feed.post/1", "!hide"),
+            labeled("at://did:plc:author/app.bsky.feed.post/2", "!warn"),
+        ];
+        app.update(Action::ProfileLoaded {
+            profile,
+            posts,
+            cursor: None,
+        })
+        .await;
+
+        let posts = &app.profile_feed.posts;
+        assert!(posts[0].filtered.is_some());
+        assert!(posts[1].content_warning.is_some());
+    }
+}
@@ -53,10 +53,11 @@ impl AppConfig {
 }
 
 fn config_path() -> PathBuf {
-    dirs_path().join("config.toml")
+    config_dir().join("config.toml")
 }
 
-fn dirs_path() -> PathBuf {
+/// Where config, sessions and drafts are kept.
+pub fn config_dir() -> PathBuf {
     let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
     PathBuf::from(home).join(".config").join("skyscraper")
 }
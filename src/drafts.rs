@@ -0,0 +1,163 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::client::{QuoteRef, ReplyRef};
+use crate::config;
+
+/// Unsent composer contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Draft {
+    /// What the draft is for: `new`, `reply:<uri>` or `quote:<uri>`. There is
+    /// at most one draft per key.
+    pub key: String,
+    pub segments: Vec<String>,
+    #[serde(default)]
+    pub reply_to: Option<ReplyRef>,
+    #[serde(default)]
+    pub reply_to_author: Option<String>,
+    #[serde(default)]
+    pub quote: Option<QuoteRef>,
+    #[serde(default)]
+    pub quote_preview: Option<String>,
+    #[serde(default)]
+    pub mentions: HashMap<String, String>,
+    #[serde(default)]
+    pub images: Vec<DraftImage>,
+    /// Self-label value, e.g. `graphic-media`.
+    #[serde(default)]
+    pub self_label: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An attached image, read from disk again when the draft is opened.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftImage {
+    pub path: PathBuf,
+    pub alt: String,
+}
+
+impl Draft {
+    pub fn key_for(reply_to: Option<&ReplyRef>, quote: Option<&QuoteRef>) -> String {
+        match (reply_to, quote) {
+            (Some(reply), _) => format!("reply:{}", reply.parent_uri),
+            (None, Some(quote)) => format!("quote:{}", quote.uri),
+            (None, None) => "new".to_string(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty() && self.segments.iter().all(|s| s.trim().is_empty())
+    }
+
+    /// What's left of a thread whose first `posted` segments went out, continuing
+    /// from the last one that did.
+    pub fn unposted(mut self, posted: usize, reply_to: Option<ReplyRef>) -> Self {
+        if posted > 0 {
+            self.segments.drain(..posted.min(self.segments.len().saturating_sub(1)));
+            self.images.clear();
+            self.self_label = None;
+            self.quote = None;
+            self.quote_preview = None;
+            self.key = Self::key_for(reply_to.as_ref(), None);
+            self.reply_to = reply_to;
+            self.reply_to_author = Some("your thread".to_string());
+        }
+        self.updated_at = Utc::now();
+        self
+    }
+
+    /// "Reply to alice", "Quote bob" or "New post".
+    pub fn target(&self) -> String {
+        if self.reply_to.is_some() {
+            format!(
+                "Reply to {}",
+                self.reply_to_author.as_deref().unwrap_or("post")
+            )
+        } else if let Some(ref preview) = self.quote_preview {
+            format!("Quote {}", preview.split(':').next().unwrap_or("post"))
+        } else {
+            "New post".to_string()
+        }
+    }
+}
+
+/// Drafts keyed by the DID of the account that wrote them.
+type DraftStore = BTreeMap<String, Vec<Draft>>;
+
+fn drafts_path() -> PathBuf {
+    config::config_dir().join("drafts.json")
+}
+
+/// Earlier versions wrote a single list shared by every account; the first
+/// account to load drafts takes it over.
+fn load_store(did: &str) -> Result<DraftStore> {
+    let path = drafts_path();
+    if !path.exists() {
+        return Ok(DraftStore::new());
+    }
+    let json = std::fs::read_to_string(&path)?;
+    if let Ok(legacy) = serde_json::from_str::<Vec<Draft>>(&json) {
+        let store = DraftStore::from([(did.to_string(), legacy)]);
+        write_store(&store)?;
+        return Ok(store);
+    }
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// All of an account's drafts, most recently edited first.
+pub fn load_all(did: &str) -> Result<Vec<Draft>> {
+    let mut drafts = load_store(did)?.remove(did).unwrap_or_default();
+    drafts.sort_by_key(|d| std::cmp::Reverse(d.updated_at));
+    Ok(drafts)
+}
+
+pub fn load(did: &str, key: &str) -> Option<Draft> {
+    load_all(did).ok()?.into_iter().find(|d| d.key == key)
+}
+
+/// Insert or replace the draft with the same key.
+pub fn save(did: &str, draft: &Draft) -> Result<()> {
+    let mut store = load_store(did)?;
+    let drafts = store.entry(did.to_string()).or_default();
+    drafts.retain(|d| d.key != draft.key);
+    drafts.insert(0, draft.clone());
+    write_store(&store)
+}
+
+pub fn delete(did: &str, key: &str) -> Result<()> {
+    let mut store = load_store(did)?;
+    let Some(drafts) = store.get_mut(did) else {
+        return Ok(());
+    };
+    let before = drafts.len();
+    drafts.retain(|d| d.key != key);
+    if drafts.len() == before {
+        return Ok(());
+    }
+    if drafts.is_empty() {
+        store.remove(did);
+    }
+    write_store(&store)
+}
+
+fn write_store(store: &DraftStore) -> Result<()> {
+    let dir = config::config_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let json = serde_json::to_string_pretty(store)?;
+    let path = drafts_path();
+    std::fs::write(&path, json)?;
+
+    // Drafts may hold unpublished text; owner-only like the session files
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyModifiers};
 use futures::StreamExt;
 
 use crate::action::Action;
+use crate::models::follows::FollowListKind;
 
 pub struct EventHandler {
     stream: EventStream,
@@ -40,6 +41,7 @@ pub fn key_to_action(key: KeyEvent, in_composer: bool, in_login: bool) -> Option
             Some(Action::SelectPrev)
         }
         (KeyModifiers::NONE, KeyCode::Enter) => Some(Action::OpenThread),
+        (KeyModifiers::NONE, KeyCode::Char(' ')) => Some(Action::ToggleReplies),
         (KeyModifiers::NONE, KeyCode::Esc) => Some(Action::GoBack),
         (KeyModifiers::NONE, KeyCode::Char('n')) => Some(Action::OpenComposer {
             reply_to: None,
@@ -52,15 +54,41 @@ pub fn key_to_action(key: KeyEvent, in_composer: bool, in_login: bool) -> Option
                 reply_to_author: None,
             })
         }
+        (KeyModifiers::SHIFT, KeyCode::Char('Q')) => Some(Action::QuotePost),
         (KeyModifiers::NONE, KeyCode::Char('l')) => Some(Action::ToggleLike),
         (KeyModifiers::NONE, KeyCode::Char('t')) => Some(Action::ToggleRepost),
         (KeyModifiers::NONE, KeyCode::Char('u')) => Some(Action::ViewAuthorProfile),
+        (KeyModifiers::NONE, KeyCode::Char('d')) => Some(Action::DeletePost),
         (KeyModifiers::SHIFT, KeyCode::Char('R')) => Some(Action::RefreshTimeline),
         (KeyModifiers::NONE, KeyCode::Char('g')) => Some(Action::ScrollToTop),
         (KeyModifiers::SHIFT, KeyCode::Char('G')) => Some(Action::ScrollToBottom),
         (KeyModifiers::NONE, KeyCode::Char('1')) => Some(Action::SwitchTab(0)),
         (KeyModifiers::NONE, KeyCode::Char('2')) => Some(Action::SwitchTab(1)),
+        (KeyModifiers::NONE, KeyCode::Char('3')) => Some(Action::SwitchTab(2)),
+        (KeyModifiers::NONE, KeyCode::Char('4')) => Some(Action::SwitchTab(3)),
+        (KeyModifiers::NONE, KeyCode::Char('[')) => Some(Action::PrevFeed),
+        (KeyModifiers::NONE, KeyCode::Char(']')) => Some(Action::NextFeed),
+        (KeyModifiers::NONE, KeyCode::Char('f')) => Some(Action::ShowFeedPicker),
+        (KeyModifiers::NONE, KeyCode::Char('/')) => Some(Action::ShowSearchPrompt),
+        (KeyModifiers::NONE, KeyCode::Tab) => Some(Action::ToggleSearchMode),
+        (KeyModifiers::NONE, KeyCode::Char('s')) => Some(Action::ToggleSearchSort),
         (KeyModifiers::NONE, KeyCode::Char('a')) => Some(Action::ShowAbout),
+        (KeyModifiers::SHIFT, KeyCode::Char('A')) => Some(Action::ShowAccountSwitcher),
+        (KeyModifiers::SHIFT, KeyCode::Char('D')) => Some(Action::ShowDrafts),
+        (KeyModifiers::NONE, KeyCode::Char('o')) => {
+            Some(Action::ShowFollowList(FollowListKind::Followers))
+        }
+        (KeyModifiers::SHIFT, KeyCode::Char('O')) => {
+            Some(Action::ShowFollowList(FollowListKind::Follows))
+        }
+        (KeyModifiers::NONE, KeyCode::Char('v')) => Some(Action::ToggleFilteredPost),
+        (KeyModifiers::SHIFT, KeyCode::Char('W')) => Some(Action::ShowMutedWords),
+        (KeyModifiers::SHIFT, KeyCode::Char('F')) => Some(Action::ToggleFollow),
+        (KeyModifiers::SHIFT, KeyCode::Char('M')) => Some(Action::ToggleMute),
+        (KeyModifiers::SHIFT, KeyCode::Char('B')) => Some(Action::ToggleBlock),
+        (_, KeyCode::Char('!')) => Some(Action::ShowReport),
+        (KeyModifiers::SHIFT, KeyCode::Char('L')) => Some(Action::ShowLists),
+        (KeyModifiers::NONE, KeyCode::Char('i')) => Some(Action::ShowListPicker),
         _ => None,
     }
 }
@@ -4,6 +4,7 @@ mod action;
 mod api;
 mod app;
 mod config;
+mod drafts;
 mod event;
 mod models;
 mod tui;
@@ -18,10 +19,14 @@ use clap::Parser;
 #[derive(Parser, Debug)]
 #[command(name = "skyscraper", version, about = "A TUI client for Bluesky")]
 struct Cli {
-    /// Bluesky handle (e.g. alice.bsky.social)
+    /// Saved account to use, or handle to pre-fill at login (e.g. alice.bsky.social)
     #[arg(short = 'u', long)]
     handle: Option<String>,
 
+    /// PDS or entryway URL to use (defaults to `service` in config.toml)
+    #[arg(long)]
+    service: Option<String>,
+
     /// Use app password authentication instead of OAuth
     #[arg(long)]
     app_password: bool,
@@ -43,7 +48,11 @@ async fn main() -> Result<()> {
         .with_writer(std::io::stderr)
         .init();
 
-    let client = Arc::new(api::client::BlueskyClient::new().await?);
+    let config = config::AppConfig::load().unwrap_or_default();
+    let prefer_app_password = cli.app_password || config.prefer_app_password;
+    let service = cli.service.unwrap_or(config.service);
+
+    let client = Arc::new(api::client::BlueskyClient::new(&service).await?);
 
     let mut terminal = tui::init()?;
 
@@ -54,7 +63,7 @@ async fn main() -> Result<()> {
         default_panic(info);
     }));
 
-    let result = app::App::new(cli.handle, cli.app_password, client).run(&mut terminal).await;
+    let result = app::App::new(cli.handle, prefer_app_password, client).run(&mut terminal).await;
     tui::restore()?;
 
     result
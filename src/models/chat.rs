@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+
+use super::paged::PagedList;
+
+/// The other side of a conversation.
+#[derive(Debug, Clone)]
+pub struct ChatMember {
+    pub did: String,
+    pub handle: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConvoViewModel {
+    pub id: String,
+    /// Members other than the signed-in account.
+    pub members: Vec<ChatMember>,
+    pub last_message: Option<String>,
+    pub last_message_at: Option<DateTime<Utc>>,
+    pub unread_count: i64,
+    pub muted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageViewModel {
+    pub id: String,
+    pub sender_did: String,
+    pub text: String,
+    pub sent_at: DateTime<Utc>,
+    pub deleted: bool,
+}
+
+impl ConvoViewModel {
+    pub fn from_convo_view(
+        convo: &atrium_api::chat::bsky::convo::defs::ConvoView,
+        own_did: Option<&str>,
+    ) -> Self {
+        use atrium_api::chat::bsky::convo::defs::ConvoViewLastMessageRefs;
+        use atrium_api::types::Union;
+
+        let members = convo
+            .members
+            .iter()
+            .filter(|m| Some(m.did.as_str()) != own_did)
+            .map(|m| ChatMember {
+                did: m.did.to_string(),
+                handle: m.handle.to_string(),
+                display_name: m
+                    .display_name
+                    .clone()
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| m.handle.to_string()),
+            })
+            .collect();
+
+        let (last_message, last_message_at) = match &convo.last_message {
+            Some(Union::Refs(ConvoViewLastMessageRefs::MessageView(m))) => {
+                (Some(m.text.clone()), parse_datetime(m.sent_at.as_str()))
+            }
+            Some(Union::Refs(ConvoViewLastMessageRefs::DeletedMessageView(m))) => (
+                Some("Message deleted".to_string()),
+                parse_datetime(m.sent_at.as_str()),
+            ),
+            _ => (None, None),
+        };
+
+        ConvoViewModel {
+            id: convo.id.clone(),
+            members,
+            last_message,
+            last_message_at,
+            unread_count: convo.unread_count,
+            muted: convo.muted,
+        }
+    }
+
+    /// Member names for headers and list rows.
+    pub fn title(&self) -> String {
+        if self.members.is_empty() {
+            return "Just you".to_string();
+        }
+        self.members
+            .iter()
+            .map(|m| m.display_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl MessageViewModel {
+    pub fn from_message_view(message: &atrium_api::chat::bsky::convo::defs::MessageView) -> Self {
+        MessageViewModel {
+            id: message.id.clone(),
+            sender_did: message.sender.did.to_string(),
+            text: message.text.clone(),
+            sent_at: parse_datetime(message.sent_at.as_str()).unwrap_or_else(Utc::now),
+            deleted: false,
+        }
+    }
+
+    pub fn from_deleted(message: &atrium_api::chat::bsky::convo::defs::DeletedMessageView) -> Self {
+        MessageViewModel {
+            id: message.id.clone(),
+            sender_did: message.sender.did.to_string(),
+            text: String::new(),
+            sent_at: parse_datetime(message.sent_at.as_str()).unwrap_or_else(Utc::now),
+            deleted: true,
+        }
+    }
+}
+
+fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// The Messages tab: conversations, most recently active first.
+#[derive(Debug, Clone)]
+pub struct ConvoListState {
+    pub convos: PagedList<ConvoViewModel>,
+    /// Unread messages across all conversations, for the tab badge.
+    pub unread_count: i64,
+    /// The session lacks chat access (e.g. an OAuth grant without `transition:chat.bsky`).
+    pub unavailable: bool,
+}
+
+impl ConvoListState {
+    pub fn new() -> Self {
+        ConvoListState {
+            convos: PagedList::new(),
+            unread_count: 0,
+            unavailable: false,
+        }
+    }
+
+    pub fn convo_mut(&mut self, id: &str) -> Option<&mut ConvoViewModel> {
+        self.convos.items.iter_mut().find(|c| c.id == id)
+    }
+
+    pub fn append_convos(&mut self, convos: Vec<ConvoViewModel>, cursor: Option<String>) {
+        self.convos.append(convos, cursor);
+        self.recount_unread();
+    }
+
+    /// Polls refresh the first page, so the selection stays where it was.
+    pub fn replace_convos(&mut self, convos: Vec<ConvoViewModel>, cursor: Option<String>) {
+        let selected = self.convos.selected;
+        self.convos.replace(convos, cursor);
+        self.convos.selected = selected;
+        self.convos.clamp_selection();
+        self.recount_unread();
+    }
+
+    pub fn remove_convo(&mut self, id: &str) {
+        self.convos.retain(|c| c.id != id);
+        self.recount_unread();
+    }
+
+    /// Muted conversations don't count towards the badge.
+    pub fn recount_unread(&mut self) {
+        self.unread_count = self
+            .convos
+            .items
+            .iter()
+            .filter(|c| !c.muted)
+            .map(|c| c.unread_count)
+            .sum();
+    }
+}
+
+impl Default for ConvoListState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An open conversation. Messages are oldest first, the selection starts on the
+/// newest and `cursor` pages further back.
+#[derive(Debug, Clone)]
+pub struct ConversationState {
+    pub convo: ConvoViewModel,
+    pub messages: PagedList<MessageViewModel>,
+}
+
+impl ConversationState {
+    pub fn new(convo: ConvoViewModel) -> Self {
+        let mut messages = PagedList::new();
+        messages.loading = true;
+        ConversationState { convo, messages }
+    }
+
+    /// `page` comes newest first, as `getMessages` returns it.
+    pub fn prepend_older(&mut self, page: Vec<MessageViewModel>, cursor: Option<String>) {
+        let added = page.len();
+        let mut messages: Vec<MessageViewModel> = page.into_iter().rev().collect();
+        messages.append(&mut self.messages.items);
+        self.messages.items = messages;
+        self.messages.selected += added;
+        self.messages.cursor = cursor;
+        self.messages.loading = false;
+    }
+
+    /// Merge the newest page, keeping what's already loaded. Returns true when
+    /// anything new arrived.
+    pub fn merge_latest(&mut self, page: Vec<MessageViewModel>, cursor: Option<String>) -> bool {
+        let messages = &mut self.messages;
+        let first_load = messages.is_empty();
+        let at_newest = messages.selected + 1 >= messages.len();
+        let mut fresh: Vec<MessageViewModel> = page
+            .into_iter()
+            .filter(|m| !messages.items.iter().any(|old| old.id == m.id))
+            .collect();
+        fresh.reverse();
+        let arrived = !fresh.is_empty();
+        messages.items.extend(fresh);
+        messages.items.sort_by_key(|m| m.sent_at);
+        if first_load {
+            messages.cursor = cursor;
+        }
+        if at_newest {
+            messages.select_last();
+        }
+        messages.loading = false;
+        arrived
+    }
+
+    pub fn near_top(&self) -> bool {
+        self.messages.selected < 3 && self.messages.cursor.is_some()
+    }
+
+    pub fn newest_id(&self) -> Option<&str> {
+        self.messages.items.last().map(|m| m.id.as_str())
+    }
+}
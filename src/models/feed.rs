@@ -61,6 +61,12 @@ impl FeedState {
         self.loading = false;
     }
 
+    /// Drop every entry for `uri`, including reposts of it.
+    pub fn remove_post(&mut self, uri: &str) {
+        self.posts.retain(|p| p.uri != uri);
+        self.selected_index = self.selected_index.min(self.posts.len().saturating_sub(1));
+    }
+
     pub fn near_bottom(&self, _visible_height: usize) -> bool {
         if self.posts.is_empty() {
             return false;
@@ -74,3 +80,36 @@ impl Default for FeedState {
         Self::new()
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedSource {
+    /// The home "Following" timeline.
+    Following,
+    /// A feed generator, by AT-URI.
+    Generator(String),
+    /// A list feed, by AT-URI.
+    List(String),
+}
+
+/// A feed saved in the user's preferences, with its own posts and cursor.
+#[derive(Debug, Clone)]
+pub struct SavedFeed {
+    /// The saved-feed item id from `savedFeedsPrefV2`.
+    pub id: String,
+    pub source: FeedSource,
+    pub name: String,
+    pub pinned: bool,
+    pub state: FeedState,
+}
+
+impl SavedFeed {
+    pub fn following() -> Self {
+        SavedFeed {
+            id: "following".to_string(),
+            source: FeedSource::Following,
+            name: "Following".to_string(),
+            pinned: true,
+            state: FeedState::new(),
+        }
+    }
+}
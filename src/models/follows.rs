@@ -0,0 +1,38 @@
+use super::paged::PagedList;
+use super::profile::ProfileViewModel;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FollowListKind {
+    Followers,
+    Follows,
+}
+
+impl FollowListKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            FollowListKind::Followers => "Followers",
+            FollowListKind::Follows => "Following",
+        }
+    }
+}
+
+/// One account's followers or follows, paged in as the selection nears the end.
+#[derive(Debug, Clone)]
+pub struct FollowListState {
+    pub kind: FollowListKind,
+    /// DID of the account whose graph this is.
+    pub actor: String,
+    pub actor_handle: String,
+    pub actors: PagedList<ProfileViewModel>,
+}
+
+impl FollowListState {
+    pub fn new(kind: FollowListKind, actor: String, actor_handle: String) -> Self {
+        FollowListState {
+            kind,
+            actor,
+            actor_handle,
+            actors: PagedList::new(),
+        }
+    }
+}
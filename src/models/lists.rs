@@ -0,0 +1,159 @@
+use atrium_api::app::bsky::graph::defs::{CURATELIST, MODLIST, REFERENCELIST};
+
+use super::feed::FeedState;
+use super::paged::PagedList;
+use super::profile::ProfileViewModel;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListPurpose {
+    /// A list of people, with a feed of their posts.
+    Curate,
+    /// A list to mute or block in bulk.
+    Moderation,
+    Reference,
+}
+
+impl ListPurpose {
+    fn parse(purpose: &str) -> Self {
+        match purpose {
+            MODLIST => ListPurpose::Moderation,
+            REFERENCELIST => ListPurpose::Reference,
+            _ => ListPurpose::Curate,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListPurpose::Curate => CURATELIST,
+            ListPurpose::Moderation => MODLIST,
+            ListPurpose::Reference => REFERENCELIST,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ListPurpose::Curate => "List",
+            ListPurpose::Moderation => "Moderation list",
+            ListPurpose::Reference => "Reference list",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListViewModel {
+    pub uri: String,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub description: String,
+    pub creator_did: String,
+    pub creator_handle: String,
+    pub member_count: usize,
+    /// Subscribed to as a mute list.
+    pub muted: bool,
+    /// Our `listblock` record when subscribed to as a block list.
+    pub blocking_uri: Option<String>,
+}
+
+impl ListViewModel {
+    pub fn from_list_view(list: &atrium_api::app::bsky::graph::defs::ListView) -> Self {
+        let viewer = list.viewer.as_ref();
+        ListViewModel {
+            uri: list.uri.clone(),
+            name: list.name.clone(),
+            purpose: ListPurpose::parse(&list.purpose),
+            description: list.description.clone().unwrap_or_default(),
+            creator_did: list.creator.did.to_string(),
+            creator_handle: list.creator.handle.to_string(),
+            member_count: list.list_item_count.unwrap_or(0),
+            muted: viewer.and_then(|v| v.muted).unwrap_or(false),
+            blocking_uri: viewer.and_then(|v| v.blocked.clone()),
+        }
+    }
+}
+
+/// Someone's lists, paged in as the selection nears the end.
+#[derive(Debug, Clone)]
+pub struct ListsState {
+    /// DID of the account whose lists these are.
+    pub actor: String,
+    pub actor_handle: String,
+    pub lists: PagedList<ListViewModel>,
+}
+
+impl ListsState {
+    pub fn new(actor: String, actor_handle: String) -> Self {
+        ListsState {
+            actor,
+            actor_handle,
+            lists: PagedList::new(),
+        }
+    }
+
+    pub fn list_mut(&mut self, uri: &str) -> Option<&mut ListViewModel> {
+        self.lists.items.iter_mut().find(|l| l.uri == uri)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListTab {
+    Posts,
+    Members,
+}
+
+/// An open list: the feed of its members' posts (curate lists only) and the members.
+#[derive(Debug, Clone)]
+pub struct ListDetailState {
+    pub list: ListViewModel,
+    pub tab: ListTab,
+    pub feed: FeedState,
+    pub members: PagedList<ProfileViewModel>,
+}
+
+impl ListDetailState {
+    pub fn new(list: ListViewModel) -> Self {
+        let tab = if list.purpose == ListPurpose::Curate {
+            ListTab::Posts
+        } else {
+            ListTab::Members
+        };
+        ListDetailState {
+            list,
+            tab,
+            feed: FeedState::new(),
+            members: PagedList::new(),
+        }
+    }
+
+    /// Only curate lists have a feed.
+    pub fn has_feed(&self) -> bool {
+        self.list.purpose == ListPurpose::Curate
+    }
+
+    pub fn select_next(&mut self) {
+        match self.tab {
+            ListTab::Posts => self.feed.select_next(),
+            ListTab::Members => self.members.select_next(),
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        match self.tab {
+            ListTab::Posts => self.feed.select_prev(),
+            ListTab::Members => self.members.select_prev(),
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        match self.tab {
+            ListTab::Posts => self.feed.select_first(),
+            ListTab::Members => self.members.select_first(),
+        }
+    }
+
+    pub fn select_last(&mut self) {
+        match self.tab {
+            ListTab::Posts => self.feed.select_last(),
+            ListTab::Members => self.members.select_last(),
+        }
+    }
+}
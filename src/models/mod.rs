@@ -1,4 +1,11 @@
+pub mod chat;
 pub mod feed;
+pub mod follows;
+pub mod lists;
+pub mod moderation;
+pub mod notification;
+pub mod paged;
 pub mod post;
 pub mod profile;
+pub mod search;
 pub mod thread;
@@ -0,0 +1,695 @@
+use chrono::{DateTime, Utc};
+
+use super::post::{PostLabel, PostViewModel};
+
+/// Bluesky's own moderation service; always consulted, like on the web.
+pub const BSKY_LABELER_DID: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+/// What to do with content carrying a label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LabelVisibility {
+    Show,
+    Warn,
+    Hide,
+}
+
+impl LabelVisibility {
+    /// `ignore`, `show`, `warn` or `hide`, as stored in `contentLabelPref`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ignore" | "show" => Some(LabelVisibility::Show),
+            "warn" => Some(LabelVisibility::Warn),
+            "hide" => Some(LabelVisibility::Hide),
+            _ => None,
+        }
+    }
+}
+
+/// What one label means for a post, with the label's display name.
+#[derive(Debug, Clone)]
+struct LabelDecision {
+    visibility: LabelVisibility,
+    name: String,
+    /// Hidden regardless of what the user asks, as on the official clients.
+    no_override: bool,
+}
+
+/// A label value, either one of the global ones or defined by a labeler.
+#[derive(Debug, Clone)]
+pub struct LabelDefinition {
+    /// `None` for global labels, which any labeler (or the author) can apply.
+    pub labeler_did: Option<String>,
+    pub identifier: String,
+    pub name: String,
+    pub default: LabelVisibility,
+    pub adult_only: bool,
+    /// Labels with `blurs: none` only inform and never warn or hide.
+    pub blurs: bool,
+}
+
+impl LabelDefinition {
+    fn global(identifier: &str, name: &str, default: LabelVisibility, adult_only: bool) -> Self {
+        LabelDefinition {
+            labeler_did: None,
+            identifier: identifier.to_string(),
+            name: name.to_string(),
+            default,
+            adult_only,
+            blurs: true,
+        }
+    }
+
+    pub fn from_labeler(
+        labeler_did: &str,
+        def: &atrium_api::com::atproto::label::defs::LabelValueDefinitionData,
+    ) -> Self {
+        LabelDefinition {
+            labeler_did: Some(labeler_did.to_string()),
+            identifier: def.identifier.clone(),
+            name: def
+                .locales
+                .iter()
+                .find(|l| l.lang.as_ref().as_str().starts_with("en"))
+                .or(def.locales.first())
+                .map_or_else(|| def.identifier.clone(), |l| l.name.clone()),
+            default: def
+                .default_setting
+                .as_deref()
+                .and_then(LabelVisibility::parse)
+                .unwrap_or(LabelVisibility::Warn),
+            adult_only: def.adult_only.unwrap_or(false),
+            blurs: def.blurs != "none",
+        }
+    }
+}
+
+/// The labels every client understands, and the ones authors can apply to their own posts.
+fn global_labels() -> Vec<LabelDefinition> {
+    vec![
+        LabelDefinition::global("porn", "Adult content", LabelVisibility::Hide, true),
+        LabelDefinition::global("sexual", "Sexually suggestive", LabelVisibility::Warn, true),
+        LabelDefinition::global("nudity", "Non-sexual nudity", LabelVisibility::Show, true),
+        LabelDefinition::global("graphic-media", "Graphic media", LabelVisibility::Warn, false),
+    ]
+}
+
+/// Self-labels offered in the composer, as `(value, name)`.
+pub const SELF_LABELS: [(&str, &str); 4] = [
+    ("sexual", "Suggestive"),
+    ("nudity", "Nudity"),
+    ("porn", "Adult"),
+    ("graphic-media", "Graphic media"),
+];
+
+/// Report reasons from `com.atproto.moderation.defs`, as `(reason type, name, description)`.
+pub const REPORT_REASONS: [(&str, &str, &str); 6] = {
+    use atrium_api::com::atproto::moderation::defs::*;
+    [
+        (REASON_SPAM, "Spam", "Excessive mentions or replies"),
+        (REASON_VIOLATION, "Illegal and urgent", "Glaring violations of law or terms of service"),
+        (REASON_MISLEADING, "Misleading", "Impersonation, misinformation or false claims"),
+        (REASON_SEXUAL, "Unwanted sexual content", "Nudity or adult content not labeled as such"),
+        (REASON_RUDE, "Anti-social behavior", "Harassment, trolling or intolerance"),
+        (REASON_OTHER, "Other", "An issue not included in these options"),
+    ]
+};
+
+/// What a report is about.
+#[derive(Debug, Clone)]
+pub enum ReportSubject {
+    Account { did: String, handle: String },
+    Post { uri: String, cid: String, author_handle: String },
+}
+
+impl ReportSubject {
+    pub fn describe(&self) -> String {
+        match self {
+            ReportSubject::Account { handle, .. } => format!("account @{}", handle),
+            ReportSubject::Post { author_handle, .. } => format!("post by @{}", author_handle),
+        }
+    }
+}
+
+/// A moderation service reports can be sent to: Bluesky's or a subscribed labeler.
+#[derive(Debug, Clone)]
+pub struct ModerationService {
+    pub did: String,
+    pub name: String,
+    /// Reason types the service reviews; `None` accepts all of them.
+    pub reason_types: Option<Vec<String>>,
+}
+
+impl ModerationService {
+    /// Used until the labeler details have loaded.
+    pub fn bluesky() -> Self {
+        ModerationService {
+            did: BSKY_LABELER_DID.to_string(),
+            name: "Bluesky Moderation Service".to_string(),
+            reason_types: None,
+        }
+    }
+
+    pub fn accepts(&self, reason_type: &str) -> bool {
+        self.reason_types
+            .as_ref()
+            .is_none_or(|types| types.iter().any(|t| t == reason_type))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub subject: ReportSubject,
+    pub reason_type: String,
+    pub comment: Option<String>,
+    pub service_did: String,
+}
+
+/// A muted word rule from `mutedWordsPref`.
+#[derive(Debug, Clone)]
+pub struct MutedWord {
+    pub id: Option<String>,
+    pub value: String,
+    /// Only match hashtags, not post text.
+    pub tags_only: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Don't apply to accounts we follow (`actorTarget: exclude-following`).
+    pub exclude_following: bool,
+}
+
+impl MutedWord {
+    pub fn from_data(data: &atrium_api::app::bsky::actor::defs::MutedWordData) -> Self {
+        MutedWord {
+            id: data.id.clone(),
+            value: data.value.clone(),
+            tags_only: !data.targets.iter().any(|t| t == "content"),
+            expires_at: data
+                .expires_at
+                .as_ref()
+                .and_then(|at| DateTime::parse_from_rfc3339(at.as_str()).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            exclude_following: data.actor_target.as_deref() == Some("exclude-following"),
+        }
+    }
+
+    pub fn to_data(&self) -> atrium_api::app::bsky::actor::defs::MutedWordData {
+        let targets = if self.tags_only {
+            vec!["tag".to_string()]
+        } else {
+            vec!["content".to_string(), "tag".to_string()]
+        };
+        atrium_api::app::bsky::actor::defs::MutedWordData {
+            actor_target: Some(
+                if self.exclude_following { "exclude-following" } else { "all" }.to_string(),
+            ),
+            expires_at: self
+                .expires_at
+                .map(|at| atrium_api::types::string::Datetime::new(at.fixed_offset())),
+            id: self.id.clone(),
+            targets,
+            value: self.value.clone(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Utc::now())
+    }
+
+    /// The same rule: by id when both have one, otherwise by value ignoring case,
+    /// for words saved without an id.
+    pub fn same_rule(&self, other: &MutedWord) -> bool {
+        match (&self.id, &other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            _ => self.value.to_lowercase() == other.value.to_lowercase(),
+        }
+    }
+
+    fn matches(&self, post: &PostViewModel) -> bool {
+        let value = self.value.trim_start_matches('#').to_lowercase();
+        if value.is_empty() {
+            return false;
+        }
+        if post.tags.iter().any(|t| t.to_lowercase() == value) {
+            return true;
+        }
+        if self.tags_only {
+            return false;
+        }
+        let quote = post.quote.as_ref().map(|q| q.text.as_str());
+        let embed = post.embed_summary.as_ref();
+        let title = embed.and_then(|e| e.title.as_deref());
+        let description = embed.and_then(|e| e.description.as_deref());
+        [Some(post.text.as_str()), quote, title, description]
+            .into_iter()
+            .flatten()
+            .any(|text| text_contains_word(&text.to_lowercase(), &value))
+    }
+}
+
+/// Single words match whole words only; phrases, punctuation and scripts written
+/// without spaces fall back to a substring match.
+fn text_contains_word(text: &str, word: &str) -> bool {
+    if word.chars().all(|c| c.is_ascii_alphanumeric()) {
+        text.split(|c: char| !c.is_alphanumeric()).any(|token| token == word)
+    } else {
+        text.contains(word)
+    }
+}
+
+/// Filtering rules from `app.bsky.actor.getPreferences`.
+#[derive(Debug, Clone, Default)]
+pub struct ModerationPrefs {
+    pub muted_words: Vec<MutedWord>,
+    /// Posts hidden with "Hide post" on any client.
+    pub hidden_posts: Vec<String>,
+    pub adult_content: bool,
+    /// `contentLabelPref` overrides as `(labeler did, label, visibility)`; the DID is
+    /// `None` for global labels.
+    pub label_prefs: Vec<(Option<String>, String, LabelVisibility)>,
+    /// Subscribed labelers, starting with Bluesky's.
+    pub labelers: Vec<String>,
+    /// Label values defined by the subscribed labelers, filled in by the client.
+    pub label_definitions: Vec<LabelDefinition>,
+    /// Where reports can go, Bluesky's service first; filled in by the client.
+    pub services: Vec<ModerationService>,
+}
+
+impl ModerationPrefs {
+    pub fn from_preferences(preferences: &atrium_api::app::bsky::actor::defs::Preferences) -> Self {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        let mut prefs = ModerationPrefs {
+            labelers: vec![BSKY_LABELER_DID.to_string()],
+            ..Default::default()
+        };
+        for pref in preferences {
+            match pref {
+                Union::Refs(PreferencesItem::AdultContentPref(p)) => {
+                    prefs.adult_content = p.enabled;
+                }
+                Union::Refs(PreferencesItem::ContentLabelPref(p)) => {
+                    if let Some(visibility) = LabelVisibility::parse(&p.visibility) {
+                        let labeler = p.labeler_did.as_ref().map(|d| d.to_string());
+                        prefs.label_prefs.push((labeler, p.label.clone(), visibility));
+                    }
+                }
+                Union::Refs(PreferencesItem::LabelersPref(p)) => {
+                    prefs.labelers.extend(
+                        p.labelers
+                            .iter()
+                            .map(|l| l.did.to_string())
+                            .filter(|did| did != BSKY_LABELER_DID),
+                    );
+                }
+                Union::Refs(PreferencesItem::MutedWordsPref(p)) => {
+                    prefs.muted_words = p.items.iter().map(|w| MutedWord::from_data(w)).collect();
+                }
+                Union::Refs(PreferencesItem::HiddenPostsPref(p)) => {
+                    prefs.hidden_posts = p.items.clone();
+                }
+                _ => {}
+            }
+        }
+        prefs
+    }
+
+    /// Set or clear `post.filtered` and `post.content_warning`. Our own posts are never
+    /// filtered; muted threads only apply outside the thread view, as on the web.
+    pub fn apply(&self, post: &mut PostViewModel, own_did: Option<&str>, in_thread: bool) {
+        post.filtered = None;
+        post.content_warning = None;
+        post.no_override = false;
+        if own_did == Some(post.author_did.as_str()) {
+            return;
+        }
+        let decision = post
+            .labels
+            .iter()
+            .filter_map(|label| self.label_decision(label, &post.author_did))
+            .max_by_key(|d| (d.visibility, d.no_override));
+        if let Some(decision) = decision {
+            match decision.visibility {
+                LabelVisibility::Hide => {
+                    post.filtered = Some(format!("labeled {}", decision.name));
+                    post.no_override = decision.no_override;
+                    post.revealed &= !decision.no_override;
+                    return;
+                }
+                LabelVisibility::Warn => post.content_warning = Some(decision.name),
+                LabelVisibility::Show => {}
+            }
+        }
+        if self.hidden_posts.contains(&post.uri) {
+            post.filtered = Some("hidden by you".to_string());
+            return;
+        }
+        if post.thread_muted && !in_thread {
+            post.filtered = Some("muted thread".to_string());
+            return;
+        }
+        let muted = self
+            .muted_words
+            .iter()
+            .filter(|w| !w.is_expired())
+            .filter(|w| !(w.exclude_following && post.author_following))
+            .find(|w| w.matches(post));
+        if let Some(word) = muted {
+            post.filtered = Some(format!("matched muted word '{}'", word.value));
+        }
+    }
+
+    /// How the user wants a label handled. `None` for labels we don't understand or
+    /// that only inform.
+    fn label_decision(&self, label: &PostLabel, author_did: &str) -> Option<LabelDecision> {
+        match label.val.as_str() {
+            "!hide" => {
+                return Some(LabelDecision {
+                    visibility: LabelVisibility::Hide,
+                    name: "by moderators".to_string(),
+                    no_override: true,
+                })
+            }
+            "!warn" => {
+                return Some(LabelDecision {
+                    visibility: LabelVisibility::Warn,
+                    name: "Content warning".to_string(),
+                    no_override: false,
+                })
+            }
+            _ => {}
+        }
+        let global = global_labels();
+        // Global labels may come from anyone; labeler-defined ones only from their
+        // labeler, and never as self-labels
+        let definition = global
+            .iter()
+            .find(|d| d.identifier == label.val)
+            .or_else(|| {
+                self.label_definitions.iter().find(|d| {
+                    d.identifier == label.val
+                        && d.labeler_did.as_deref() == Some(label.src.as_str())
+                        && label.src != author_did
+                })
+            })?;
+        if !definition.blurs {
+            return None;
+        }
+        if definition.adult_only && !self.adult_content {
+            return Some(LabelDecision {
+                visibility: LabelVisibility::Hide,
+                name: definition.name.clone(),
+                no_override: true,
+            });
+        }
+        let visibility = self
+            .label_prefs
+            .iter()
+            .find(|(labeler, name, _)| {
+                *name == definition.identifier && *labeler == definition.labeler_did
+            })
+            .map_or(definition.default, |(_, _, visibility)| *visibility);
+        Some(LabelDecision {
+            visibility,
+            name: definition.name.clone(),
+            no_override: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::models::post::{EmbedKind, EmbedSummary};
+
+    const AUTHOR: &str = "did:plc:author";
+    const LABELER: &str = "did:plc:labeler";
+
+    fn post(text: &str) -> PostViewModel {
+        PostViewModel::sample("at://did:plc:author/app.bsky.feed.post/1", AUTHOR, text)
+    }
+
+    fn labeled(labels: &[(&str, &str)]) -> PostViewModel {
+        let mut post = post("hello");
+        post.labels = labels
+            .iter()
+            .map(|(src, val)| PostLabel {
+                src: src.to_string(),
+                val: val.to_string(),
+            })
+            .collect();
+        post
+    }
+
+    fn word(value: &str) -> MutedWord {
+        MutedWord {
+            id: None,
+            value: value.to_string(),
+            tags_only: false,
+            expires_at: None,
+            exclude_following: false,
+        }
+    }
+
+    fn muting(words: Vec<MutedWord>) -> ModerationPrefs {
+        ModerationPrefs {
+            muted_words: words,
+            ..Default::default()
+        }
+    }
+
+    fn labeler_definition(identifier: &str, default: LabelVisibility) -> LabelDefinition {
+        LabelDefinition {
+            labeler_did: Some(LABELER.to_string()),
+            identifier: identifier.to_string(),
+            name: identifier.to_string(),
+            default,
+            adult_only: false,
+            blurs: true,
+        }
+    }
+
+    fn moderate(prefs: &ModerationPrefs, mut post: PostViewModel) -> PostViewModel {
+        prefs.apply(&mut post, Some("did:plc:me"), false);
+        post
+    }
+
+    #[test]
+    fn single_words_match_whole_words_only() {
+        assert!(text_contains_word("my cat sat", "cat"));
+        assert!(text_contains_word("cat!", "cat"));
+        assert!(!text_contains_word("concatenate", "cat"));
+        assert!(!text_contains_word("cats", "cat"));
+    }
+
+    #[test]
+    fn phrases_and_unspaced_scripts_match_substrings() {
+        assert!(text_contains_word("what a bad day it was", "bad day"));
+        assert!(text_contains_word("今日の新聞です", "新聞"));
+    }
+
+    #[test]
+    fn muted_words_ignore_case_and_hash() {
+        assert!(word("Cat").matches(&post("LOOK AT MY CAT")));
+
+        let mut tagged = post("nothing here");
+        tagged.tags = vec!["Rust".to_string()];
+        assert!(word("#rust").matches(&tagged));
+    }
+
+    #[test]
+    fn muted_words_check_quotes_and_link_cards() {
+        let mut quoting = post("look");
+        quoting.quote = Some(crate::models::post::QuotedPost {
+            uri: "at://did:plc:other/app.bsky.feed.post/2".to_string(),
+            author_handle: "other.test".to_string(),
+            author_display_name: "Other".to_string(),
+            text: "spoilers ahead".to_string(),
+            created_at: Utc::now(),
+        });
+        assert!(word("spoilers").matches(&quoting));
+
+        let mut linking = post("read this");
+        linking.embed_summary = Some(EmbedSummary {
+            kind: EmbedKind::ExternalLink,
+            title: Some("Finale spoilers".to_string()),
+            description: None,
+            url: None,
+        });
+        assert!(word("spoilers").matches(&linking));
+    }
+
+    #[test]
+    fn rules_are_the_same_by_id_then_value() {
+        let with_id = |id: &str, value: &str| MutedWord {
+            id: Some(id.to_string()),
+            ..word(value)
+        };
+        assert!(with_id("1", "cat").same_rule(&with_id("1", "dog")));
+        assert!(!with_id("1", "cat").same_rule(&with_id("2", "cat")));
+        assert!(with_id("1", "Cat").same_rule(&word("cAT")));
+        assert!(word("É").same_rule(&word("é")));
+        assert!(!word("cat").same_rule(&word("cats")));
+    }
+
+    #[test]
+    fn tags_only_words_skip_text() {
+        let rule = MutedWord {
+            tags_only: true,
+            ..word("rust")
+        };
+        assert!(!rule.matches(&post("I like rust")));
+
+        let mut tagged = post("I like it");
+        tagged.tags = vec!["rust".to_string()];
+        assert!(rule.matches(&tagged));
+    }
+
+    #[test]
+    fn expired_words_no_longer_filter() {
+        let expired = MutedWord {
+            expires_at: Some(Utc::now() - Duration::hours(1)),
+            ..word("cat")
+        };
+        assert!(moderate(&muting(vec![expired]), post("cat")).filtered.is_none());
+
+        let active = MutedWord {
+            expires_at: Some(Utc::now() + Duration::hours(1)),
+            ..word("cat")
+        };
+        assert!(moderate(&muting(vec![active]), post("cat")).filtered.is_some());
+    }
+
+    #[test]
+    fn exclude_following_spares_followed_authors() {
+        let prefs = muting(vec![MutedWord {
+            exclude_following: true,
+            ..word("cat")
+        }]);
+        let mut followed = post("cat");
+        followed.author_following = true;
+        assert!(moderate(&prefs, followed).filtered.is_none());
+        assert!(moderate(&prefs, post("cat")).filtered.is_some());
+    }
+
+    #[test]
+    fn own_posts_are_never_filtered() {
+        let prefs = muting(vec![word("cat")]);
+        let mut own = post("cat");
+        prefs.apply(&mut own, Some(AUTHOR), false);
+        assert!(own.filtered.is_none());
+    }
+
+    #[test]
+    fn hide_outranks_warn() {
+        let prefs = ModerationPrefs {
+            adult_content: true,
+            ..Default::default()
+        };
+        let post = moderate(&prefs, labeled(&[(LABELER, "graphic-media"), (LABELER, "porn")]));
+        assert_eq!(post.filtered.as_deref(), Some("labeled Adult content"));
+        assert!(post.content_warning.is_none());
+        assert!(post.can_reveal());
+    }
+
+    #[test]
+    fn adult_labels_cannot_be_revealed_with_adult_content_off() {
+        let mut post = labeled(&[(LABELER, "sexual")]);
+        post.revealed = true;
+        let post = moderate(&ModerationPrefs::default(), post);
+        assert!(post.filtered.is_some());
+        assert!(post.no_override);
+        assert!(!post.revealed);
+        assert!(!post.can_reveal());
+    }
+
+    #[test]
+    fn moderator_labels() {
+        let prefs = ModerationPrefs::default();
+        let hidden = moderate(&prefs, labeled(&[(LABELER, "!warn"), (LABELER, "!hide")]));
+        assert!(hidden.filtered.is_some() && hidden.no_override);
+
+        let warned = moderate(&prefs, labeled(&[(LABELER, "!warn")]));
+        assert_eq!(warned.content_warning.as_deref(), Some("Content warning"));
+    }
+
+    #[test]
+    fn global_label_prefs_only_apply_to_global_labels() {
+        let global = ModerationPrefs {
+            label_prefs: vec![(None, "graphic-media".to_string(), LabelVisibility::Hide)],
+            ..Default::default()
+        };
+        assert!(moderate(&global, labeled(&[(LABELER, "graphic-media")])).filtered.is_some());
+
+        let scoped = ModerationPrefs {
+            label_prefs: vec![(
+                Some(LABELER.to_string()),
+                "graphic-media".to_string(),
+                LabelVisibility::Hide,
+            )],
+            ..Default::default()
+        };
+        let post = moderate(&scoped, labeled(&[(LABELER, "graphic-media")]));
+        assert!(post.filtered.is_none());
+        assert_eq!(post.content_warning.as_deref(), Some("Graphic media"));
+    }
+
+    #[test]
+    fn labeler_labels_only_count_from_their_labeler() {
+        let prefs = ModerationPrefs {
+            label_definitions: vec![labeler_definition("spider", LabelVisibility::Hide)],
+            label_prefs: vec![(
+                Some(LABELER.to_string()),
+                "spider".to_string(),
+                LabelVisibility::Warn,
+            )],
+            ..Default::default()
+        };
+        let post = moderate(&prefs, labeled(&[(LABELER, "spider")]));
+        assert_eq!(post.content_warning.as_deref(), Some("spider"));
+
+        let elsewhere = moderate(&prefs, labeled(&[("did:plc:someone", "spider")]));
+        assert!(elsewhere.filtered.is_none() && elsewhere.content_warning.is_none());
+    }
+
+    #[test]
+    fn informational_labels_are_ignored() {
+        let prefs = ModerationPrefs {
+            label_definitions: vec![LabelDefinition {
+                blurs: false,
+                ..labeler_definition("verified", LabelVisibility::Warn)
+            }],
+            ..Default::default()
+        };
+        let post = moderate(&prefs, labeled(&[(LABELER, "verified")]));
+        assert!(post.filtered.is_none() && post.content_warning.is_none());
+    }
+
+    #[test]
+    fn self_labels() {
+        let prefs = ModerationPrefs {
+            label_definitions: vec![LabelDefinition {
+                labeler_did: Some(AUTHOR.to_string()),
+                ..labeler_definition("spider", LabelVisibility::Hide)
+            }],
+            ..Default::default()
+        };
+        // Global labels can be self-applied
+        let post = moderate(&prefs, labeled(&[(AUTHOR, "graphic-media")]));
+        assert_eq!(post.content_warning.as_deref(), Some("Graphic media"));
+        // Labeler-defined ones can't, even when the author runs the labeler
+        let post = moderate(&prefs, labeled(&[(AUTHOR, "spider")]));
+        assert!(post.filtered.is_none());
+    }
+
+    #[test]
+    fn muted_threads_show_inside_the_thread() {
+        let prefs = ModerationPrefs::default();
+        let mut muted = post("hello");
+        muted.thread_muted = true;
+        assert!(moderate(&prefs, muted.clone()).filtered.is_some());
+        prefs.apply(&mut muted, None, true);
+        assert!(muted.filtered.is_none());
+    }
+}
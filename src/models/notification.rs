@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::paged::PagedList;
+use super::post::PostViewModel;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationReason {
+    Like,
+    Repost,
+    Follow,
+    Mention,
+    Reply,
+    Quote,
+}
+
+impl NotificationReason {
+    fn parse(reason: &str) -> Option<Self> {
+        match reason {
+            "like" => Some(NotificationReason::Like),
+            "repost" => Some(NotificationReason::Repost),
+            "follow" => Some(NotificationReason::Follow),
+            "mention" => Some(NotificationReason::Mention),
+            "reply" => Some(NotificationReason::Reply),
+            "quote" => Some(NotificationReason::Quote),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationViewModel {
+    pub uri: String,
+    pub reason: NotificationReason,
+    /// The post that was liked/reposted (likes and reposts only).
+    pub reason_subject: Option<String>,
+    pub author_did: String,
+    pub author_handle: String,
+    pub author_display_name: String,
+    /// Text of the notifying record (mentions, replies and quotes).
+    pub text: Option<String>,
+    pub indexed_at: DateTime<Utc>,
+    pub is_read: bool,
+}
+
+impl NotificationViewModel {
+    pub fn from_notification(
+        n: &atrium_api::app::bsky::notification::list_notifications::Notification,
+    ) -> Option<Self> {
+        let reason = NotificationReason::parse(&n.reason)?;
+        let text = serde_json::to_value(&n.record)
+            .ok()
+            .and_then(|r| r.get("text").and_then(|t| t.as_str()).map(|t| t.to_string()))
+            .filter(|_| {
+                matches!(
+                    reason,
+                    NotificationReason::Mention
+                        | NotificationReason::Reply
+                        | NotificationReason::Quote
+                )
+            });
+        let indexed_at = DateTime::parse_from_rfc3339(n.indexed_at.as_str())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Some(NotificationViewModel {
+            uri: n.uri.clone(),
+            reason,
+            reason_subject: n.reason_subject.clone(),
+            author_did: n.author.did.to_string(),
+            author_handle: n.author.handle.to_string(),
+            author_display_name: n
+                .author
+                .display_name
+                .clone()
+                .filter(|d| !d.is_empty())
+                .unwrap_or_else(|| n.author.handle.to_string()),
+            text,
+            indexed_at,
+            is_read: n.is_read,
+        })
+    }
+}
+
+/// One row in the notifications list. Likes and reposts of the same post, and
+/// follows, collapse into a single group; mentions, replies and quotes stand alone.
+#[derive(Debug, Clone)]
+pub struct NotificationGroup {
+    pub reason: NotificationReason,
+    /// Liked/reposted post, or the mentioning/replying/quoting post itself.
+    pub subject_uri: Option<String>,
+    pub authors: Vec<(String, String)>,
+    pub text: Option<String>,
+    pub latest_at: DateTime<Utc>,
+    pub is_read: bool,
+}
+
+impl NotificationGroup {
+    /// DID of the most recent actor in the group.
+    pub fn first_author_did(&self) -> Option<&str> {
+        self.authors.first().map(|(did, _)| did.as_str())
+    }
+
+    pub fn summary(&self) -> String {
+        let names: Vec<&str> = self.authors.iter().map(|(_, name)| name.as_str()).collect();
+        let who = match names.len() {
+            0 => String::new(),
+            1 => names[0].to_string(),
+            2 => format!("{} and {}", names[0], names[1]),
+            n => format!("{}, {} and {} others", names[0], names[1], n - 2),
+        };
+        let what = match self.reason {
+            NotificationReason::Like => "liked your post",
+            NotificationReason::Repost => "reposted your post",
+            NotificationReason::Follow => "followed you",
+            NotificationReason::Mention => "mentioned you",
+            NotificationReason::Reply => "replied to you",
+            NotificationReason::Quote => "quoted your post",
+        };
+        format!("{} {}", who, what)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationState {
+    pub notifications: Vec<NotificationViewModel>,
+    /// Rows shown, rebuilt from `notifications` as pages arrive.
+    pub groups: PagedList<NotificationGroup>,
+    /// Liked/reposted posts, keyed by URI, so groups can show what was interacted with.
+    pub subjects: HashMap<String, PostViewModel>,
+    pub unread_count: i64,
+}
+
+impl NotificationState {
+    pub fn new() -> Self {
+        NotificationState {
+            notifications: Vec::new(),
+            groups: PagedList::new(),
+            subjects: HashMap::new(),
+            unread_count: 0,
+        }
+    }
+
+    pub fn append(
+        &mut self,
+        notifications: Vec<NotificationViewModel>,
+        subjects: Vec<PostViewModel>,
+        cursor: Option<String>,
+    ) {
+        self.notifications.extend(notifications);
+        self.subjects
+            .extend(subjects.into_iter().map(|p| (p.uri.clone(), p)));
+        self.groups.cursor = cursor;
+        self.groups.loading = false;
+        self.regroup();
+    }
+
+    pub fn replace(
+        &mut self,
+        notifications: Vec<NotificationViewModel>,
+        subjects: Vec<PostViewModel>,
+        cursor: Option<String>,
+    ) {
+        self.notifications.clear();
+        self.subjects.clear();
+        self.groups.selected = 0;
+        self.append(notifications, subjects, cursor);
+    }
+
+    /// When the newest loaded notification was indexed.
+    pub fn newest_at(&self) -> Option<DateTime<Utc>> {
+        self.notifications.iter().map(|n| n.indexed_at).max()
+    }
+
+    fn regroup(&mut self) {
+        let mut groups: Vec<NotificationGroup> = Vec::new();
+        let mut index: HashMap<(String, Option<String>), usize> = HashMap::new();
+
+        for n in &self.notifications {
+            let (key, subject_uri) = match n.reason {
+                NotificationReason::Like | NotificationReason::Repost => (
+                    Some((format!("{:?}", n.reason), n.reason_subject.clone())),
+                    n.reason_subject.clone(),
+                ),
+                NotificationReason::Follow => (Some(("Follow".to_string(), None)), None),
+                _ => (None, Some(n.uri.clone())),
+            };
+
+            if let Some(&i) = key.as_ref().and_then(|k| index.get(k)) {
+                let group = &mut groups[i];
+                if !group.authors.iter().any(|(did, _)| did == &n.author_did) {
+                    group
+                        .authors
+                        .push((n.author_did.clone(), n.author_display_name.clone()));
+                }
+                group.is_read &= n.is_read;
+                continue;
+            }
+
+            let text = match n.reason {
+                NotificationReason::Like | NotificationReason::Repost => subject_uri
+                    .as_ref()
+                    .and_then(|uri| self.subjects.get(uri))
+                    .map(|p| p.text.clone()),
+                _ => n.text.clone(),
+            };
+
+            if let Some(key) = key {
+                index.insert(key, groups.len());
+            }
+            groups.push(NotificationGroup {
+                reason: n.reason.clone(),
+                subject_uri,
+                authors: vec![(n.author_did.clone(), n.author_display_name.clone())],
+                text,
+                latest_at: n.indexed_at,
+                is_read: n.is_read,
+            });
+        }
+
+        self.groups.items = groups;
+        self.groups.clamp_selection();
+    }
+}
+
+impl Default for NotificationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POST: &str = "at://did:plc:me/app.bsky.feed.post/1";
+    const OTHER_POST: &str = "at://did:plc:me/app.bsky.feed.post/2";
+
+    fn notification(
+        reason: NotificationReason,
+        author: &str,
+        subject: Option<&str>,
+    ) -> NotificationViewModel {
+        NotificationViewModel {
+            uri: format!("at://{}/record/{}", author, uuid::Uuid::new_v4()),
+            reason,
+            reason_subject: subject.map(str::to_string),
+            author_did: author.to_string(),
+            author_handle: format!("{}.test", author),
+            author_display_name: author.to_string(),
+            text: None,
+            indexed_at: Utc::now(),
+            is_read: true,
+        }
+    }
+
+    fn like(author: &str, subject: &str) -> NotificationViewModel {
+        notification(NotificationReason::Like, author, Some(subject))
+    }
+
+    fn grouped(notifications: Vec<NotificationViewModel>) -> NotificationState {
+        let mut state = NotificationState::new();
+        state.replace(notifications, Vec::new(), None);
+        state
+    }
+
+    #[test]
+    fn likes_of_one_post_collapse() {
+        let state = grouped(vec![
+            like("alice", POST),
+            like("bob", POST),
+            like("alice", POST),
+        ]);
+        assert_eq!(state.groups.len(), 1);
+        let group = &state.groups.items[0];
+        assert_eq!(group.subject_uri.as_deref(), Some(POST));
+        assert_eq!(group.summary(), "alice and bob liked your post");
+    }
+
+    #[test]
+    fn groups_split_by_post_and_reason() {
+        let state = grouped(vec![
+            like("alice", POST),
+            like("bob", OTHER_POST),
+            notification(NotificationReason::Repost, "carol", Some(POST)),
+        ]);
+        assert_eq!(state.groups.len(), 3);
+    }
+
+    #[test]
+    fn follows_collapse_and_replies_stand_alone() {
+        let state = grouped(vec![
+            notification(NotificationReason::Follow, "alice", None),
+            notification(NotificationReason::Reply, "bob", None),
+            notification(NotificationReason::Follow, "carol", None),
+            notification(NotificationReason::Reply, "bob", None),
+        ]);
+        let reasons: Vec<_> = state.groups.items.iter().map(|g| g.reason.clone()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                NotificationReason::Follow,
+                NotificationReason::Reply,
+                NotificationReason::Reply
+            ]
+        );
+        assert_eq!(state.groups.items[0].authors.len(), 2);
+    }
+
+    #[test]
+    fn groups_are_unread_if_any_notification_is() {
+        let mut unread = like("bob", POST);
+        unread.is_read = false;
+        let state = grouped(vec![like("alice", POST), unread]);
+        assert!(!state.groups.items[0].is_read);
+    }
+
+    #[test]
+    fn later_pages_join_existing_groups() {
+        let mut state = grouped(vec![like("alice", POST)]);
+        state.append(vec![like("bob", POST)], Vec::new(), None);
+        assert_eq!(state.groups.len(), 1);
+        assert_eq!(state.groups.items[0].authors.len(), 2);
+    }
+
+    #[test]
+    fn liked_post_text_comes_from_subjects() {
+        let mut state = NotificationState::new();
+        let subject = PostViewModel::sample(POST, "did:plc:me", "my post");
+        state.replace(vec![like("alice", POST)], vec![subject], None);
+        assert_eq!(state.groups.items[0].text.as_deref(), Some("my post"));
+    }
+
+    #[test]
+    fn selection_stays_in_range_after_regrouping() {
+        let mut state = grouped(vec![like("alice", POST), like("bob", OTHER_POST)]);
+        state.groups.select_last();
+        state.notifications.truncate(1);
+        state.regroup();
+        assert_eq!(state.groups.selected, 0);
+    }
+
+    #[test]
+    fn newest_at_is_the_latest_indexed_notification() {
+        let mut older = like("alice", POST);
+        older.indexed_at -= chrono::Duration::minutes(5);
+        let newer = like("bob", OTHER_POST);
+        let newest = newer.indexed_at;
+        assert_eq!(grouped(vec![newer, older]).newest_at(), Some(newest));
+        assert_eq!(NotificationState::new().newest_at(), None);
+    }
+}
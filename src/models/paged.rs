@@ -0,0 +1,86 @@
+/// A list fetched a page at a time, with the cursor for the next page and the
+/// selected row.
+#[derive(Debug, Clone)]
+pub struct PagedList<T> {
+    pub items: Vec<T>,
+    pub cursor: Option<String>,
+    pub selected: usize,
+    pub loading: bool,
+}
+
+impl<T> PagedList<T> {
+    pub fn new() -> Self {
+        PagedList {
+            items: Vec::new(),
+            cursor: None,
+            selected: 0,
+            loading: false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.items.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.items.len().saturating_sub(1);
+    }
+
+    pub fn selected_item(&self) -> Option<&T> {
+        self.items.get(self.selected)
+    }
+
+    /// Add the next page.
+    pub fn append(&mut self, items: Vec<T>, cursor: Option<String>) {
+        self.items.extend(items);
+        self.cursor = cursor;
+        self.loading = false;
+    }
+
+    /// Start over from a first page.
+    pub fn replace(&mut self, items: Vec<T>, cursor: Option<String>) {
+        self.items = items;
+        self.cursor = cursor;
+        self.selected = 0;
+        self.loading = false;
+    }
+
+    /// Keep the selection in range after items were removed.
+    pub fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+    }
+
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.items.retain(f);
+        self.clamp_selection();
+    }
+
+    /// Within a few rows of the end, with another page to fetch.
+    pub fn near_bottom(&self) -> bool {
+        !self.items.is_empty() && self.selected + 3 >= self.items.len() && self.cursor.is_some()
+    }
+}
+
+impl<T> Default for PagedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -20,8 +20,35 @@ pub struct PostViewModel {
     pub is_reposted: bool,
     pub repost_uri: Option<String>,
     pub embed_summary: Option<EmbedSummary>,
+    pub quote: Option<QuotedPost>,
     pub reply_parent_author: Option<String>,
+    /// `(uri, cid)` of the thread root when this post is a reply.
+    pub reply_root: Option<(String, String)>,
     pub reposted_by: Option<String>,
+    /// Pinned to the top of its author's profile.
+    pub pinned: bool,
+    /// Hashtags from facets and the record's `tags`, without the `#`.
+    pub tags: Vec<String>,
+    /// We follow the author.
+    pub author_following: bool,
+    pub thread_muted: bool,
+    /// Labels on the post and its author.
+    pub labels: Vec<PostLabel>,
+    /// Why moderation preferences hide this post, e.g. a matched muted word.
+    pub filtered: Option<String>,
+    /// Name of the label the post is shown behind a warning for.
+    pub content_warning: Option<String>,
+    /// The filter can't be overridden: `!hide`, or adult content while it's disabled.
+    pub no_override: bool,
+    /// A filtered or warned post the user chose to show anyway.
+    pub revealed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PostLabel {
+    /// DID of the labeler, or of the author for self-labels.
+    pub src: String,
+    pub val: String,
 }
 
 #[derive(Debug, Clone)]
@@ -51,8 +78,18 @@ pub enum EmbedKind {
     ExternalLink,
     Images(usize),
     Video,
+    /// A record that can't be shown inline: a feed, list, or an unavailable post.
     Record,
-    RecordWithMedia,
+}
+
+/// A post embedded via `app.bsky.embed.record`, rendered as a nested mini-post.
+#[derive(Debug, Clone)]
+pub struct QuotedPost {
+    pub uri: String,
+    pub author_handle: String,
+    pub author_display_name: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
 }
 
 impl PostViewModel {
@@ -63,7 +100,14 @@ impl PostViewModel {
             extract_author_from_reply_parent(&r.parent)
         });
         let reposted_by = fvp.reason.as_ref().and_then(extract_repost_reason);
-        Self::from_post_view_inner(&fvp.post, reply_parent_author, reposted_by)
+        let mut post = Self::from_post_view_inner(&fvp.post, reply_parent_author, reposted_by)?;
+        post.pinned = matches!(
+            fvp.reason,
+            Some(atrium_api::types::Union::Refs(
+                atrium_api::app::bsky::feed::defs::FeedViewPostReasonRefs::ReasonPin(_)
+            ))
+        );
+        Some(post)
     }
 
     pub fn from_post_view(
@@ -91,14 +135,55 @@ impl PostViewModel {
             .unwrap_or_else(|_| Utc::now());
 
         let facets = parse_facets_from_record(&record);
+        let mut tags: Vec<String> = facets
+            .iter()
+            .filter_map(|f| match f.kind {
+                FacetKind::Tag(ref tag) => Some(tag.clone()),
+                _ => None,
+            })
+            .collect();
+        if let Some(extra) = record.get("tags").and_then(|t| t.as_array()) {
+            tags.extend(extra.iter().filter_map(|t| t.as_str()).map(String::from));
+        }
+        let reply_root = record.get("reply").and_then(|r| r.get("root")).and_then(|root| {
+            Some((
+                root.get("uri")?.as_str()?.to_string(),
+                root.get("cid")?.as_str()?.to_string(),
+            ))
+        });
 
         let viewer = post.viewer.as_ref();
         let is_liked = viewer.and_then(|v| v.like.as_ref()).is_some();
         let like_uri = viewer.and_then(|v| v.like.clone());
         let is_reposted = viewer.and_then(|v| v.repost.as_ref()).is_some();
         let repost_uri = viewer.and_then(|v| v.repost.clone());
+        let thread_muted = viewer.and_then(|v| v.thread_muted).unwrap_or(false);
+        let author_following = author
+            .viewer
+            .as_ref()
+            .is_some_and(|v| v.following.is_some());
+
+        // Labels on the author's profile record (e.g. a self-labeled avatar) only apply
+        // to the profile, not to everything they post
+        let account_labels = author
+            .labels
+            .iter()
+            .flatten()
+            .filter(|l| !l.uri.contains("/app.bsky.actor.profile/"));
+        let labels = post
+            .labels
+            .iter()
+            .flatten()
+            .chain(account_labels)
+            .filter(|l| !l.neg.unwrap_or(false))
+            .map(|l| PostLabel {
+                src: l.src.to_string(),
+                val: l.val.clone(),
+            })
+            .collect();
 
         let embed_summary = post.embed.as_ref().and_then(extract_embed_summary);
+        let quote = post.embed.as_ref().and_then(extract_quoted_post);
 
         Some(PostViewModel {
             uri: post.uri.clone(),
@@ -122,10 +207,76 @@ impl PostViewModel {
             is_reposted,
             repost_uri,
             embed_summary,
+            quote,
             reply_parent_author,
+            reply_root,
             reposted_by,
+            pinned: false,
+            tags,
+            author_following,
+            thread_muted,
+            labels,
+            filtered: None,
+            content_warning: None,
+            no_override: false,
+            revealed: false,
         })
     }
+
+    /// Drawn as a one-line placeholder until revealed.
+    pub fn is_collapsed(&self) -> bool {
+        self.filtered.is_some() && !self.revealed
+    }
+
+    /// Filtered or warned, and allowed to be shown anyway.
+    pub fn can_reveal(&self) -> bool {
+        (self.filtered.is_some() || self.content_warning.is_some()) && !self.no_override
+    }
+
+    /// Drawn behind a content warning bar until revealed.
+    pub fn is_blurred(&self) -> bool {
+        self.content_warning.is_some() && !self.revealed
+    }
+}
+
+#[cfg(test)]
+impl PostViewModel {
+    /// A bare post for unit tests.
+    pub fn sample(uri: &str, author_did: &str, text: &str) -> Self {
+        PostViewModel {
+            uri: uri.to_string(),
+            cid: "bafyreisample".to_string(),
+            author_did: author_did.to_string(),
+            author_handle: "author.test".to_string(),
+            author_display_name: "Author".to_string(),
+            author_avatar: None,
+            text: text.to_string(),
+            facets: Vec::new(),
+            created_at: Utc::now(),
+            like_count: 0,
+            repost_count: 0,
+            reply_count: 0,
+            quote_count: 0,
+            is_liked: false,
+            like_uri: None,
+            is_reposted: false,
+            repost_uri: None,
+            embed_summary: None,
+            quote: None,
+            reply_parent_author: None,
+            reply_root: None,
+            reposted_by: None,
+            pinned: false,
+            tags: Vec::new(),
+            author_following: false,
+            thread_muted: false,
+            labels: Vec::new(),
+            filtered: None,
+            content_warning: None,
+            no_override: false,
+            revealed: false,
+        }
+    }
 }
 
 fn parse_facets_from_record(record: &serde_json::Value) -> Vec<Facet> {
@@ -198,26 +349,107 @@ fn extract_embed_summary(
                 url: None,
             })
         }
-        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(_)) => {
-            Some(EmbedSummary {
-                kind: EmbedKind::Record,
-                title: None,
-                description: Some("Quoted post".to_string()),
-                url: None,
-            })
+        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(view)) => {
+            record_embed_summary(&view.record)
         }
-        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(_)) => {
-            Some(EmbedSummary {
-                kind: EmbedKind::RecordWithMedia,
-                title: None,
-                description: Some("Quote with media".to_string()),
-                url: None,
-            })
+        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view)) => {
+            use atrium_api::app::bsky::embed::record_with_media::ViewMediaRefs;
+            match &view.media {
+                Union::Refs(ViewMediaRefs::AppBskyEmbedImagesView(imgs)) => Some(EmbedSummary {
+                    kind: EmbedKind::Images(imgs.images.len()),
+                    title: None,
+                    description: None,
+                    url: None,
+                }),
+                Union::Refs(ViewMediaRefs::AppBskyEmbedVideoView(_)) => Some(EmbedSummary {
+                    kind: EmbedKind::Video,
+                    title: None,
+                    description: None,
+                    url: None,
+                }),
+                Union::Refs(ViewMediaRefs::AppBskyEmbedExternalView(ext)) => Some(EmbedSummary {
+                    kind: EmbedKind::ExternalLink,
+                    title: Some(ext.external.title.clone()),
+                    description: Some(ext.external.description.clone()),
+                    url: Some(ext.external.uri.clone()),
+                }),
+                _ => None,
+            }
         }
         _ => None,
     }
 }
 
+/// Summary line for embedded records that aren't shown as a nested post.
+fn record_embed_summary(
+    record: &atrium_api::types::Union<atrium_api::app::bsky::embed::record::ViewRecordRefs>,
+) -> Option<EmbedSummary> {
+    use atrium_api::app::bsky::embed::record::ViewRecordRefs;
+    use atrium_api::types::Union;
+
+    let description = match record {
+        Union::Refs(ViewRecordRefs::ViewRecord(_)) => return None,
+        Union::Refs(ViewRecordRefs::ViewNotFound(_)) => "Quoted post not found".to_string(),
+        Union::Refs(ViewRecordRefs::ViewBlocked(_)) => "Quoted post is blocked".to_string(),
+        Union::Refs(ViewRecordRefs::ViewDetached(_)) => {
+            "Quoted post was removed by its author".to_string()
+        }
+        Union::Refs(ViewRecordRefs::AppBskyFeedDefsGeneratorView(g)) => {
+            format!("Feed: {}", g.display_name)
+        }
+        Union::Refs(ViewRecordRefs::AppBskyGraphDefsListView(l)) => format!("List: {}", l.name),
+        _ => "Quoted record".to_string(),
+    };
+    Some(EmbedSummary {
+        kind: EmbedKind::Record,
+        title: None,
+        description: Some(description),
+        url: None,
+    })
+}
+
+fn extract_quoted_post(
+    embed: &atrium_api::types::Union<
+        atrium_api::app::bsky::feed::defs::PostViewEmbedRefs,
+    >,
+) -> Option<QuotedPost> {
+    use atrium_api::app::bsky::embed::record::ViewRecordRefs;
+    use atrium_api::app::bsky::feed::defs::PostViewEmbedRefs;
+    use atrium_api::types::Union;
+
+    let record = match embed {
+        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordView(view)) => &view.record,
+        Union::Refs(PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(view)) => {
+            &view.record.record
+        }
+        _ => return None,
+    };
+    let Union::Refs(ViewRecordRefs::ViewRecord(view)) = record else {
+        return None;
+    };
+
+    let value = serde_json::to_value(&view.value).ok()?;
+    let text = value.get("text").and_then(|t| t.as_str()).unwrap_or("").to_string();
+    let created_at = value
+        .get("createdAt")
+        .and_then(|c| c.as_str())
+        .and_then(|c| DateTime::parse_from_rfc3339(c).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+
+    Some(QuotedPost {
+        uri: view.uri.clone(),
+        author_handle: view.author.handle.to_string(),
+        author_display_name: view
+            .author
+            .display_name
+            .clone()
+            .unwrap_or_else(|| view.author.handle.to_string()),
+        text,
+        created_at,
+    })
+}
+
 fn extract_author_from_reply_parent(
     parent: &atrium_api::types::Union<
         atrium_api::app::bsky::feed::defs::ReplyRefParentRefs,
@@ -9,6 +9,83 @@ pub struct ProfileViewModel {
     pub followers_count: i64,
     pub follows_count: i64,
     pub posts_count: i64,
+    pub viewer: Relationship,
+}
+
+/// Sub-tabs of a profile's feed. Likes are only visible for our own account.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuthorFeedTab {
+    Posts,
+    Replies,
+    Media,
+    Likes,
+}
+
+impl AuthorFeedTab {
+    pub fn title(&self) -> &'static str {
+        match self {
+            AuthorFeedTab::Posts => "Posts",
+            AuthorFeedTab::Replies => "Replies",
+            AuthorFeedTab::Media => "Media",
+            AuthorFeedTab::Likes => "Likes",
+        }
+    }
+
+    /// `filter` for `app.bsky.feed.getAuthorFeed`; `None` for likes, which use
+    /// `getActorLikes` instead.
+    pub fn filter(&self) -> Option<&'static str> {
+        match self {
+            AuthorFeedTab::Posts => Some("posts_no_replies"),
+            AuthorFeedTab::Replies => Some("posts_with_replies"),
+            AuthorFeedTab::Media => Some("posts_with_media"),
+            AuthorFeedTab::Likes => None,
+        }
+    }
+
+    pub fn available(own: bool) -> &'static [AuthorFeedTab] {
+        const ALL: [AuthorFeedTab; 4] = [
+            AuthorFeedTab::Posts,
+            AuthorFeedTab::Replies,
+            AuthorFeedTab::Media,
+            AuthorFeedTab::Likes,
+        ];
+        if own {
+            &ALL
+        } else {
+            &ALL[..3]
+        }
+    }
+}
+
+/// How the signed-in account relates to this one.
+#[derive(Debug, Clone, Default)]
+pub struct Relationship {
+    pub following: bool,
+    /// Our `app.bsky.graph.follow` record; `None` while a follow is in flight.
+    pub following_uri: Option<String>,
+    pub followed_by: bool,
+    pub muted: bool,
+    pub blocking: bool,
+    /// Our `app.bsky.graph.block` record; `None` while a block is in flight.
+    pub blocking_uri: Option<String>,
+    pub blocked_by: bool,
+}
+
+impl Relationship {
+    fn from_viewer(viewer: Option<&atrium_api::app::bsky::actor::defs::ViewerState>) -> Self {
+        let Some(viewer) = viewer else {
+            return Relationship::default();
+        };
+        Relationship {
+            following: viewer.following.is_some(),
+            following_uri: viewer.following.clone(),
+            followed_by: viewer.followed_by.is_some(),
+            muted: viewer.muted.unwrap_or(false),
+            blocking: viewer.blocking.is_some(),
+            blocking_uri: viewer.blocking.clone(),
+            blocked_by: viewer.blocked_by.unwrap_or(false),
+        }
+    }
 }
 
 impl ProfileViewModel {
@@ -28,6 +105,57 @@ impl ProfileViewModel {
             followers_count: profile.followers_count.unwrap_or(0),
             follows_count: profile.follows_count.unwrap_or(0),
             posts_count: profile.posts_count.unwrap_or(0),
+            viewer: Relationship::from_viewer(profile.viewer.as_ref()),
+        }
+    }
+
+    /// Follow state changed; keeps the follower count in step.
+    pub fn set_following(&mut self, following: bool, uri: Option<String>) {
+        if following != self.viewer.following {
+            self.followers_count = (self.followers_count + if following { 1 } else { -1 }).max(0);
+        }
+        self.viewer.following = following;
+        self.viewer.following_uri = uri;
+    }
+
+    /// From the lighter `ProfileView` returned by search and graph lists, which carries
+    /// no counts or banner.
+    pub fn from_profile_view(profile: &atrium_api::app::bsky::actor::defs::ProfileView) -> Self {
+        ProfileViewModel {
+            did: profile.did.to_string(),
+            handle: profile.handle.to_string(),
+            display_name: profile
+                .display_name
+                .clone()
+                .unwrap_or_else(|| profile.handle.to_string()),
+            description: profile.description.clone().unwrap_or_default(),
+            avatar: profile.avatar.clone(),
+            banner: None,
+            followers_count: 0,
+            follows_count: 0,
+            posts_count: 0,
+            viewer: Relationship::from_viewer(profile.viewer.as_ref()),
+        }
+    }
+
+    /// From `ProfileViewBasic`, as returned by typeahead search.
+    pub fn from_profile_view_basic(
+        profile: &atrium_api::app::bsky::actor::defs::ProfileViewBasic,
+    ) -> Self {
+        ProfileViewModel {
+            did: profile.did.to_string(),
+            handle: profile.handle.to_string(),
+            display_name: profile
+                .display_name
+                .clone()
+                .unwrap_or_else(|| profile.handle.to_string()),
+            description: String::new(),
+            avatar: profile.avatar.clone(),
+            banner: None,
+            followers_count: 0,
+            follows_count: 0,
+            posts_count: 0,
+            viewer: Relationship::from_viewer(profile.viewer.as_ref()),
         }
     }
 }
@@ -0,0 +1,187 @@
+use super::feed::FeedState;
+use super::paged::PagedList;
+use super::profile::ProfileViewModel;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchMode {
+    Posts,
+    People,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchSort {
+    Top,
+    Latest,
+}
+
+impl SearchSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchSort::Top => "top",
+            SearchSort::Latest => "latest",
+        }
+    }
+}
+
+/// A search prompt split into free text and `from:`/`since:`/`until:`/`lang:` filters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    pub text: String,
+    pub author: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub lang: Option<String>,
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> Self {
+        let mut query = SearchQuery::default();
+        let mut words = Vec::new();
+        for word in input.split_whitespace() {
+            match word.split_once(':') {
+                Some(("from", v)) if !v.is_empty() => {
+                    query.author = Some(v.trim_start_matches('@').to_string())
+                }
+                Some(("since", v)) if !v.is_empty() => query.since = Some(v.to_string()),
+                Some(("until", v)) if !v.is_empty() => query.until = Some(v.to_string()),
+                Some(("lang", v)) if !v.is_empty() => query.lang = Some(v.to_string()),
+                _ => words.push(word),
+            }
+        }
+        query.text = words.join(" ");
+        query
+    }
+
+    pub fn has_filters(&self) -> bool {
+        self.author.is_some() || self.since.is_some() || self.until.is_some() || self.lang.is_some()
+    }
+
+    /// The `q` for `searchPosts`, which rejects an empty one; filters alone match everything.
+    pub fn q(&self) -> String {
+        if self.text.is_empty() && self.has_filters() {
+            "*".to_string()
+        } else {
+            self.text.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchState {
+    /// The prompt as typed, filters included.
+    pub input: String,
+    pub query: SearchQuery,
+    pub mode: SearchMode,
+    pub sort: SearchSort,
+    pub posts: FeedState,
+    pub actors: PagedList<ProfileViewModel>,
+}
+
+impl SearchState {
+    pub fn new() -> Self {
+        SearchState {
+            input: String::new(),
+            query: SearchQuery::default(),
+            mode: SearchMode::Posts,
+            sort: SearchSort::Top,
+            posts: FeedState::new(),
+            actors: PagedList::new(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        match self.mode {
+            SearchMode::Posts => self.posts.select_next(),
+            SearchMode::People => self.actors.select_next(),
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        match self.mode {
+            SearchMode::Posts => self.posts.select_prev(),
+            SearchMode::People => self.actors.select_prev(),
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        match self.mode {
+            SearchMode::Posts => self.posts.select_first(),
+            SearchMode::People => self.actors.select_first(),
+        }
+    }
+
+    pub fn select_last(&mut self) {
+        match self.mode {
+            SearchMode::Posts => self.posts.select_last(),
+            SearchMode::People => self.actors.select_last(),
+        }
+    }
+
+    pub fn near_bottom(&self) -> bool {
+        match self.mode {
+            SearchMode::Posts => self.posts.near_bottom(20),
+            SearchMode::People => self.actors.near_bottom(),
+        }
+    }
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_has_no_filters() {
+        let query = SearchQuery::parse("  rust   tui ");
+        assert_eq!(
+            query,
+            SearchQuery {
+                text: "rust tui".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn filters_are_pulled_out_of_the_text() {
+        let query = SearchQuery::parse(
+            "from:@alice.test rust since:2024-01-01 lang:en tui until:2024-02-01",
+        );
+        assert_eq!(
+            query,
+            SearchQuery {
+                text: "rust tui".to_string(),
+                author: Some("alice.test".to_string()),
+                since: Some("2024-01-01".to_string()),
+                until: Some("2024-02-01".to_string()),
+                lang: Some("en".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_and_unknown_filters_stay_in_the_text() {
+        let query = SearchQuery::parse("from: note:this https://example.com");
+        assert_eq!(query.text, "from: note:this https://example.com");
+        assert_eq!(query.author, None);
+    }
+
+    #[test]
+    fn filters_alone_leave_empty_text() {
+        let query = SearchQuery::parse("from:bob.test");
+        assert!(query.text.is_empty());
+        assert_eq!(query.author.as_deref(), Some("bob.test"));
+    }
+
+    #[test]
+    fn filter_only_searches_send_a_wildcard() {
+        assert_eq!(SearchQuery::parse("from:alice since:2026-01-01").q(), "*");
+        assert_eq!(SearchQuery::parse("rust from:alice").q(), "rust");
+        assert_eq!(SearchQuery::parse("").q(), "");
+    }
+}
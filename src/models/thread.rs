@@ -1,15 +1,88 @@
 use super::post::PostViewModel;
 
+/// Reply levels requested per fetch; deeper subtrees are loaded on demand.
+pub const THREAD_DEPTH: u16 = 6;
+
+/// Why a post in a thread can't be shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unavailable {
+    NotFound,
+    Blocked,
+    /// Listed in the root author's threadgate `hiddenReplies`.
+    Hidden,
+}
+
+impl Unavailable {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Unavailable::NotFound => "Post deleted",
+            Unavailable::Blocked => "Blocked post",
+            Unavailable::Hidden => "Hidden by author's threadgate",
+        }
+    }
+
+    /// Shown in place of the whole thread when the requested post itself is unavailable.
+    pub fn root_message(&self) -> &'static str {
+        match self {
+            Unavailable::NotFound => "This post has been deleted.",
+            Unavailable::Blocked => {
+                "This post is unavailable because of a block between you and its author."
+            }
+            Unavailable::Hidden => "This post was hidden by the thread's author.",
+        }
+    }
+}
+
+/// A slot in a thread: a post, or a placeholder where one can't be shown.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum ThreadPost {
+    Post(PostViewModel),
+    Unavailable { uri: String, reason: Unavailable },
+}
+
+impl ThreadPost {
+    pub fn uri(&self) -> &str {
+        match self {
+            ThreadPost::Post(post) => &post.uri,
+            ThreadPost::Unavailable { uri, .. } => uri,
+        }
+    }
+
+    pub fn post(&self) -> Option<&PostViewModel> {
+        match self {
+            ThreadPost::Post(post) => Some(post),
+            ThreadPost::Unavailable { .. } => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ThreadViewModel {
-    pub parents: Vec<PostViewModel>,
+    pub parents: Vec<ThreadPost>,
     pub focal: PostViewModel,
-    pub replies: Vec<PostViewModel>,
+    /// The reply tree below the focal post, flattened depth-first.
+    pub replies: Vec<ReplyNode>,
+    /// Index into `replies`, or `None` when the focal post is selected.
+    pub selected: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplyNode {
+    pub post: ThreadPost,
+    /// 1 for direct replies to the focal post.
+    pub depth: usize,
+    pub collapsed: bool,
+    /// Replies exist past the depth limit and haven't been fetched yet.
+    pub has_more: bool,
+    pub loading: bool,
 }
 
 impl ThreadViewModel {
+    /// `hidden` holds the URIs of replies the root author hid with a threadgate.
     pub fn from_thread_view_post(
         tvp: &atrium_api::app::bsky::feed::defs::ThreadViewPost,
+        hidden: &[String],
     ) -> Option<Self> {
         let focal = PostViewModel::from_post_view(&tvp.post)?;
 
@@ -20,7 +93,7 @@ impl ThreadViewModel {
         let mut replies = Vec::new();
         if let Some(ref reply_list) = tvp.replies {
             for reply in reply_list {
-                Self::collect_reply(reply, &mut replies);
+                Self::collect_reply(reply, 1, hidden, &mut replies);
             }
         }
 
@@ -28,6 +101,7 @@ impl ThreadViewModel {
             parents,
             focal,
             replies,
+            selected: None,
         })
     }
 
@@ -37,19 +111,31 @@ impl ThreadViewModel {
                 atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs,
             >,
         >,
-        out: &mut Vec<PostViewModel>,
+        out: &mut Vec<ThreadPost>,
     ) {
         use atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs;
         use atrium_api::types::Union;
 
-        if let Some(Union::Refs(ThreadViewPostParentRefs::ThreadViewPost(
-            tvp,
-        ))) = parent
-        {
-            if let Some(post) = PostViewModel::from_post_view(&tvp.post) {
-                out.push(post);
+        match parent {
+            Some(Union::Refs(ThreadViewPostParentRefs::ThreadViewPost(tvp))) => {
+                if let Some(post) = PostViewModel::from_post_view(&tvp.post) {
+                    out.push(ThreadPost::Post(post));
+                }
+                Self::collect_parents(&tvp.parent, out);
+            }
+            Some(Union::Refs(ThreadViewPostParentRefs::NotFoundPost(nf))) => {
+                out.push(ThreadPost::Unavailable {
+                    uri: nf.uri.clone(),
+                    reason: Unavailable::NotFound,
+                });
+            }
+            Some(Union::Refs(ThreadViewPostParentRefs::BlockedPost(bp))) => {
+                out.push(ThreadPost::Unavailable {
+                    uri: bp.uri.clone(),
+                    reason: Unavailable::Blocked,
+                });
             }
-            Self::collect_parents(&tvp.parent, out);
+            _ => {}
         }
     }
 
@@ -57,17 +143,313 @@ impl ThreadViewModel {
         reply: &atrium_api::types::Union<
             atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem,
         >,
-        out: &mut Vec<PostViewModel>,
+        depth: usize,
+        hidden: &[String],
+        out: &mut Vec<ReplyNode>,
     ) {
         use atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem;
         use atrium_api::types::Union;
 
-        if let Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(tvp)) =
-            reply
-        {
-            if let Some(post) = PostViewModel::from_post_view(&tvp.post) {
-                out.push(post);
+        let placeholder = |uri: &str, reason| ReplyNode {
+            post: ThreadPost::Unavailable {
+                uri: uri.to_string(),
+                reason,
+            },
+            depth,
+            collapsed: false,
+            has_more: false,
+            loading: false,
+        };
+        let tvp = match reply {
+            Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(tvp)) => tvp,
+            Union::Refs(ThreadViewPostRepliesItem::NotFoundPost(nf)) => {
+                out.push(placeholder(&nf.uri, Unavailable::NotFound));
+                return;
+            }
+            Union::Refs(ThreadViewPostRepliesItem::BlockedPost(bp)) => {
+                out.push(placeholder(&bp.uri, Unavailable::Blocked));
+                return;
+            }
+            Union::Unknown(_) => return,
+        };
+        // Hidden replies take their subtree with them, as on the web
+        if hidden.contains(&tvp.post.uri) {
+            out.push(placeholder(&tvp.post.uri, Unavailable::Hidden));
+            return;
+        }
+        let Some(post) = PostViewModel::from_post_view(&tvp.post) else {
+            return;
+        };
+        let children = tvp.replies.as_deref().unwrap_or_default();
+        let has_more =
+            depth >= THREAD_DEPTH as usize && children.is_empty() && post.reply_count > 0;
+        out.push(ReplyNode {
+            post: ThreadPost::Post(post),
+            depth,
+            collapsed: false,
+            has_more,
+            loading: false,
+        });
+        for child in children {
+            Self::collect_reply(child, depth + 1, hidden, out);
+        }
+    }
+
+    /// Number of nodes below `replies[index]` in its subtree.
+    pub fn descendant_count(&self, index: usize) -> usize {
+        let depth = self.replies[index].depth;
+        self.replies[index + 1..]
+            .iter()
+            .take_while(|n| n.depth > depth)
+            .count()
+    }
+
+    /// Indices of the replies not hidden inside a collapsed subtree.
+    pub fn visible_replies(&self) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut i = 0;
+        while i < self.replies.len() {
+            visible.push(i);
+            if self.replies[i].collapsed {
+                i += self.descendant_count(i);
+            }
+            i += 1;
+        }
+        visible
+    }
+
+    /// `None` when a placeholder is selected.
+    pub fn selected_post(&self) -> Option<&PostViewModel> {
+        match self.selected {
+            Some(i) => self.replies[i].post.post(),
+            None => Some(&self.focal),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        let visible = self.visible_replies();
+        self.selected = match self.selected {
+            None => visible.first().copied(),
+            Some(i) => {
+                let pos = visible.iter().position(|&v| v == i).unwrap_or(0);
+                Some(visible.get(pos + 1).copied().unwrap_or(i))
+            }
+        };
+    }
+
+    pub fn select_prev(&mut self) {
+        let visible = self.visible_replies();
+        self.selected = match self.selected {
+            None | Some(0) => None,
+            Some(i) => match visible.iter().position(|&v| v == i) {
+                Some(0) | None => None,
+                Some(pos) => Some(visible[pos - 1]),
+            },
+        };
+    }
+
+    pub fn select_first(&mut self) {
+        self.selected = None;
+    }
+
+    pub fn select_last(&mut self) {
+        if let Some(&last) = self.visible_replies().last() {
+            self.selected = Some(last);
+        }
+    }
+
+    /// Collapse or expand the selected reply's subtree. Returns the post URI when its
+    /// replies are past the depth limit and have to be fetched instead.
+    pub fn toggle_selected(&mut self) -> Option<String> {
+        let index = self.selected?;
+        if self.descendant_count(index) > 0 {
+            let node = &mut self.replies[index];
+            node.collapsed = !node.collapsed;
+            return None;
+        }
+        let node = &mut self.replies[index];
+        if node.has_more && !node.loading {
+            node.loading = true;
+            return Some(node.post.uri().to_string());
+        }
+        None
+    }
+
+    /// Graft the replies of a separately fetched `subtree` under the reply at `uri`.
+    pub fn insert_subtree(&mut self, uri: &str, subtree: Option<ThreadViewModel>) {
+        let Some(index) = self.replies.iter().position(|n| n.post.uri() == uri) else {
+            return;
+        };
+        let depth = self.replies[index].depth;
+        self.replies[index].loading = false;
+
+        // A failed fetch leaves `has_more` set so it can be retried
+        let Some(subtree) = subtree else {
+            return;
+        };
+        if self.descendant_count(index) > 0 {
+            return;
+        }
+        self.replies[index].has_more = false;
+        let nodes = subtree.replies.into_iter().map(|mut n| {
+            n.depth += depth;
+            n
+        });
+        let count = self.replies.len();
+        self.replies.splice(index + 1..index + 1, nodes);
+        let added = self.replies.len() - count;
+        if let Some(ref mut selected) = self.selected {
+            if *selected > index {
+                *selected += added;
             }
         }
     }
+
+    /// Replace a deleted post with a placeholder, keeping any replies below it.
+    pub fn mark_deleted(&mut self, uri: &str) {
+        let slots = self
+            .parents
+            .iter_mut()
+            .chain(self.replies.iter_mut().map(|n| &mut n.post))
+            .filter(|p| p.uri() == uri);
+        for slot in slots {
+            *slot = ThreadPost::Unavailable {
+                uri: uri.to_string(),
+                reason: Unavailable::NotFound,
+            };
+        }
+    }
+
+    pub fn posts_mut(&mut self) -> impl Iterator<Item = &mut PostViewModel> {
+        self.parents
+            .iter_mut()
+            .chain(self.replies.iter_mut().map(|n| &mut n.post))
+            .filter_map(|p| match p {
+                ThreadPost::Post(post) => Some(post),
+                ThreadPost::Unavailable { .. } => None,
+            })
+            .chain(std::iter::once(&mut self.focal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, depth: usize) -> ReplyNode {
+        ReplyNode {
+            post: ThreadPost::Post(PostViewModel::sample(name, "did:plc:author", name)),
+            depth,
+            collapsed: false,
+            has_more: false,
+            loading: false,
+        }
+    }
+
+    fn thread(replies: Vec<ReplyNode>) -> ThreadViewModel {
+        ThreadViewModel {
+            parents: Vec::new(),
+            focal: PostViewModel::sample("focal", "did:plc:author", "focal"),
+            replies,
+            selected: None,
+        }
+    }
+
+    fn uris(thread: &ThreadViewModel) -> Vec<(String, usize)> {
+        thread
+            .replies
+            .iter()
+            .map(|n| (n.post.uri().to_string(), n.depth))
+            .collect()
+    }
+
+    /// a
+    ///   a1
+    ///     a1x
+    ///   a2
+    /// b
+    fn tree() -> ThreadViewModel {
+        thread(vec![
+            node("a", 1),
+            node("a1", 2),
+            node("a1x", 3),
+            node("a2", 2),
+            node("b", 1),
+        ])
+    }
+
+    #[test]
+    fn everything_is_visible_until_collapsed() {
+        assert_eq!(tree().visible_replies(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn collapsing_hides_only_the_subtree() {
+        let mut thread = tree();
+        thread.replies[1].collapsed = true;
+        assert_eq!(thread.visible_replies(), vec![0, 1, 3, 4]);
+
+        thread.replies[0].collapsed = true;
+        assert_eq!(thread.visible_replies(), vec![0, 4]);
+    }
+
+    #[test]
+    fn selection_skips_collapsed_replies() {
+        let mut thread = tree();
+        thread.selected = Some(0);
+        assert_eq!(thread.toggle_selected(), None);
+        thread.select_next();
+        assert_eq!(thread.selected, Some(4));
+        thread.select_prev();
+        assert_eq!(thread.selected, Some(0));
+        thread.select_prev();
+        assert_eq!(thread.selected, None);
+    }
+
+    #[test]
+    fn subtrees_are_grafted_below_their_reply() {
+        let mut thread = tree();
+        thread.replies[2].has_more = true;
+        thread.replies[2].loading = true;
+        thread.selected = Some(4);
+
+        let subtree = self::thread(vec![node("deep", 1), node("deeper", 2)]);
+        thread.insert_subtree("a1x", Some(subtree));
+
+        assert_eq!(
+            uris(&thread),
+            vec![
+                ("a".to_string(), 1),
+                ("a1".to_string(), 2),
+                ("a1x".to_string(), 3),
+                ("deep".to_string(), 4),
+                ("deeper".to_string(), 5),
+                ("a2".to_string(), 2),
+                ("b".to_string(), 1),
+            ]
+        );
+        assert!(!thread.replies[2].has_more && !thread.replies[2].loading);
+        // Still on "b"
+        assert_eq!(thread.selected, Some(6));
+    }
+
+    #[test]
+    fn failed_subtree_fetch_only_clears_loading() {
+        let mut thread = tree();
+        thread.replies[2].has_more = true;
+        thread.replies[2].loading = true;
+        thread.insert_subtree("a1x", None);
+        assert_eq!(thread.replies.len(), 5);
+        assert!(!thread.replies[2].loading);
+        assert!(thread.replies[2].has_more);
+    }
+
+    #[test]
+    fn subtrees_are_not_grafted_twice() {
+        let mut thread = tree();
+        let subtree = self::thread(vec![node("dupe", 1)]);
+        thread.insert_subtree("a1", Some(subtree.clone()));
+        thread.insert_subtree("missing", Some(subtree));
+        assert_eq!(thread.replies.len(), 5);
+    }
 }
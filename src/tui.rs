@@ -2,7 +2,8 @@ use anyhow::Result;
 use crossterm::{
     cursor,
     event::{
-        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+        DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
     },
     execute,
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
@@ -14,7 +15,7 @@ pub type Tui = Terminal<CrosstermBackend<io::Stdout>>;
 
 pub fn init() -> Result<Tui> {
     terminal::enable_raw_mode()?;
-    execute!(stdout(), EnterAlternateScreen)?;
+    execute!(stdout(), EnterAlternateScreen, EnableBracketedPaste)?;
 
     // Enable keyboard enhancement for terminals that support it (iTerm2, Kitty, WezTerm, etc.)
     // This allows distinguishing Ctrl+Enter from plain Enter.
@@ -32,6 +33,6 @@ pub fn init() -> Result<Tui> {
 pub fn restore() -> Result<()> {
     let _ = execute!(stdout(), PopKeyboardEnhancementFlags);
     terminal::disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen, cursor::Show)?;
+    execute!(stdout(), DisableBracketedPaste, LeaveAlternateScreen, cursor::Show)?;
     Ok(())
 }
@@ -51,8 +51,24 @@ pub fn draw_about(frame: &mut Frame, area: Rect) {
             Span::raw("Go back"),
         ]),
         Line::from(vec![
-            Span::styled("  1 / 2      ", Style::default().fg(Color::Yellow)),
-            Span::raw("Timeline / Profile tab"),
+            Span::styled("  1 - 4      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Timeline / Profile / Notifications / Messages tab"),
+        ]),
+        Line::from(vec![
+            Span::styled("  space      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Collapse / expand replies (thread)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  [ / ]      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Previous / next pinned feed or profile tab"),
+        ]),
+        Line::from(vec![
+            Span::styled("  f          ", Style::default().fg(Color::Yellow)),
+            Span::raw("All saved feeds (p to pin/unpin)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  /          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Search posts and people"),
         ]),
         Line::from(""),
         Line::from(Span::styled(
@@ -67,6 +83,18 @@ pub fn draw_about(frame: &mut Frame, area: Rect) {
             Span::styled("  r          ", Style::default().fg(Color::Yellow)),
             Span::raw("Reply to selected post"),
         ]),
+        Line::from(vec![
+            Span::styled("  Q          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Quote selected post"),
+        ]),
+        Line::from(vec![
+            Span::styled("  d          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Delete your selected post"),
+        ]),
+        Line::from(vec![
+            Span::styled("  D          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Saved drafts"),
+        ]),
         Line::from(vec![
             Span::styled("  l          ", Style::default().fg(Color::Yellow)),
             Span::raw("Like / unlike"),
@@ -88,6 +116,38 @@ pub fn draw_about(frame: &mut Frame, area: Rect) {
             Span::styled("  u          ", Style::default().fg(Color::Yellow)),
             Span::raw("View author profile"),
         ]),
+        Line::from(vec![
+            Span::styled("  o / O      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Followers / following (profile)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  F / M / B  ", Style::default().fg(Color::Yellow)),
+            Span::raw("Follow, mute, block (profile)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  m / x      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Mute / leave conversation (messages)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  v          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Show / hide a filtered or warned post"),
+        ]),
+        Line::from(vec![
+            Span::styled("  L / i      ", Style::default().fg(Color::Yellow)),
+            Span::raw("Lists / add author to a list"),
+        ]),
+        Line::from(vec![
+            Span::styled("  !          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Report a post or account"),
+        ]),
+        Line::from(vec![
+            Span::styled("  W          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Muted words"),
+        ]),
+        Line::from(vec![
+            Span::styled("  A          ", Style::default().fg(Color::Yellow)),
+            Span::raw("Switch account"),
+        ]),
         Line::from(vec![
             Span::styled("  a          ", Style::default().fg(Color::Yellow)),
             Span::raw("This about screen"),
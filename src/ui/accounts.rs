@@ -0,0 +1,137 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::action::Action;
+use crate::api::session;
+use crate::ui::Component;
+
+struct AccountEntry {
+    did: String,
+    handle: String,
+}
+
+/// Modal list of saved accounts, shown on the login screen and via `A` at runtime.
+pub struct AccountPicker {
+    accounts: Vec<AccountEntry>,
+    active_did: Option<String>,
+    selected: usize,
+}
+
+impl AccountPicker {
+    pub fn new() -> Self {
+        AccountPicker {
+            accounts: Vec::new(),
+            active_did: None,
+            selected: 0,
+        }
+    }
+
+    /// Reload saved accounts from disk, preselecting the active one.
+    pub fn reload(&mut self, active_did: Option<String>) {
+        self.accounts = session::list_accounts()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| AccountEntry {
+                did: s.did,
+                handle: s.handle,
+            })
+            .collect();
+        self.selected = active_did
+            .as_ref()
+            .and_then(|did| self.accounts.iter().position(|a| &a.did == did))
+            .unwrap_or(0);
+        self.active_did = active_did;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+impl Component for AccountPicker {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => return Some(Action::Quit),
+            (KeyModifiers::NONE, KeyCode::Esc) => return Some(Action::CloseAccountSwitcher),
+            (KeyModifiers::NONE, KeyCode::Char('j')) | (KeyModifiers::NONE, KeyCode::Down) => {
+                // The last row is "Add account"
+                self.selected = (self.selected + 1).min(self.accounts.len());
+            }
+            (KeyModifiers::NONE, KeyCode::Char('k')) | (KeyModifiers::NONE, KeyCode::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            (KeyModifiers::NONE, KeyCode::Char('a')) => return Some(Action::AddAccount),
+            (KeyModifiers::NONE, KeyCode::Enter) => {
+                return Some(match self.accounts.get(self.selected) {
+                    Some(account) if Some(&account.did) == self.active_did.as_ref() => {
+                        Action::CloseAccountSwitcher
+                    }
+                    Some(account) => Action::SwitchAccount(account.did.clone()),
+                    None => Action::AddAccount,
+                });
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 50.min(area.width.saturating_sub(4));
+        let modal_height = (self.accounts.len() as u16 + 5).min(area.height.saturating_sub(4));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Accounts ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let mut lines: Vec<Line> = self
+            .accounts
+            .iter()
+            .enumerate()
+            .map(|(i, account)| {
+                let selected = i == self.selected;
+                let marker = if Some(&account.did) == self.active_did.as_ref() {
+                    "● "
+                } else {
+                    "  "
+                };
+                let style = if selected {
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(vec![
+                    Span::styled(marker, Style::default().fg(Color::Green)),
+                    Span::styled(format!("@{}", account.handle), style),
+                ])
+            })
+            .collect();
+
+        let add_style = if self.selected == self.accounts.len() {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        lines.push(Line::from(Span::styled("  + Add account", add_style)));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k: select  Enter: switch  a: add  Esc: close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -0,0 +1,82 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::models::profile::ProfileViewModel;
+
+const ROW_HEIGHT: u16 = 3;
+
+/// Compact list of accounts: name, handle and follow state, then the first line of the bio.
+pub fn draw_actor_list(
+    frame: &mut Frame,
+    area: Rect,
+    actors: &[ProfileViewModel],
+    selected: usize,
+    loading: bool,
+) {
+    let visible_rows = (area.height / ROW_HEIGHT).max(1) as usize;
+    let offset = (selected + 1).saturating_sub(visible_rows);
+
+    let mut y = area.y;
+    for (i, actor) in actors.iter().enumerate().skip(offset) {
+        if y + ROW_HEIGHT > area.bottom() {
+            break;
+        }
+        draw_actor_row(
+            frame,
+            Rect::new(area.x, y, area.width, ROW_HEIGHT),
+            actor,
+            i == selected,
+        );
+        y += ROW_HEIGHT;
+    }
+
+    if loading && y < area.bottom() {
+        frame.render_widget(
+            Paragraph::new("Loading more...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y, area.width, 1),
+        );
+    }
+}
+
+fn draw_actor_row(frame: &mut Frame, area: Rect, actor: &ProfileViewModel, selected: bool) {
+    let border_style = if selected {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let x = inner.x + 1;
+    let w = inner.width.saturating_sub(1);
+
+    let mut name = vec![
+        Span::styled(
+            actor.display_name.as_str(),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  @{}", actor.handle),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if actor.viewer.following {
+        name.push(Span::styled("  Following", Style::default().fg(Color::Cyan)));
+    }
+    if actor.viewer.followed_by {
+        name.push(Span::styled("  Follows you", Style::default().fg(Color::Gray)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(name)), Rect::new(x, inner.y, w, 1));
+
+    if let Some(bio) = actor.description.lines().find(|l| !l.trim().is_empty()) {
+        frame.render_widget(
+            Paragraph::new(bio).style(Style::default().fg(Color::Gray)),
+            Rect::new(x, inner.y + 1, w, 1),
+        );
+    }
+}
@@ -1,105 +1,790 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use tui_textarea::TextArea;
 
 use crate::action::Action;
-use crate::api::client::ReplyRef;
+use crate::api::client::{ImageAttachment, QuoteRef, ReplyRef};
+use crate::drafts::{Draft, DraftImage};
+use crate::models::moderation::SELF_LABELS;
+use crate::models::profile::ProfileViewModel;
 use crate::ui::Component;
+use crate::utils::image::{self, MAX_IMAGES};
+use crate::utils::text::{split_thread, PostLength, MAX_POST_BYTES, MAX_POST_GRAPHEMES};
+use crate::utils::time::relative_time;
+
+/// Single-line prompt shown inside the composer.
+enum Prompt {
+    AttachPath(TextArea<'static>),
+    AltText(usize, TextArea<'static>),
+}
 
-const MAX_CHARS: usize = 300;
+impl Prompt {
+    fn input(&self) -> &TextArea<'static> {
+        match self {
+            Prompt::AttachPath(input) | Prompt::AltText(_, input) => input,
+        }
+    }
+
+    fn input_mut(&mut self) -> &mut TextArea<'static> {
+        match self {
+            Prompt::AttachPath(input) | Prompt::AltText(_, input) => input,
+        }
+    }
+}
+
+fn prompt_input(title: &str, initial: &str) -> TextArea<'static> {
+    let mut input = TextArea::new(vec![initial.to_string()]);
+    input.set_block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(title.to_string()),
+    );
+    input.set_cursor_line_style(Style::default());
+    input.move_cursor(tui_textarea::CursorMove::End);
+    input
+}
+
+fn segment_input(text: &str, first: bool) -> TextArea<'static> {
+    let mut textarea = TextArea::new(text.lines().map(str::to_string).collect());
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_placeholder_text(if first {
+        "What's on your mind?"
+    } else {
+        "Continue the thread..."
+    });
+    textarea.move_cursor(tui_textarea::CursorMove::Bottom);
+    textarea.move_cursor(tui_textarea::CursorMove::End);
+    textarea
+}
+
+/// Shown while the word under the cursor is an `@mention` or `#tag`.
+struct Completion {
+    trigger: char,
+    /// The word after the trigger character.
+    query: String,
+    items: Vec<CompletionItem>,
+    selected: usize,
+}
+
+struct CompletionItem {
+    /// Handle or tag, without the trigger character.
+    value: String,
+    label: String,
+    did: Option<String>,
+}
+
+const MAX_COMPLETIONS: usize = 8;
+
+const THREAD_SEPARATOR: &str = "\n---\n";
+
+fn segment_length(textarea: &TextArea) -> PostLength {
+    PostLength::of(&textarea.lines().join("\n"))
+}
 
 pub struct Composer {
-    textarea: TextArea<'static>,
+    /// One textarea per post; more than one makes a thread.
+    segments: Vec<TextArea<'static>>,
+    active: usize,
     reply_to: Option<ReplyRef>,
     reply_to_author: Option<String>,
+    quote: Option<QuoteRef>,
+    /// One-line "author: text" preview of the quoted post.
+    quote_preview: Option<String>,
+    images: Vec<ImageAttachment>,
+    /// Index into `SELF_LABELS` for the content warning on the first post.
+    self_label: Option<usize>,
+    prompt: Option<Prompt>,
+    notice: Option<String>,
+    /// Set once the user has been told about missing alt text; the next submit posts.
+    alt_text_warned: bool,
+    completion: Option<Completion>,
+    /// Hashtags from loaded posts, offered for `#` completion.
+    known_tags: Vec<String>,
+    /// Handles inserted from `@` completion, with the DID that was picked.
+    mentions: HashMap<String, String>,
+    /// Edited since the draft was last saved.
+    dirty: bool,
+    /// Esc was pressed on a non-empty draft; waiting for save/discard.
+    confirm_close: bool,
 }
 
 impl Composer {
     pub fn new() -> Self {
-        let mut textarea = TextArea::default();
-        textarea.set_block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
-                .title(" Compose "),
-        );
-        textarea.set_cursor_line_style(Style::default());
-        textarea.set_placeholder_text("What's on your mind?");
-
-        Composer {
-            textarea,
+        let mut composer = Composer {
+            segments: vec![segment_input("", true)],
+            active: 0,
             reply_to: None,
             reply_to_author: None,
-        }
+            quote: None,
+            quote_preview: None,
+            images: Vec::new(),
+            self_label: None,
+            prompt: None,
+            notice: None,
+            alt_text_warned: false,
+            completion: None,
+            known_tags: Vec::new(),
+            mentions: HashMap::new(),
+            dirty: false,
+            confirm_close: false,
+        };
+        composer.refresh_blocks();
+        composer
     }
 
     pub fn set_reply(&mut self, reply_to: Option<ReplyRef>, reply_to_author: Option<String>) {
         self.reply_to = reply_to;
         self.reply_to_author = reply_to_author;
-        if self.reply_to.is_some() {
-            self.textarea.set_block(
+        self.refresh_blocks();
+    }
+
+    pub fn set_quote(&mut self, quote: QuoteRef, author: String, text: String) {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        self.quote_preview = Some(format!("{}: {}", author, text));
+        self.quote = Some(quote);
+        self.refresh_blocks();
+    }
+
+    /// Explain why a reloaded submission is back: the whole post failed, or a
+    /// thread stopped after `posted` of `total` segments.
+    pub fn set_failure(&mut self, posted: usize, total: usize, error: &str) {
+        self.notice = Some(if posted > 0 {
+            format!(
+                "Posted {} of {}; the rest failed: {}. Enter retries",
+                posted, total, error
+            )
+        } else {
+            format!("Post failed: {}", error)
+        });
+        self.dirty = true;
+    }
+
+    pub fn draft_key(&self) -> String {
+        Draft::key_for(self.reply_to.as_ref(), self.quote.as_ref())
+    }
+
+    pub fn to_draft(&self) -> Draft {
+        Draft {
+            key: self.draft_key(),
+            segments: self.segment_texts(),
+            reply_to: self.reply_to.clone(),
+            reply_to_author: self.reply_to_author.clone(),
+            quote: self.quote.clone(),
+            quote_preview: self.quote_preview.clone(),
+            mentions: self.mentions.clone(),
+            images: self
+                .images
+                .iter()
+                .map(|i| DraftImage {
+                    path: i.path.clone(),
+                    alt: i.alt.clone(),
+                })
+                .collect(),
+            self_label: self.self_label.map(|i| SELF_LABELS[i].0.to_string()),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn load_draft(&mut self, draft: Draft) {
+        self.segments = draft
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(i, text)| segment_input(text, i == 0))
+            .collect();
+        if self.segments.is_empty() {
+            self.segments.push(segment_input("", true));
+        }
+        self.reply_to = draft.reply_to;
+        self.reply_to_author = draft.reply_to_author;
+        self.quote = draft.quote;
+        self.quote_preview = draft.quote_preview;
+        self.mentions = draft.mentions;
+        self.self_label = draft
+            .self_label
+            .and_then(|label| SELF_LABELS.iter().position(|(value, _)| *value == label));
+        let mut missing = Vec::new();
+        self.images = draft
+            .images
+            .into_iter()
+            .filter_map(|saved| match image::load_image(&saved.path) {
+                Ok(attachment) => Some(ImageAttachment {
+                    alt: saved.alt,
+                    ..attachment
+                }),
+                Err(_) => {
+                    missing.push(saved.path.display().to_string());
+                    None
+                }
+            })
+            .collect();
+        let mut notice = format!("Restored draft from {}", relative_time(&draft.updated_at));
+        if !missing.is_empty() {
+            notice.push_str(&format!("; couldn't attach {}", missing.join(", ")));
+        }
+        self.notice = Some(notice);
+        self.focus(0);
+    }
+
+    /// Whether there's anything worth keeping as a draft.
+    pub fn is_blank(&self) -> bool {
+        self.images.is_empty() && self.segments.iter().all(|s| s.is_empty())
+    }
+
+    /// Clear and return the unsaved-changes flag.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn title(&self) -> String {
+        if let Some(ref preview) = self.quote_preview {
+            let author = preview.split(':').next().unwrap_or("post");
+            format!(" Quote {} ", author)
+        } else if self.reply_to.is_some() {
+            format!(
+                " Reply to {} ",
+                self.reply_to_author.as_deref().unwrap_or("post")
+            )
+        } else {
+            " Compose ".to_string()
+        }
+    }
+
+    fn refresh_blocks(&mut self) {
+        let title = self.title();
+        let count = self.segments.len();
+        for (i, segment) in self.segments.iter_mut().enumerate() {
+            let (title, color) = if count > 1 {
+                let color = if i == self.active { Color::Cyan } else { Color::DarkGray };
+                (format!("{}({}/{}) ", title, i + 1, count), color)
+            } else {
+                (title.clone(), Color::DarkGray)
+            };
+            segment.set_block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::DarkGray))
-                    .title(format!(
-                        " Reply to {} ",
-                        self.reply_to_author.as_deref().unwrap_or("post")
-                    )),
+                    .border_style(Style::default().fg(color))
+                    .title(title),
             );
         }
     }
 
-    fn char_count(&self) -> usize {
-        self.textarea
-            .lines()
+    fn textarea(&self) -> &TextArea<'static> {
+        &self.segments[self.active]
+    }
+
+    fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.segments[self.active]
+    }
+
+    fn focus(&mut self, index: usize) {
+        self.active = index.min(self.segments.len() - 1);
+        self.completion = None;
+        self.refresh_blocks();
+    }
+
+    fn add_segment(&mut self) {
+        self.segments.insert(self.active + 1, segment_input("", false));
+        self.focus(self.active + 1);
+    }
+
+    fn remove_active_segment(&mut self) {
+        self.segments.remove(self.active);
+        self.focus(self.active.saturating_sub(1));
+    }
+
+    /// Break the current post at sentence boundaries into as many posts as it needs.
+    fn split_active(&mut self) {
+        let parts = split_thread(&self.textarea().lines().join("\n"));
+        if parts.len() <= 1 {
+            self.notice = Some("This post already fits".to_string());
+            return;
+        }
+        let added = parts.len() - 1;
+        let first = self.active == 0;
+        let inputs = parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| segment_input(part, first && i == 0));
+        self.segments.splice(self.active..=self.active, inputs);
+        self.notice = None;
+        self.focus(self.active + added);
+    }
+
+    pub fn set_known_tags(&mut self, tags: Vec<String>) {
+        self.known_tags = tags;
+    }
+
+    /// Typeahead results for `query`; dropped if the user has typed on since.
+    pub fn set_mention_suggestions(&mut self, query: &str, actors: Vec<ProfileViewModel>) {
+        let Some(completion) = self.completion.as_mut() else {
+            return;
+        };
+        if completion.trigger != '@' || completion.query != query {
+            return;
+        }
+        completion.items = actors
+            .into_iter()
+            .map(|actor| CompletionItem {
+                label: format!("{}  @{}", actor.display_name, actor.handle),
+                value: actor.handle,
+                did: Some(actor.did),
+            })
+            .collect();
+        completion.selected = 0;
+    }
+
+    /// The `@` or `#` word the cursor is at the end of, if any.
+    fn completion_token(&self) -> Option<(char, String)> {
+        let (row, col) = self.textarea().cursor();
+        let line = self.textarea().lines().get(row)?;
+        let before: String = line.chars().take(col).collect();
+        let word = before
+            .rsplit(|c: char| c.is_whitespace() || c == '(')
+            .next()?;
+        let mut chars = word.chars();
+        let trigger = chars.next().filter(|c| *c == '@' || *c == '#')?;
+        let query = chars.as_str();
+        let valid = match trigger {
+            '@' => query.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-'),
+            _ => !query.contains('#'),
+        };
+        valid.then(|| (trigger, query.to_string()))
+    }
+
+    /// Refresh the popup after an edit. Returns a typeahead lookup when the
+    /// mention query changed.
+    fn update_completion(&mut self) -> Option<Action> {
+        let Some((trigger, query)) = self.completion_token() else {
+            self.completion = None;
+            return None;
+        };
+        if let Some(ref c) = self.completion {
+            if c.trigger == trigger && c.query == query {
+                return None;
+            }
+        }
+
+        if trigger == '#' {
+            let lower = query.to_lowercase();
+            let items: Vec<CompletionItem> = self
+                .known_tags
+                .iter()
+                .filter(|t| t.to_lowercase().starts_with(&lower) && t.len() > query.len())
+                .take(MAX_COMPLETIONS)
+                .map(|t| CompletionItem {
+                    value: t.clone(),
+                    label: format!("#{}", t),
+                    did: None,
+                })
+                .collect();
+            self.completion = (!items.is_empty()).then_some(Completion {
+                trigger,
+                query,
+                items,
+                selected: 0,
+            });
+            return None;
+        }
+
+        // Keep showing the previous results until the new ones arrive
+        let items = self
+            .completion
+            .take()
+            .filter(|c| c.trigger == '@')
+            .map(|c| c.items)
+            .unwrap_or_default();
+        self.completion = Some(Completion {
+            trigger,
+            query: query.clone(),
+            items,
+            selected: 0,
+        });
+        (!query.is_empty()).then_some(Action::MentionTypeahead(query))
+    }
+
+    /// Replace the word under the cursor with the selected completion.
+    fn accept_completion(&mut self) {
+        let Some(completion) = self.completion.take() else {
+            return;
+        };
+        let Some(item) = completion.items.into_iter().nth(completion.selected) else {
+            return;
+        };
+        for _ in 0..completion.query.chars().count() {
+            self.textarea_mut().delete_char();
+        }
+        self.textarea_mut().insert_str(format!("{} ", item.value));
+        if let Some(did) = item.did {
+            self.mentions.insert(item.value, did);
+        }
+    }
+
+    fn handle_completion_key(&mut self, key: KeyEvent) -> bool {
+        let Some(completion) = self.completion.as_mut().filter(|c| !c.items.is_empty()) else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Down => {
+                completion.selected = (completion.selected + 1).min(completion.items.len() - 1);
+            }
+            KeyCode::Up => completion.selected = completion.selected.saturating_sub(1),
+            KeyCode::Tab | KeyCode::Enter => self.accept_completion(),
+            KeyCode::Esc => self.completion = None,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Bracketed paste. A dragged-in image file attaches; anything else is typed.
+    pub fn handle_paste(&mut self, text: &str) {
+        self.dirty = true;
+        match self.prompt {
+            Some(ref mut prompt) => {
+                prompt.input_mut().insert_str(text.replace('\n', " "));
+            }
+            None if image::is_image_path(text) => self.attach(text),
+            None => {
+                self.textarea_mut().insert_str(text);
+                if segment_length(self.textarea()).exceeds_limit() {
+                    self.notice = Some(self.over_limit_notice(self.active));
+                }
+            }
+        }
+    }
+
+    fn over_limit_notice(&self, index: usize) -> String {
+        let length = segment_length(&self.segments[index]);
+        let what = if length.bytes > MAX_POST_BYTES {
+            format!("{} bytes", MAX_POST_BYTES)
+        } else {
+            format!("{} characters", MAX_POST_GRAPHEMES)
+        };
+        if self.segments.len() == 1 {
+            format!("Over {}. Ctrl+Z splits it into a thread", what)
+        } else {
+            format!("Post {} is over {}. Ctrl+Z splits it", index + 1, what)
+        }
+    }
+
+    /// The whole draft for `$EDITOR`, thread posts separated by `---` lines.
+    pub fn draft_text(&self) -> String {
+        self.segment_texts().join(THREAD_SEPARATOR)
+    }
+
+    /// Load text back from the editor. Reply, quote and images are kept.
+    pub fn set_draft_text(&mut self, text: &str) {
+        let mut segments: Vec<TextArea<'static>> = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        for line in text.lines().chain(["---"]) {
+            if line.trim() != "---" {
+                current.push(line);
+                continue;
+            }
+            let part = current.join("\n");
+            let part = part.trim_matches('\n');
+            if !part.is_empty() || segments.is_empty() {
+                segments.push(segment_input(part, segments.is_empty()));
+            }
+            current.clear();
+        }
+
+        self.segments = segments;
+        self.dirty = true;
+        self.notice = self
+            .segments
+            .iter()
+            .position(|s| segment_length(s).exceeds_limit())
+            .map(|i| self.over_limit_notice(i));
+        self.focus(self.active);
+    }
+
+    fn segment_texts(&self) -> Vec<String> {
+        self.segments.iter().map(|s| s.lines().join("\n")).collect()
+    }
+
+    fn attach(&mut self, input: &str) {
+        if self.images.len() >= MAX_IMAGES {
+            self.notice = Some(format!("A post can have at most {} images", MAX_IMAGES));
+            return;
+        }
+        match image::load_image(&image::parse_path(input)) {
+            Ok(attachment) => {
+                self.images.push(attachment);
+                self.notice = None;
+                self.alt_text_warned = false;
+                self.edit_alt_text(self.images.len() - 1);
+            }
+            Err(e) => self.notice = Some(e.to_string()),
+        }
+    }
+
+    fn edit_alt_text(&mut self, index: usize) {
+        if let Some(image) = self.images.get(index) {
+            let title = format!(" Alt text for {} ", image.name);
+            self.prompt = Some(Prompt::AltText(index, prompt_input(&title, &image.alt)));
+        }
+    }
+
+    fn handle_prompt_key(&mut self, key: KeyEvent) {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Enter => {
+                let value = prompt.input().lines().join(" ").trim().to_string();
+                match self.prompt.take() {
+                    Some(Prompt::AttachPath(_)) if !value.is_empty() => self.attach(&value),
+                    Some(Prompt::AltText(index, _)) => {
+                        if let Some(image) = self.images.get_mut(index) {
+                            image.alt = value;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {
+                prompt.input_mut().input(key);
+            }
+        }
+    }
+
+    fn submit(&mut self) -> Option<Action> {
+        let segments = self.segment_texts();
+        if segments.len() == 1
+            && segments[0].is_empty()
+            && self.images.is_empty()
+            && self.quote.is_none()
+        {
+            return None;
+        }
+
+        if segments.len() > 1 {
+            if let Some(i) = segments.iter().position(|s| s.trim().is_empty()) {
+                self.notice = Some(format!("Post {} of the thread is empty", i + 1));
+                self.focus(i);
+                return None;
+            }
+        }
+
+        if let Some(i) = self
+            .segments
             .iter()
-            .map(|l| l.len())
-            .sum::<usize>()
-            + self.textarea.lines().len().saturating_sub(1)
+            .position(|s| segment_length(s).exceeds_limit())
+        {
+            self.notice = Some(self.over_limit_notice(i));
+            self.focus(i);
+            return None;
+        }
+
+        let missing_alt = self.images.iter().filter(|i| i.alt.is_empty()).count();
+        if missing_alt > 0 && !self.alt_text_warned {
+            self.alt_text_warned = true;
+            self.notice = Some(format!(
+                "{} image(s) without alt text. Ctrl+T to add, Enter to post anyway",
+                missing_alt
+            ));
+            return None;
+        }
+
+        Some(Action::SubmitPost {
+            segments,
+            reply_to: self.reply_to.clone(),
+            images: self.images.clone(),
+            quote: self.quote.clone(),
+            mentions: self.mentions.clone(),
+            self_label: self.self_label.map(|i| SELF_LABELS[i].0.to_string()),
+        })
     }
+}
+
+impl Composer {
+    /// Popup under the cursor line, or above it when there's no room below.
+    fn draw_completion(&self, frame: &mut Frame, textarea_area: Rect, modal_area: Rect) {
+        let Some(completion) = self.completion.as_ref().filter(|c| !c.items.is_empty()) else {
+            return;
+        };
+
+        let height = completion.items.len() as u16 + 2;
+        let width = 40.min(textarea_area.width.saturating_sub(2));
+        let row = self.textarea().cursor().0 as u16;
+        let cursor_y = (textarea_area.y + 1 + row).min(textarea_area.bottom().saturating_sub(2));
+        let y = if cursor_y + 1 + height <= modal_area.bottom() {
+            cursor_y + 1
+        } else {
+            cursor_y.saturating_sub(height).max(modal_area.y)
+        };
+        let popup = Rect {
+            x: textarea_area.x + 1,
+            y,
+            width,
+            height: height.min(modal_area.bottom().saturating_sub(y)),
+        };
+
+        let lines: Vec<Line> = completion
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let style = if i == completion.selected {
+                    Style::default().fg(Color::Black).bg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(Span::styled(format!(" {} ", item.label), style))
+            })
+            .collect();
 
-    fn text(&self) -> String {
-        self.textarea.lines().join("\n")
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            ),
+            popup,
+        );
     }
 }
 
 impl Component for Composer {
     fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
-        // Esc closes the composer
+        if self.prompt.is_some() {
+            self.handle_prompt_key(key);
+            return None;
+        }
+
+        if self.confirm_close {
+            self.confirm_close = false;
+            self.notice = None;
+            return match key.code {
+                KeyCode::Char('y') => Some(Action::CloseComposer { save_draft: true }),
+                KeyCode::Char('n') => Some(Action::CloseComposer { save_draft: false }),
+                _ => None,
+            };
+        }
+
+        if self.handle_completion_key(key) {
+            self.dirty = true;
+            return None;
+        }
+
+        // Esc closes the composer, asking first if there's a draft to keep
         if key.code == KeyCode::Esc {
-            return Some(Action::CloseComposer);
+            if self.is_blank() {
+                return Some(Action::CloseComposer { save_draft: false });
+            }
+            self.confirm_close = true;
+            self.notice = Some("Save draft? y: save  n: discard  Esc: keep editing".to_string());
+            return None;
         }
 
+        self.dirty = true;
+
         // Enter (with or without modifiers) submits the post
         // Ctrl+S also submits
         if key.code == KeyCode::Enter
             || (key.modifiers.contains(KeyModifiers::CONTROL)
                 && key.code == KeyCode::Char('s'))
         {
-            let text = self.text();
-            if text.is_empty() {
-                return None;
+            return self.submit();
+        }
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('o') => {
+                    if self.images.len() >= MAX_IMAGES {
+                        self.notice =
+                            Some(format!("A post can have at most {} images", MAX_IMAGES));
+                    } else {
+                        self.prompt =
+                            Some(Prompt::AttachPath(prompt_input(" Image path ", "")));
+                    }
+                    return None;
+                }
+                KeyCode::Char('t') => {
+                    // First image still missing alt text, otherwise the last one
+                    let index = self
+                        .images
+                        .iter()
+                        .position(|i| i.alt.is_empty())
+                        .unwrap_or(self.images.len().saturating_sub(1));
+                    self.edit_alt_text(index);
+                    return None;
+                }
+                KeyCode::Char('g') => {
+                    self.images.pop();
+                    self.notice = None;
+                    return None;
+                }
+                KeyCode::Char('l') => {
+                    self.add_segment();
+                    return None;
+                }
+                KeyCode::Char('z') => {
+                    self.split_active();
+                    return None;
+                }
+                KeyCode::Char('x') => {
+                    // None, then each label in turn
+                    self.self_label = match self.self_label {
+                        None => Some(0),
+                        Some(i) if i + 1 < SELF_LABELS.len() => Some(i + 1),
+                        Some(_) => None,
+                    };
+                    return None;
+                }
+                KeyCode::Char('q') => return Some(Action::OpenExternalEditor),
+                _ => {}
             }
-            return Some(Action::SubmitPost {
-                text,
-                reply_to: self.reply_to.clone(),
-            });
         }
 
-        // All other keys go to the textarea
         match key.code {
-            KeyCode::Char(_) if self.char_count() >= MAX_CHARS => return None,
-            _ => self.textarea.input(key),
-        };
+            KeyCode::Tab => self.focus(self.active + 1),
+            KeyCode::BackTab => self.focus(self.active.saturating_sub(1)),
+            KeyCode::Backspace
+                if self.segments.len() > 1 && self.textarea().is_empty() =>
+            {
+                self.remove_active_segment()
+            }
+            // All other keys go to the textarea. An edit that pushes the post
+            // over the limit is undone; paste is checked on submit instead.
+            _ => {
+                let was_over = segment_length(self.textarea()).exceeds_limit();
+                let modified = self.textarea_mut().input(key);
+                if modified && !was_over && segment_length(self.textarea()).exceeds_limit() {
+                    self.textarea_mut().undo();
+                }
+                if modified || self.completion.is_some() {
+                    return self.update_completion();
+                }
+            }
+        }
         None
     }
 
     fn update(&mut self, _action: &Action) {}
 
     fn draw(&self, frame: &mut Frame, area: Rect) {
+        let segment_rows = if self.segments.len() > 1 {
+            self.segments.len() as u16
+        } else {
+            0
+        };
+        let quote_rows = if self.quote_preview.is_some() { 1 } else { 0 };
+        let image_rows = self.images.len() as u16 + u16::from(self.self_label.is_some());
+        let prompt_rows = if self.prompt.is_some() { 3 } else { 0 };
+        let notice_rows = if self.notice.is_some() { 1 } else { 0 };
+
         let modal_width = 60.min(area.width.saturating_sub(4));
-        let modal_height = 12.min(area.height.saturating_sub(4));
+        let modal_height =
+            (12 + segment_rows + quote_rows + image_rows + prompt_rows + notice_rows).min(area.height.saturating_sub(4));
         let modal_area = Rect {
             x: (area.width.saturating_sub(modal_width)) / 2,
             y: (area.height.saturating_sub(modal_height)) / 2,
@@ -111,32 +796,148 @@ impl Component for Composer {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .constraints([
+                Constraint::Length(segment_rows),
+                Constraint::Min(1),
+                Constraint::Length(quote_rows),
+                Constraint::Length(image_rows),
+                Constraint::Length(prompt_rows),
+                Constraint::Length(notice_rows),
+                Constraint::Length(1),
+            ])
             .split(modal_area);
 
-        frame.render_widget(&self.textarea, chunks[0]);
+        // Thread overview, one row per post
+        let segment_lines: Vec<Line> = self
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                let length = segment_length(segment);
+                let marker = if i == self.active { "▸" } else { " " };
+                let count_style = if length.exceeds_limit() {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} {}. ", marker, i + 1),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(format!("{:>3}/{} ", length.graphemes, MAX_POST_GRAPHEMES), count_style),
+                    Span::styled(
+                        segment.lines().join(" "),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ])
+            })
+            .collect();
+        if segment_rows > 0 {
+            frame.render_widget(
+                Paragraph::new(segment_lines).style(Style::default().bg(Color::Black)),
+                chunks[0],
+            );
+        }
+
+        frame.render_widget(self.textarea(), chunks[1]);
+        self.draw_completion(frame, chunks[1], modal_area);
+
+        if let Some(ref preview) = self.quote_preview {
+            frame.render_widget(
+                Paragraph::new(format!(" ❝ {}", preview))
+                    .style(Style::default().fg(Color::DarkGray).bg(Color::Black)),
+                chunks[2],
+            );
+        }
+
+        // Attached images, then the self-label
+        let mut image_lines: Vec<Line> = self
+            .images
+            .iter()
+            .enumerate()
+            .map(|(i, image)| {
+                let alt = if image.alt.is_empty() {
+                    Span::styled("no alt text", Style::default().fg(Color::Yellow))
+                } else {
+                    Span::styled(
+                        format!("alt: {}", image.alt),
+                        Style::default().fg(Color::DarkGray),
+                    )
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!(" [{}] {} ", i + 1, image.name),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{}×{}  ", image.width, image.height),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    alt,
+                ])
+            })
+            .collect();
+        if let Some(i) = self.self_label {
+            image_lines.push(Line::from(vec![
+                Span::styled(" ⚠ Content warning: ", Style::default().fg(Color::Yellow)),
+                Span::styled(SELF_LABELS[i].1, Style::default().fg(Color::White)),
+                Span::styled("  Ctrl+X: change", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        frame.render_widget(
+            Paragraph::new(image_lines).style(Style::default().bg(Color::Black)),
+            chunks[3],
+        );
+
+        if let Some(ref prompt) = self.prompt {
+            frame.render_widget(prompt.input(), chunks[4]);
+        }
+
+        if let Some(ref notice) = self.notice {
+            frame.render_widget(
+                Paragraph::new(format!(" {}", notice))
+                    .style(Style::default().fg(Color::Yellow).bg(Color::Black)),
+                chunks[5],
+            );
+        }
 
         // Character counter + hints
-        let count = self.char_count();
-        let counter_style = if count > MAX_CHARS {
+        let length = segment_length(self.textarea());
+        let counter_style = if length.exceeds_limit() {
             Style::default().fg(Color::Red)
-        } else if count > MAX_CHARS - 20 {
+        } else if length.graphemes > MAX_POST_GRAPHEMES - 20 {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::DarkGray)
         };
 
+        let hints = if self.prompt.is_some() {
+            "Enter: confirm  Esc: cancel"
+        } else if length.exceeds_limit() {
+            "Ctrl+Z: split into thread  Esc: cancel"
+        } else if self.segments.len() > 1 {
+            "Enter: post thread  Tab: next  Ctrl+L: add  Ctrl+O: image  Esc: cancel"
+        } else if self.images.is_empty() {
+            "Enter: post  Ctrl+L: thread  Ctrl+O: image  Ctrl+Q: $EDITOR  Esc: cancel"
+        } else {
+            "Enter: post  Ctrl+O: image  Ctrl+T: alt  Ctrl+G: drop image  Ctrl+X: label  Esc: cancel"
+        };
+
+        // Byte count only matters for long non-Latin text, so show it when close
+        let counter = if length.bytes > MAX_POST_BYTES * 9 / 10 {
+            format!(
+                "{}/{} · {}/{} bytes",
+                length.graphemes, MAX_POST_GRAPHEMES, length.bytes, MAX_POST_BYTES
+            )
+        } else {
+            format!("{}/{}", length.graphemes, MAX_POST_GRAPHEMES)
+        };
         let status = Line::from(vec![
-            Span::styled(
-                format!("{}/{}", count, MAX_CHARS),
-                counter_style,
-            ),
+            Span::styled(counter, counter_style),
             Span::raw("  "),
-            Span::styled(
-                "Enter: post  Esc: cancel",
-                Style::default().fg(Color::DarkGray),
-            ),
+            Span::styled(hints, Style::default().fg(Color::DarkGray)),
         ]);
-        frame.render_widget(Paragraph::new(status), chunks[1]);
+        frame.render_widget(Paragraph::new(status), chunks[6]);
     }
 }
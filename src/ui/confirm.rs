@@ -0,0 +1,73 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::ui::Component;
+
+/// Yes/no modal for destructive actions. `y`/Enter dispatches `on_confirm`.
+pub struct ConfirmDialog {
+    title: String,
+    message: String,
+    on_confirm: Action,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<String>, message: impl Into<String>, on_confirm: Action) -> Self {
+        ConfirmDialog {
+            title: title.into(),
+            message: message.into(),
+            on_confirm,
+        }
+    }
+}
+
+impl Component for ConfirmDialog {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => Some(Action::Quit),
+            (_, KeyCode::Char('y')) | (_, KeyCode::Enter) => Some(self.on_confirm.clone()),
+            (_, KeyCode::Char('n')) | (_, KeyCode::Esc) => Some(Action::CloseConfirm),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 50.min(area.width.saturating_sub(4));
+        let modal_height = 8.min(area.height.saturating_sub(4));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", self.title))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(self.message.as_str())
+                .style(Style::default().fg(Color::White))
+                .wrap(Wrap { trim: true }),
+            chunks[0],
+        );
+        frame.render_widget(
+            Paragraph::new("y: confirm  n: cancel")
+                .style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+    }
+}
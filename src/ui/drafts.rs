@@ -0,0 +1,125 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::action::Action;
+use crate::drafts::Draft;
+use crate::ui::Component;
+use crate::utils::time::relative_time;
+
+/// Modal list of saved drafts, opened with `D`.
+pub struct DraftPicker {
+    drafts: Vec<Draft>,
+    selected: usize,
+}
+
+impl DraftPicker {
+    pub fn new() -> Self {
+        DraftPicker {
+            drafts: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub fn set_drafts(&mut self, drafts: Vec<Draft>) {
+        self.drafts = drafts;
+        self.selected = self.selected.min(self.drafts.len().saturating_sub(1));
+    }
+}
+
+impl Component for DraftPicker {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => return Some(Action::Quit),
+            (KeyModifiers::NONE, KeyCode::Esc) => return Some(Action::CloseDrafts),
+            (KeyModifiers::NONE, KeyCode::Char('j')) | (KeyModifiers::NONE, KeyCode::Down) => {
+                self.selected = (self.selected + 1).min(self.drafts.len().saturating_sub(1));
+            }
+            (KeyModifiers::NONE, KeyCode::Char('k')) | (KeyModifiers::NONE, KeyCode::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            (KeyModifiers::NONE, KeyCode::Char('d')) => {
+                return self
+                    .drafts
+                    .get(self.selected)
+                    .map(|d| Action::DeleteDraft(d.key.clone()));
+            }
+            (KeyModifiers::NONE, KeyCode::Enter) => {
+                return self
+                    .drafts
+                    .get(self.selected)
+                    .map(|d| Action::OpenDraft(d.key.clone()));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let rows = (self.drafts.len().max(1) * 2) as u16;
+        let modal_width = 60.min(area.width.saturating_sub(4));
+        let modal_height = (rows + 4).min(area.height.saturating_sub(4));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Drafts ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let mut lines: Vec<Line> = Vec::new();
+        if self.drafts.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No drafts",
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(""));
+        }
+        for (i, draft) in self.drafts.iter().enumerate() {
+            let style = if i == self.selected {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let posts = if draft.segments.len() > 1 {
+                format!("  {} posts", draft.segments.len())
+            } else {
+                String::new()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(draft.target(), style),
+                Span::styled(
+                    format!("  {}{}", relative_time(&draft.updated_at), posts),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            let first_line = draft
+                .segments
+                .first()
+                .and_then(|s| s.lines().find(|l| !l.trim().is_empty()))
+                .unwrap_or("");
+            lines.push(Line::from(Span::styled(
+                format!("  {}", first_line),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k: select  Enter: open  d: delete  Esc: close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -0,0 +1,129 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::action::Action;
+use crate::models::feed::SavedFeed;
+use crate::ui::Component;
+
+struct FeedEntry {
+    id: String,
+    name: String,
+    pinned: bool,
+}
+
+/// Modal list of all saved feeds, opened with `f`, for switching and pinning.
+pub struct FeedPicker {
+    feeds: Vec<FeedEntry>,
+    active: usize,
+    selected: usize,
+}
+
+impl FeedPicker {
+    pub fn new() -> Self {
+        FeedPicker {
+            feeds: Vec::new(),
+            active: 0,
+            selected: 0,
+        }
+    }
+
+    /// Refresh the list, keeping the cursor where it was.
+    pub fn set_feeds(&mut self, feeds: &[SavedFeed], active: usize) {
+        self.feeds = feeds
+            .iter()
+            .map(|f| FeedEntry {
+                id: f.id.clone(),
+                name: f.name.clone(),
+                pinned: f.pinned,
+            })
+            .collect();
+        self.active = active;
+        self.selected = self.selected.min(self.feeds.len().saturating_sub(1));
+    }
+
+    pub fn select_active(&mut self) {
+        self.selected = self.active;
+    }
+}
+
+impl Component for FeedPicker {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => return Some(Action::Quit),
+            (KeyModifiers::NONE, KeyCode::Esc) => return Some(Action::CloseFeedPicker),
+            (KeyModifiers::NONE, KeyCode::Char('j')) | (KeyModifiers::NONE, KeyCode::Down) => {
+                self.selected = (self.selected + 1).min(self.feeds.len().saturating_sub(1));
+            }
+            (KeyModifiers::NONE, KeyCode::Char('k')) | (KeyModifiers::NONE, KeyCode::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            (KeyModifiers::NONE, KeyCode::Char('p')) => {
+                return self
+                    .feeds
+                    .get(self.selected)
+                    .map(|f| Action::ToggleFeedPin(f.id.clone()));
+            }
+            (KeyModifiers::NONE, KeyCode::Enter) => {
+                return self
+                    .feeds
+                    .get(self.selected)
+                    .map(|f| Action::SwitchFeed(f.id.clone()));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 50.min(area.width.saturating_sub(4));
+        let modal_height = (self.feeds.len() as u16 + 4).min(area.height.saturating_sub(4));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(" Feeds ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let mut lines: Vec<Line> = self
+            .feeds
+            .iter()
+            .enumerate()
+            .map(|(i, feed)| {
+                let marker = if i == self.active { "● " } else { "  " };
+                let style = if i == self.selected {
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                let mut spans = vec![
+                    Span::styled(marker, Style::default().fg(Color::Green)),
+                    Span::styled(feed.name.clone(), style),
+                ];
+                if feed.pinned {
+                    spans.push(Span::styled("  pinned", Style::default().fg(Color::Yellow)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k: select  Enter: open  p: pin/unpin  Esc: close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -0,0 +1,72 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::models::follows::{FollowListKind, FollowListState};
+
+pub fn draw_follow_list(frame: &mut Frame, area: Rect, list: Option<&FollowListState>) {
+    let Some(list) = list else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let tab_style = |active: bool| {
+        if active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    let header = vec![
+        Line::from(vec![
+            Span::styled(
+                " Followers ",
+                tab_style(list.kind == FollowListKind::Followers),
+            ),
+            Span::styled(
+                " Following ",
+                tab_style(list.kind == FollowListKind::Follows),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!(" @{}", list.actor_handle),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+
+    if list.actors.loading && list.actors.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Loading...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            chunks[1],
+        );
+        return;
+    }
+
+    if list.actors.is_empty() {
+        let empty = match list.kind {
+            FollowListKind::Followers => "No followers yet.",
+            FollowListKind::Follows => "Not following anyone yet.",
+        };
+        frame.render_widget(
+            Paragraph::new(empty)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            chunks[1],
+        );
+        return;
+    }
+
+    crate::ui::actors::draw_actor_list(
+        frame,
+        chunks[1],
+        &list.actors.items,
+        list.actors.selected,
+        list.actors.loading,
+    );
+}
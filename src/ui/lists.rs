@@ -0,0 +1,401 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::models::lists::{ListDetailState, ListPurpose, ListTab, ListViewModel, ListsState};
+use crate::ui::Component;
+
+const LIST_ROW_HEIGHT: u16 = 3;
+
+struct PickerEntry {
+    list: ListViewModel,
+    /// The `listitem` record while the account is on the list.
+    item_uri: Option<String>,
+    pending: bool,
+}
+
+/// Modal for adding an account to, or removing it from, our lists. Opened with `i`.
+pub struct ListPicker {
+    did: String,
+    handle: String,
+    entries: Vec<PickerEntry>,
+    selected: usize,
+    loading: bool,
+}
+
+impl ListPicker {
+    pub fn new(did: String, handle: String) -> Self {
+        ListPicker {
+            did,
+            handle,
+            entries: Vec::new(),
+            selected: 0,
+            loading: true,
+        }
+    }
+
+    pub fn did(&self) -> &str {
+        &self.did
+    }
+
+    pub fn set_lists(&mut self, lists: Vec<(ListViewModel, Option<String>)>) {
+        self.entries = lists
+            .into_iter()
+            .map(|(list, item_uri)| PickerEntry {
+                list,
+                item_uri,
+                pending: false,
+            })
+            .collect();
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+        self.loading = false;
+    }
+
+    /// The `listitem` URI for `list_uri`, or `None` when not a member. Marks the row pending.
+    pub fn begin_toggle(&mut self, list_uri: &str) -> Option<Option<String>> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|e| e.list.uri == list_uri && !e.pending)?;
+        entry.pending = true;
+        Some(entry.item_uri.clone())
+    }
+
+    /// Record the outcome of a toggle; `None` for `item_uri` means not a member.
+    pub fn set_membership(&mut self, list_uri: &str, item_uri: Option<String>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.list.uri == list_uri) {
+            match (&entry.item_uri, &item_uri) {
+                (None, Some(_)) => entry.list.member_count += 1,
+                (Some(_), None) => {
+                    entry.list.member_count = entry.list.member_count.saturating_sub(1)
+                }
+                _ => {}
+            }
+            entry.item_uri = item_uri;
+            entry.pending = false;
+        }
+    }
+
+    pub fn clear_pending(&mut self, list_uri: &str) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.list.uri == list_uri) {
+            entry.pending = false;
+        }
+    }
+}
+
+impl Component for ListPicker {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => return Some(Action::Quit),
+            (_, KeyCode::Esc) => return Some(Action::CloseListPicker),
+            (KeyModifiers::NONE, KeyCode::Char('j')) | (KeyModifiers::NONE, KeyCode::Down) => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+            }
+            (KeyModifiers::NONE, KeyCode::Char('k')) | (KeyModifiers::NONE, KeyCode::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            (KeyModifiers::NONE, KeyCode::Enter) | (KeyModifiers::NONE, KeyCode::Char(' ')) => {
+                return self
+                    .entries
+                    .get(self.selected)
+                    .map(|e| Action::ToggleListMembership(e.list.uri.clone()));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 56.min(area.width.saturating_sub(4));
+        let rows = self.entries.len().max(1) as u16;
+        let modal_height = (rows + 4).min(area.height.saturating_sub(4));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .title(format!(" Lists for @{} ", self.handle))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let mut lines: Vec<Line> = if self.loading {
+            vec![Line::from(Span::styled(
+                "Loading lists...",
+                Style::default().fg(Color::Yellow),
+            ))]
+        } else if self.entries.is_empty() {
+            vec![Line::from(Span::styled(
+                "You don't have any lists yet.",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            self.entries
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let (marker, marker_style) = match (entry.pending, &entry.item_uri) {
+                        (true, _) => ("… ", Style::default().fg(Color::Yellow)),
+                        (false, Some(_)) => ("✓ ", Style::default().fg(Color::Green)),
+                        (false, None) => ("  ", Style::default()),
+                    };
+                    let style = if i == self.selected {
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+                    let mut spans = vec![
+                        Span::styled(marker, marker_style),
+                        Span::styled(entry.list.name.clone(), style),
+                    ];
+                    if entry.list.purpose == ListPurpose::Moderation {
+                        spans.push(Span::styled("  moderation", Style::default().fg(Color::Yellow)));
+                    }
+                    Line::from(spans)
+                })
+                .collect()
+        };
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "j/k: select  Enter: add/remove  Esc: close",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+/// On the list screens `M` and `B` subscribe to the list rather than its creator.
+pub fn key_to_action(key: KeyEvent) -> Option<Action> {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::SHIFT, KeyCode::Char('M')) => Some(Action::ToggleListMute),
+        (KeyModifiers::SHIFT, KeyCode::Char('B')) => Some(Action::ToggleListBlock),
+        _ => None,
+    }
+}
+
+pub fn draw_lists(frame: &mut Frame, area: Rect, lists: Option<&ListsState>) {
+    let Some(lists) = lists else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let header = vec![
+        Line::from(Span::styled(
+            " Lists",
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(" @{}", lists.actor_handle),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+
+    let area = chunks[1];
+    if lists.lists.loading && lists.lists.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Loading lists...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if lists.lists.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No lists yet.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let visible_rows = (area.height / LIST_ROW_HEIGHT).max(1) as usize;
+    let offset = (lists.lists.selected + 1).saturating_sub(visible_rows);
+
+    let mut y = area.y;
+    for (i, list) in lists.lists.items.iter().enumerate().skip(offset) {
+        if y + LIST_ROW_HEIGHT > area.bottom() {
+            break;
+        }
+        draw_list_row(
+            frame,
+            Rect::new(area.x, y, area.width, LIST_ROW_HEIGHT),
+            list,
+            i == lists.lists.selected,
+        );
+        y += LIST_ROW_HEIGHT;
+    }
+
+    if lists.lists.loading && y < area.bottom() {
+        frame.render_widget(
+            Paragraph::new("Loading more...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y, area.width, 1),
+        );
+    }
+}
+
+fn draw_list_row(frame: &mut Frame, area: Rect, list: &ListViewModel, selected: bool) {
+    let border_style = if selected {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let x = inner.x + 1;
+    let w = inner.width.saturating_sub(1);
+
+    let mut title = vec![Span::styled(
+        list.name.as_str(),
+        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+    )];
+    title.push(Span::styled(
+        format!("  {} · {}", list.purpose.label(), members_label(list.member_count)),
+        Style::default().fg(Color::DarkGray),
+    ));
+    title.extend(subscription_badges(list));
+    frame.render_widget(Paragraph::new(Line::from(title)), Rect::new(x, inner.y, w, 1));
+
+    let description = list.description.lines().next().unwrap_or("");
+    frame.render_widget(
+        Paragraph::new(description).style(Style::default().fg(Color::Gray)),
+        Rect::new(x, inner.y + 1, w, 1),
+    );
+}
+
+fn members_label(count: usize) -> String {
+    format!("{} {}", count, if count == 1 { "member" } else { "members" })
+}
+
+fn subscription_badges(list: &ListViewModel) -> Vec<Span<'static>> {
+    let mut badges = Vec::new();
+    if list.muted {
+        badges.push(Span::styled("  Muting", Style::default().fg(Color::Yellow)));
+    }
+    if list.blocking_uri.is_some() {
+        badges.push(Span::styled("  Blocking", Style::default().fg(Color::Red)));
+    }
+    badges
+}
+
+pub fn draw_list_detail(frame: &mut Frame, area: Rect, detail: Option<&ListDetailState>) {
+    let Some(detail) = detail else {
+        frame.render_widget(
+            Paragraph::new("Loading list...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    };
+    let list = &detail.list;
+
+    let has_description = !list.description.is_empty();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2 + u16::from(has_description)),
+            Constraint::Length(2),
+            Constraint::Min(1),
+        ])
+        .split(area);
+
+    let mut title = vec![Span::styled(
+        format!(" {}", list.name),
+        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+    )];
+    title.extend(subscription_badges(list));
+    let mut header = vec![
+        Line::from(title),
+        Line::from(Span::styled(
+            format!(
+                " {} by @{} · {}",
+                list.purpose.label(),
+                list.creator_handle,
+                members_label(list.member_count)
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    if has_description {
+        header.push(Line::from(Span::styled(
+            format!(" {}", list.description.lines().next().unwrap_or("")),
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    frame.render_widget(Paragraph::new(header).wrap(Wrap { trim: false }), chunks[0]);
+
+    let tab_style = |active: bool| {
+        if active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    let mut tabs = Vec::new();
+    if detail.has_feed() {
+        tabs.push(Span::styled(" Posts ", tab_style(detail.tab == ListTab::Posts)));
+    }
+    tabs.push(Span::styled(" Members ", tab_style(detail.tab == ListTab::Members)));
+    frame.render_widget(
+        Paragraph::new(Line::from(tabs)).block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        chunks[1],
+    );
+
+    match detail.tab {
+        ListTab::Posts => crate::ui::timeline::draw_timeline(frame, chunks[2], &detail.feed),
+        ListTab::Members => {
+            if detail.members.loading && detail.members.is_empty() {
+                frame.render_widget(
+                    Paragraph::new("Loading members...")
+                        .style(Style::default().fg(Color::Yellow))
+                        .alignment(Alignment::Center),
+                    chunks[2],
+                );
+            } else if detail.members.is_empty() {
+                frame.render_widget(
+                    Paragraph::new("This list is empty.")
+                        .style(Style::default().fg(Color::DarkGray))
+                        .alignment(Alignment::Center),
+                    chunks[2],
+                );
+            } else {
+                crate::ui::actors::draw_actor_list(
+                    frame,
+                    chunks[2],
+                    &detail.members.items,
+                    detail.members.selected,
+                    detail.members.loading,
+                );
+            }
+        }
+    }
+}
@@ -17,16 +17,18 @@ pub struct LoginForm {
     focused_field: LoginField,
     error: Option<String>,
     submitting: bool,
+    use_app_password: bool,
 }
 
 impl LoginForm {
-    pub fn new(default_handle: Option<String>) -> Self {
+    pub fn new(default_handle: Option<String>, use_app_password: bool) -> Self {
         LoginForm {
             handle: default_handle.unwrap_or_default(),
             password: String::new(),
             focused_field: LoginField::Handle,
             error: None,
             submitting: false,
+            use_app_password,
         }
     }
 
@@ -39,6 +41,45 @@ impl LoginForm {
         self.error = None;
         self.submitting = false;
     }
+
+    /// Bracketed paste into the focused field, e.g. an app password from a manager.
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.submitting {
+            return;
+        }
+        let text = text.trim();
+        match self.focused_field {
+            LoginField::Handle => self.handle.push_str(text),
+            LoginField::Password => self.password.push_str(text),
+        }
+    }
+
+    fn draw_password_field(&self, frame: &mut Frame, label_area: Rect, field_area: Rect) {
+        let pw_label_style = if self.focused_field == LoginField::Password {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        frame.render_widget(
+            Paragraph::new("App Password:").style(pw_label_style),
+            label_area,
+        );
+
+        let pw_style = if self.focused_field == LoginField::Password {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let pw_display = if self.focused_field == LoginField::Password {
+            format!("{}█", "•".repeat(self.password.len()))
+        } else {
+            "•".repeat(self.password.len())
+        };
+        frame.render_widget(
+            Paragraph::new(pw_display).style(pw_style),
+            field_area,
+        );
+    }
 }
 
 impl Component for LoginForm {
@@ -49,21 +90,37 @@ impl Component for LoginForm {
 
         match (key.modifiers, key.code) {
             (KeyModifiers::CONTROL, KeyCode::Char('c')) => return Some(Action::Quit),
-            (KeyModifiers::NONE, KeyCode::Tab) => {
+            (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
+                self.use_app_password = !self.use_app_password;
+                self.focused_field = LoginField::Handle;
+                self.error = None;
+            }
+            (KeyModifiers::NONE, KeyCode::Tab) if self.use_app_password => {
                 self.focused_field = match self.focused_field {
                     LoginField::Handle => LoginField::Password,
                     LoginField::Password => LoginField::Handle,
                 };
             }
-            (KeyModifiers::NONE, KeyCode::Enter) => {
-                if !self.handle.is_empty() && !self.password.is_empty() {
-                    self.submitting = true;
-                    self.error = None;
-                    return Some(Action::SubmitLogin {
-                        handle: self.handle.clone(),
-                        password: self.password.clone(),
-                    });
-                }
+            (KeyModifiers::NONE, KeyCode::Enter)
+                if !self.use_app_password && !self.handle.is_empty() =>
+            {
+                self.submitting = true;
+                self.error = None;
+                return Some(Action::SubmitOAuthLogin {
+                    handle: self.handle.clone(),
+                });
+            }
+            (KeyModifiers::NONE, KeyCode::Enter)
+                if self.use_app_password
+                    && !self.handle.is_empty()
+                    && !self.password.is_empty() =>
+            {
+                self.submitting = true;
+                self.error = None;
+                return Some(Action::SubmitLogin {
+                    handle: self.handle.clone(),
+                    password: self.password.clone(),
+                });
             }
             (KeyModifiers::NONE, KeyCode::Backspace) => match self.focused_field {
                 LoginField::Handle => {
@@ -121,8 +178,13 @@ impl Component for LoginForm {
             .split(inner);
 
         let title_style = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
+        let title = if self.use_app_password {
+            "Sign in with app password"
+        } else {
+            "Sign in with Bluesky"
+        };
         frame.render_widget(
-            Paragraph::new("Sign in with app password").style(title_style).alignment(Alignment::Center),
+            Paragraph::new(title).style(title_style).alignment(Alignment::Center),
             chunks[0],
         );
 
@@ -151,30 +213,16 @@ impl Component for LoginForm {
             chunks[3],
         );
 
-        let pw_label_style = if self.focused_field == LoginField::Password {
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::Gray)
-        };
-        frame.render_widget(
-            Paragraph::new("App Password:").style(pw_label_style),
-            chunks[5],
-        );
-
-        let pw_style = if self.focused_field == LoginField::Password {
-            Style::default().fg(Color::White)
+        if self.use_app_password {
+            self.draw_password_field(frame, chunks[5], chunks[6]);
         } else {
-            Style::default().fg(Color::DarkGray)
-        };
-        let pw_display = if self.focused_field == LoginField::Password {
-            format!("{}█", "•".repeat(self.password.len()))
-        } else {
-            "•".repeat(self.password.len())
-        };
-        frame.render_widget(
-            Paragraph::new(pw_display).style(pw_style),
-            chunks[6],
-        );
+            frame.render_widget(
+                Paragraph::new("Your browser will open to authorize Skyscraper.")
+                    .style(Style::default().fg(Color::Gray))
+                    .wrap(Wrap { trim: true }),
+                Rect::new(chunks[5].x, chunks[5].y, chunks[5].width, 2),
+            );
+        }
 
         if let Some(ref error) = self.error {
             frame.render_widget(
@@ -184,15 +232,23 @@ impl Component for LoginForm {
                 chunks[8],
             );
         } else if self.submitting {
+            let status = if self.use_app_password {
+                "Signing in..."
+            } else {
+                "Waiting for browser authorization..."
+            };
             frame.render_widget(
-                Paragraph::new("Signing in...")
-                    .style(Style::default().fg(Color::Yellow)),
+                Paragraph::new(status).style(Style::default().fg(Color::Yellow)),
                 chunks[8],
             );
         } else {
+            let hints = if self.use_app_password {
+                "Tab: field  Enter: submit  ^P: OAuth  Esc: quit"
+            } else {
+                "Enter: sign in  ^P: app password  Esc: quit"
+            };
             frame.render_widget(
-                Paragraph::new("Tab: switch fields  Enter: submit  Esc: quit")
-                    .style(Style::default().fg(Color::DarkGray)),
+                Paragraph::new(hints).style(Style::default().fg(Color::DarkGray)),
                 chunks[8],
             );
         }
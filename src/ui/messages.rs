@@ -0,0 +1,421 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use tui_textarea::TextArea;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::action::Action;
+use crate::models::chat::{ConversationState, ConvoListState, ConvoViewModel, MessageViewModel};
+use crate::ui::Component;
+use crate::utils::text::wrapped_line_count;
+use crate::utils::time::relative_time;
+
+/// `chat.bsky.convo.sendMessage` rejects longer messages.
+pub const MAX_MESSAGE_GRAPHEMES: usize = 1000;
+
+const CONVO_ROW_HEIGHT: u16 = 3;
+
+/// Input line at the bottom of an open conversation.
+pub struct MessageInput {
+    textarea: TextArea<'static>,
+}
+
+impl MessageInput {
+    pub fn new(initial: &str) -> Self {
+        let mut textarea = TextArea::new(initial.lines().map(String::from).collect());
+        textarea.set_cursor_line_style(Style::default());
+        textarea.set_placeholder_text("Write a message");
+        textarea.move_cursor(tui_textarea::CursorMove::Bottom);
+        textarea.move_cursor(tui_textarea::CursorMove::End);
+        MessageInput { textarea }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        self.textarea.insert_str(text);
+    }
+
+    fn text(&self) -> String {
+        self.textarea.lines().join("\n")
+    }
+
+    fn length(&self) -> usize {
+        self.text().graphemes(true).count()
+    }
+
+    pub fn height(&self) -> u16 {
+        self.textarea.lines().len().clamp(1, 5) as u16 + 2
+    }
+}
+
+impl Component for MessageInput {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => Some(Action::CloseMessageInput),
+            KeyCode::Enter if !key.modifiers.contains(crossterm::event::KeyModifiers::ALT) => {
+                let text = self.text().trim().to_string();
+                if text.is_empty() || self.length() > MAX_MESSAGE_GRAPHEMES {
+                    return None;
+                }
+                Some(Action::SendMessage(text))
+            }
+            KeyCode::Enter => {
+                self.textarea.insert_newline();
+                None
+            }
+            _ => {
+                self.textarea.input(key);
+                None
+            }
+        }
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let length = self.length();
+        let count_style = if length > MAX_MESSAGE_GRAPHEMES {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let mut textarea = self.textarea.clone();
+        textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(" Message ")
+                .title_bottom(
+                    Line::from(Span::styled(
+                        format!(" {}/{} ", length, MAX_MESSAGE_GRAPHEMES),
+                        count_style,
+                    ))
+                    .right_aligned(),
+                ),
+        );
+        frame.render_widget(&textarea, area);
+    }
+}
+
+/// Keys the Messages tab handles itself, ahead of the global ones.
+pub fn convo_list_key_to_action(key: KeyEvent) -> Option<Action> {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::SHIFT, KeyCode::Char('R')) => Some(Action::RefreshConvos),
+        (KeyModifiers::NONE, KeyCode::Char('m')) => Some(Action::ToggleConvoMute),
+        (KeyModifiers::NONE, KeyCode::Char('x')) => Some(Action::LeaveConvo),
+        _ => None,
+    }
+}
+
+pub fn draw_convo_list(frame: &mut Frame, area: Rect, list: &ConvoListState) {
+    if list.unavailable {
+        frame.render_widget(
+            Paragraph::new("Messages need chat access. Sign in again to allow it.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if list.convos.loading && list.convos.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Loading conversations...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if list.convos.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No conversations yet.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let visible_rows = (area.height / CONVO_ROW_HEIGHT).max(1) as usize;
+    let offset = (list.convos.selected + 1).saturating_sub(visible_rows);
+
+    let mut y = area.y;
+    for (i, convo) in list.convos.items.iter().enumerate().skip(offset) {
+        if y + CONVO_ROW_HEIGHT > area.bottom() {
+            break;
+        }
+        draw_convo_row(
+            frame,
+            Rect::new(area.x, y, area.width, CONVO_ROW_HEIGHT),
+            convo,
+            i == list.convos.selected,
+        );
+        y += CONVO_ROW_HEIGHT;
+    }
+
+    if list.convos.loading && y < area.bottom() {
+        frame.render_widget(
+            Paragraph::new("Loading more...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y, area.width, 1),
+        );
+    }
+}
+
+fn draw_convo_row(frame: &mut Frame, area: Rect, convo: &ConvoViewModel, selected: bool) {
+    let border_style = if selected {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let x = inner.x + 1;
+    let w = inner.width.saturating_sub(1);
+    let unread = convo.unread_count > 0;
+
+    let mut title = vec![Span::styled(
+        convo.title(),
+        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+    )];
+    if let [member] = convo.members.as_slice() {
+        title.push(Span::styled(
+            format!("  @{}", member.handle),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(ref at) = convo.last_message_at {
+        title.push(Span::styled(
+            format!(" · {}", relative_time(at)),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if convo.muted {
+        title.push(Span::styled("  Muted", Style::default().fg(Color::Yellow)));
+    }
+    if unread {
+        title.push(Span::styled(
+            format!("  ● {}", convo.unread_count),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(title)), Rect::new(x, inner.y, w, 1));
+
+    if let Some(ref text) = convo.last_message {
+        let preview = text.lines().next().unwrap_or("");
+        let style = if unread {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        frame.render_widget(
+            Paragraph::new(preview).style(style),
+            Rect::new(x, inner.y + 1, w, 1),
+        );
+    }
+}
+
+/// Replying, opening and composing all write a message in a conversation.
+pub fn conversation_key_to_action(key: KeyEvent) -> Option<Action> {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::NONE, KeyCode::Char('r' | 'n') | KeyCode::Enter) => {
+            Some(Action::ShowMessageInput)
+        }
+        (KeyModifiers::SHIFT, KeyCode::Char('R')) => Some(Action::PollConversation),
+        (KeyModifiers::NONE, KeyCode::Char('m')) => Some(Action::ToggleConvoMute),
+        (KeyModifiers::NONE, KeyCode::Char('x')) => Some(Action::LeaveConvo),
+        _ => None,
+    }
+}
+
+pub fn draw_conversation(
+    frame: &mut Frame,
+    area: Rect,
+    conversation: &ConversationState,
+    own_did: Option<&str>,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let mut header = vec![Span::styled(
+        format!(" {}", conversation.convo.title()),
+        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+    )];
+    if conversation.convo.muted {
+        header.push(Span::styled("  Muted", Style::default().fg(Color::Yellow)));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(header)).block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        chunks[0],
+    );
+
+    let area = chunks[1];
+    let messages = &conversation.messages;
+    if messages.loading && messages.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Loading messages...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if messages.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No messages yet. Press r to say hello.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    // Lay out upwards from the newest message shown, keeping the selection on screen
+    let text_width = area.width.saturating_sub(4).max(1);
+    let heights: Vec<u16> = messages
+        .items
+        .iter()
+        .map(|m| message_height(m, text_width))
+        .collect();
+    let mut last = messages.selected;
+    let mut used: u16 = heights[last];
+    while last + 1 < heights.len() && used + heights[last + 1] <= area.height {
+        last += 1;
+        used += heights[last];
+    }
+    let mut first = messages.selected;
+    while first > 0 && used + heights[first - 1] <= area.height {
+        first -= 1;
+        used += heights[first];
+    }
+
+    let mut y = area.bottom().saturating_sub(used).max(area.y);
+    if first == 0 && messages.cursor.is_some() && y > area.y {
+        let older = if messages.loading {
+            "Loading older messages..."
+        } else {
+            "↑ older messages"
+        };
+        frame.render_widget(
+            Paragraph::new(older)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y - 1, area.width, 1),
+        );
+    }
+    let shown = messages.items[first..=last]
+        .iter()
+        .zip(&heights[first..=last])
+        .enumerate();
+    for (offset, (message, height)) in shown {
+        let i = first + offset;
+        let h = (*height).min(area.bottom().saturating_sub(y));
+        if h == 0 {
+            break;
+        }
+        let own = own_did == Some(message.sender_did.as_str());
+        let sender = if own {
+            "You".to_string()
+        } else {
+            conversation
+                .convo
+                .members
+                .iter()
+                .find(|m| m.did == message.sender_did)
+                .map(|m| m.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string())
+        };
+        draw_message(
+            frame,
+            Rect::new(area.x, y, area.width, h),
+            message,
+            &sender,
+            own,
+            i == messages.selected,
+        );
+        y += h;
+    }
+}
+
+fn message_height(message: &MessageViewModel, text_width: u16) -> u16 {
+    let body = if message.deleted {
+        1
+    } else {
+        wrapped_line_count(&message.text, text_width).max(1)
+    };
+    // Sender line, body, spacer
+    1 + body + 1
+}
+
+fn draw_message(
+    frame: &mut Frame,
+    area: Rect,
+    message: &MessageViewModel,
+    sender: &str,
+    own: bool,
+    selected: bool,
+) {
+    let accent = if own { Color::Cyan } else { Color::Green };
+    let border_style = if selected {
+        Style::default().fg(accent)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    let inner = block.inner(Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    });
+    frame.render_widget(block, Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    });
+    if inner.height == 0 {
+        return;
+    }
+
+    let x = inner.x + 1;
+    let w = inner.width.saturating_sub(1);
+    let header = Line::from(vec![
+        Span::styled(sender.to_string(), Style::default().fg(accent).add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!(" · {}", relative_time(&message.sent_at)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), Rect::new(x, inner.y, w, 1));
+
+    let body_area = Rect::new(x, inner.y + 1, w, inner.height.saturating_sub(1));
+    if message.deleted {
+        frame.render_widget(
+            Paragraph::new("Message deleted").style(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+            body_area,
+        );
+    } else {
+        frame.render_widget(
+            Paragraph::new(message.text.as_str())
+                .style(Style::default().fg(Color::White))
+                .wrap(Wrap { trim: false }),
+            body_area,
+        );
+    }
+}
@@ -1,8 +1,20 @@
 pub mod composer;
+pub mod confirm;
 pub mod about;
+pub mod accounts;
+pub mod actors;
+pub mod drafts;
+pub mod feeds;
+pub mod follows;
+pub mod lists;
 pub mod login;
+pub mod messages;
+pub mod muted_words;
+pub mod notifications;
 pub mod post_widget;
 pub mod profile;
+pub mod report;
+pub mod search;
 pub mod statusbar;
 pub mod tabs;
 pub mod thread;
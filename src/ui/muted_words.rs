@@ -0,0 +1,236 @@
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::models::moderation::MutedWord;
+use crate::ui::Component;
+
+/// Expiry choices offered when muting a word.
+const DURATIONS: [(&str, Option<i64>); 4] = [
+    ("forever", None),
+    ("24 hours", Some(1)),
+    ("7 days", Some(7)),
+    ("30 days", Some(30)),
+];
+
+/// Input for a new muted word, opened with `n` on the muted words screen.
+pub struct MutedWordPrompt {
+    textarea: TextArea<'static>,
+    tags_only: bool,
+    duration: usize,
+    exclude_following: bool,
+}
+
+impl MutedWordPrompt {
+    pub fn new() -> Self {
+        let mut textarea = TextArea::default();
+        textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(" Mute word, phrase or #tag "),
+        );
+        textarea.set_cursor_line_style(Style::default());
+        textarea.set_placeholder_text("e.g. spoilers");
+        MutedWordPrompt {
+            textarea,
+            tags_only: false,
+            duration: 0,
+            exclude_following: false,
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        self.textarea.insert_str(text.replace('\n', " "));
+    }
+}
+
+impl Default for MutedWordPrompt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for MutedWordPrompt {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Esc) => Some(Action::CloseMutedWordPrompt),
+            (_, KeyCode::Enter) => {
+                let value = self.textarea.lines().join(" ").trim().to_string();
+                if value.is_empty() {
+                    return None;
+                }
+                let expires_at = DURATIONS[self.duration]
+                    .1
+                    .map(|days| Utc::now() + Duration::days(days));
+                Some(Action::AddMutedWord(MutedWord {
+                    id: Some(uuid::Uuid::new_v4().to_string()),
+                    value,
+                    tags_only: self.tags_only,
+                    expires_at,
+                    exclude_following: self.exclude_following,
+                }))
+            }
+            (_, KeyCode::Tab) => {
+                self.tags_only = !self.tags_only;
+                None
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('e')) => {
+                self.duration = (self.duration + 1) % DURATIONS.len();
+                None
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('f')) => {
+                self.exclude_following = !self.exclude_following;
+                None
+            }
+            _ => {
+                self.textarea.input(key);
+                None
+            }
+        }
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 70.min(area.width.saturating_sub(4));
+        let modal_height = 6.min(area.height.saturating_sub(2));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: area.y + 3,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(modal_area);
+
+        frame.render_widget(&self.textarea, chunks[0]);
+
+        let option = |key: &'static str, value: String| {
+            vec![
+                Span::styled(format!(" {} ", key), Style::default().fg(Color::Yellow)),
+                Span::styled(value, Style::default().fg(Color::White)),
+            ]
+        };
+        let target = if self.tags_only { "tags only" } else { "text and tags" };
+        let actors = if self.exclude_following {
+            "except people you follow"
+        } else {
+            "everyone"
+        };
+        let lines = vec![
+            Line::from(
+                [
+                    option("Tab", target.to_string()),
+                    option("  Ctrl+E", DURATIONS[self.duration].0.to_string()),
+                ]
+                .concat(),
+            ),
+            Line::from(option("Ctrl+F", actors.to_string())),
+            Line::from(Span::styled(
+                " Enter: mute | Esc: cancel",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).style(Style::default().bg(Color::Black)),
+            chunks[1],
+        );
+    }
+}
+
+/// `r`/`n` add a word and `d` removes the selected one.
+pub fn key_to_action(key: KeyEvent) -> Option<Action> {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::NONE, KeyCode::Char('r' | 'n')) => Some(Action::ShowMutedWordPrompt),
+        (KeyModifiers::NONE, KeyCode::Char('d')) => Some(Action::RemoveMutedWord),
+        _ => None,
+    }
+}
+
+pub fn draw_muted_words(frame: &mut Frame, area: Rect, words: &[MutedWord], selected: usize) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new(" Muted words").style(
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        chunks[0],
+    );
+
+    if words.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No muted words. Press n to add one.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            chunks[1],
+        );
+        return;
+    }
+
+    let area = chunks[1];
+    let visible_rows = area.height.max(1) as usize;
+    let offset = (selected + 1).saturating_sub(visible_rows);
+    for (row, (i, word)) in words
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible_rows)
+        .enumerate()
+    {
+        let marker_style = if i == selected {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let mut details = vec![if word.tags_only { "tags only" } else { "text and tags" }.to_string()];
+        if word.exclude_following {
+            details.push("except people you follow".to_string());
+        }
+        match word.expires_at {
+            Some(_) if word.is_expired() => details.push("expired".to_string()),
+            Some(at) => details.push(format!("expires {}", until(at))),
+            None => {}
+        }
+        let line = Line::from(vec![
+            Span::styled("▌ ", marker_style),
+            Span::styled(
+                word.value.clone(),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", details.join(" · ")),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        frame.render_widget(
+            Paragraph::new(line),
+            Rect::new(area.x, area.y + row as u16, area.width, 1),
+        );
+    }
+}
+
+/// "in 3d" style counterpart to `relative_time`.
+fn until(at: chrono::DateTime<Utc>) -> String {
+    let remaining = at - Utc::now();
+    if remaining.num_days() > 0 {
+        format!("in {}d", remaining.num_days())
+    } else if remaining.num_hours() > 0 {
+        format!("in {}h", remaining.num_hours())
+    } else {
+        format!("in {}m", remaining.num_minutes().max(1))
+    }
+}
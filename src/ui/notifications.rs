@@ -0,0 +1,158 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::models::notification::{NotificationGroup, NotificationReason, NotificationState};
+use crate::utils::text::wrapped_line_count;
+use crate::utils::time::relative_time;
+
+const MAX_SNIPPET_LINES: u16 = 3;
+
+fn group_height(group: &NotificationGroup, width: u16) -> u16 {
+    let text_lines = group
+        .text
+        .as_ref()
+        .map(|t| wrapped_line_count(t, width.saturating_sub(4)).min(MAX_SNIPPET_LINES))
+        .unwrap_or(0);
+    1 + text_lines + 1
+}
+
+fn reason_icon(reason: &NotificationReason) -> (&'static str, Color) {
+    match reason {
+        NotificationReason::Like => ("♥ ", Color::Red),
+        NotificationReason::Repost => ("⟳ ", Color::Green),
+        NotificationReason::Follow => ("+ ", Color::Cyan),
+        NotificationReason::Mention => ("@ ", Color::Blue),
+        NotificationReason::Reply => ("↩ ", Color::Blue),
+        NotificationReason::Quote => ("❝ ", Color::Magenta),
+    }
+}
+
+fn draw_group(frame: &mut Frame, area: Rect, group: &NotificationGroup, selected: bool) {
+    let border_style = if selected {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height == 0 || inner.width == 0 {
+        return;
+    }
+
+    let x = inner.x + 1;
+    let w = inner.width.saturating_sub(1);
+
+    let (icon, color) = reason_icon(&group.reason);
+    let summary_style = if group.is_read {
+        Style::default().fg(Color::White)
+    } else {
+        Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+    };
+    let summary = Line::from(vec![
+        Span::styled(icon, Style::default().fg(color)),
+        Span::styled(group.summary(), summary_style),
+        Span::styled(
+            format!("  {}", relative_time(&group.latest_at)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(summary), Rect::new(x, inner.y, w, 1));
+
+    if let Some(ref text) = group.text {
+        let height = inner.height.saturating_sub(2).min(MAX_SNIPPET_LINES);
+        if height > 0 {
+            frame.render_widget(
+                Paragraph::new(text.as_str())
+                    .style(Style::default().fg(Color::Gray))
+                    .wrap(Wrap { trim: false }),
+                Rect::new(x, inner.y + 1, w, height),
+            );
+        }
+    }
+}
+
+/// Keys the Notifications tab handles itself, ahead of the global ones.
+pub fn key_to_action(key: KeyEvent) -> Option<Action> {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::SHIFT, KeyCode::Char('R')) => Some(Action::RefreshNotifications),
+        _ => None,
+    }
+}
+
+pub fn draw_notifications(frame: &mut Frame, area: Rect, state: &NotificationState) {
+    if state.groups.loading && state.groups.is_empty() {
+        let loading = Paragraph::new("Loading notifications...")
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center);
+        frame.render_widget(loading, area);
+        return;
+    }
+
+    if state.groups.is_empty() {
+        let empty = Paragraph::new("No notifications yet. Press R to refresh.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Virtual scrolling, as in the timeline
+    let visible_height = area.height as usize;
+    let mut selected_start: usize = 0;
+    let mut selected_height: usize = 0;
+    let mut cumulative_height: usize = 0;
+    for (i, group) in state.groups.items.iter().enumerate() {
+        let h = group_height(group, area.width) as usize;
+        if i == state.groups.selected {
+            selected_start = cumulative_height;
+            selected_height = h;
+            break;
+        }
+        cumulative_height += h;
+    }
+
+    let offset = (selected_start + selected_height).saturating_sub(visible_height);
+
+    let mut y = area.y;
+    let max_y = area.bottom();
+    let mut running_height: usize = 0;
+    for (i, group) in state.groups.items.iter().enumerate() {
+        let h = group_height(group, area.width);
+
+        if running_height + h as usize <= offset {
+            running_height += h as usize;
+            continue;
+        }
+
+        if y >= max_y {
+            break;
+        }
+
+        let available_h = (max_y - y).min(h);
+        draw_group(
+            frame,
+            Rect::new(area.x, y, area.width, available_h),
+            group,
+            i == state.groups.selected,
+        );
+
+        y += available_h;
+        running_height += h as usize;
+    }
+
+    if state.groups.loading && y < max_y {
+        frame.render_widget(
+            Paragraph::new("Loading more...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y, area.width, 1),
+        );
+    }
+}
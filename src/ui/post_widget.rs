@@ -1,11 +1,18 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
-use crate::models::post::PostViewModel;
+use crate::models::post::{PostViewModel, QuotedPost};
 use crate::utils::text::{styled_text, wrapped_line_count};
 use crate::utils::time::relative_time;
 
+/// Quoted posts are clipped to this many lines of text.
+const QUOTE_MAX_LINES: u16 = 4;
+
 pub fn post_height(post: &PostViewModel, width: u16) -> u16 {
+    if post.is_collapsed() {
+        // Placeholder line plus padding
+        return 2;
+    }
     let text_width = width.saturating_sub(4);
     let text_lines = wrapped_line_count(&post.text, text_width);
 
@@ -14,19 +21,36 @@ pub fn post_height(post: &PostViewModel, width: u16) -> u16 {
         + 1              // stats line
         + 1;             // bottom border/padding
 
+    if post.is_blurred() {
+        // Author line, warning bar and padding; the body stays hidden
+        height = 3;
+    }
+
     if post.reply_parent_author.is_some() {
         height += 1;
     }
-    if post.reposted_by.is_some() {
+    if post.reposted_by.is_some() || post.pinned {
         height += 1;
     }
+    if post.is_blurred() {
+        return height;
+    }
     if post.embed_summary.is_some() {
         height += 1;
     }
+    if let Some(ref quote) = post.quote {
+        height += quote_height(quote, width);
+    }
 
     height
 }
 
+fn quote_height(quote: &QuotedPost, width: u16) -> u16 {
+    // Indent of 2 plus the quote's own border
+    let text_width = width.saturating_sub(6);
+    1 + wrapped_line_count(&quote.text, text_width).min(QUOTE_MAX_LINES)
+}
+
 pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected: bool) {
     let border_style = if selected {
         Style::default().fg(Color::Cyan)
@@ -45,6 +69,24 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
         return;
     }
 
+    if let Some(ref reason) = post.filtered.as_ref().filter(|_| !post.revealed) {
+        let hint = if post.no_override { "" } else { "  v to show" };
+        let placeholder = Line::from(vec![
+            Span::styled(
+                format!("⊘ Hidden: {}", reason),
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+            Span::styled(hint, Style::default().fg(Color::DarkGray)),
+        ]);
+        frame.render_widget(
+            Paragraph::new(placeholder),
+            Rect::new(inner.x + 1, inner.y, inner.width.saturating_sub(1), 1),
+        );
+        return;
+    }
+
     let mut y = inner.y;
     let x = inner.x + 1;
     let w = inner.width.saturating_sub(1);
@@ -67,6 +109,20 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
         y += 1;
     }
 
+    // Pin indicator
+    if post.pinned {
+        if y >= bottom { return; }
+        let pin_line = Line::from(vec![
+            Span::styled("📌 ", Style::default().fg(Color::Red)),
+            Span::styled("Pinned", Style::default().fg(Color::DarkGray)),
+        ]);
+        frame.render_widget(
+            Paragraph::new(pin_line),
+            Rect::new(x, y, w, 1),
+        );
+        y += 1;
+    }
+
     // Reply indicator
     if let Some(ref parent_author) = post.reply_parent_author {
         if y >= bottom { return; }
@@ -109,17 +165,33 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
     );
     y += 1;
 
+    // Content warning bar in place of the body
+    if let Some(ref warning) = post.content_warning.as_ref().filter(|_| !post.revealed) {
+        if y >= bottom { return; }
+        let label = format!(" ⚠ Content warning: {} · v to show ", warning);
+        let fill = (w as usize).saturating_sub(label.chars().count());
+        let bar = Line::from(vec![
+            Span::styled("▒", Style::default().fg(Color::DarkGray)),
+            Span::styled(label, Style::default().fg(Color::Yellow)),
+            Span::styled("░".repeat(fill), Style::default().fg(Color::DarkGray)),
+        ]);
+        frame.render_widget(Paragraph::new(bar), Rect::new(x, y, w, 1));
+        return;
+    }
+
     // Post text
     if y >= bottom { return; }
     let text_lines = styled_text(&post.text, &post.facets);
     let remaining = bottom.saturating_sub(y);
-    let text_height = remaining.saturating_sub(2).max(1).min(remaining);
+    let quote_rows = post.quote.as_ref().map_or(0, |q| quote_height(q, area.width));
+    let reserved = 1 + post.embed_summary.is_some() as u16 + quote_rows;
+    let text_height = remaining.saturating_sub(reserved).max(1).min(remaining);
     frame.render_widget(
         Paragraph::new(text_lines).wrap(Wrap { trim: false }),
         Rect::new(x, y, w, text_height),
     );
     let wrap_lines = wrapped_line_count(&post.text, w);
-    y += wrap_lines.min(remaining);
+    y += wrap_lines.min(text_height);
 
     // Embed summary
     if let Some(ref embed) = post.embed_summary {
@@ -135,7 +207,6 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
                         crate::models::post::EmbedKind::ExternalLink => "link",
                         crate::models::post::EmbedKind::Video => "video",
                         crate::models::post::EmbedKind::Record => "quote",
-                        crate::models::post::EmbedKind::RecordWithMedia => "quote+media",
                         crate::models::post::EmbedKind::Images(_) => unreachable!(),
                     }),
                 };
@@ -148,6 +219,15 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
         }
     }
 
+    // Quoted post
+    if let Some(ref quote) = post.quote {
+        if y < bottom {
+            let height = quote_rows.min(bottom - y);
+            draw_quote(frame, Rect::new(x + 2, y, w.saturating_sub(2), height), quote);
+            y += height;
+        }
+    }
+
     // Stats line
     if y < bottom {
         let like_style = if post.is_liked {
@@ -168,10 +248,7 @@ pub fn draw_post(frame: &mut Frame, area: Rect, post: &PostViewModel, selected:
             ),
             Span::styled(format!("{}", post.like_count), like_style),
             Span::raw("  "),
-            Span::styled(
-                if post.is_reposted { "⟳ " } else { "⟳ " },
-                repost_style,
-            ),
+            Span::styled("⟳ ", repost_style),
             Span::styled(format!("{}", post.repost_count), repost_style),
             Span::raw("  "),
             Span::styled(
@@ -190,3 +267,38 @@ fn draw_embed_images(frame: &mut Frame, x: u16, y: u16, w: u16, count: usize) {
         Rect::new(x, y, w, 1),
     );
 }
+
+fn draw_quote(frame: &mut Frame, area: Rect, quote: &QuotedPost) {
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height == 0 || inner.width < 2 {
+        return;
+    }
+    let x = inner.x + 1;
+    let w = inner.width - 1;
+
+    let author_line = Line::from(vec![
+        Span::styled(
+            &quote.author_display_name,
+            Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  @{}  {}", quote.author_handle, relative_time(&quote.created_at)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(author_line), Rect::new(x, inner.y, w, 1));
+
+    if inner.height > 1 {
+        frame.render_widget(
+            Paragraph::new(quote.text.as_str())
+                .style(Style::default().fg(Color::Gray))
+                .wrap(Wrap { trim: false }),
+            Rect::new(x, inner.y + 1, w, inner.height - 1),
+        );
+    }
+}
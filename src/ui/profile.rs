@@ -2,7 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use crate::models::feed::FeedState;
-use crate::models::profile::ProfileViewModel;
+use crate::models::profile::{AuthorFeedTab, ProfileViewModel};
 use crate::ui::post_widget;
 
 pub fn draw_profile(
@@ -10,6 +10,8 @@ pub fn draw_profile(
     area: Rect,
     profile: Option<&ProfileViewModel>,
     feed: &FeedState,
+    tab: AuthorFeedTab,
+    own: bool,
 ) {
     let profile = match profile {
         Some(p) => p,
@@ -24,14 +26,21 @@ pub fn draw_profile(
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(7), Constraint::Min(1)])
+        .constraints([
+            Constraint::Length(7),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
         .split(area);
 
     // Profile header
     draw_profile_header(frame, chunks[0], profile);
 
+    // Feed tabs
+    draw_feed_tabs(frame, chunks[1], tab, own);
+
     // Author feed
-    draw_author_feed(frame, chunks[1], feed);
+    draw_author_feed(frame, chunks[2], feed);
 }
 
 fn draw_profile_header(frame: &mut Frame, area: Rect, profile: &ProfileViewModel) {
@@ -63,12 +72,19 @@ fn draw_profile_header(frame: &mut Frame, area: Rect, profile: &ProfileViewModel
         chunks[0],
     );
 
-    // Handle
-    frame.render_widget(
-        Paragraph::new(format!("@{}", profile.handle))
-            .style(Style::default().fg(Color::DarkGray)),
-        chunks[1],
-    );
+    // Handle and relationship
+    let mut handle_line = vec![Span::styled(
+        format!("@{}", profile.handle),
+        Style::default().fg(Color::DarkGray),
+    )];
+    for (label, color) in relationship_badges(profile) {
+        handle_line.push(Span::raw("  "));
+        handle_line.push(Span::styled(
+            format!(" {} ", label),
+            Style::default().fg(Color::Black).bg(color),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(handle_line)), chunks[1]);
 
     // Bio
     if !profile.description.is_empty() {
@@ -107,6 +123,42 @@ fn draw_profile_header(frame: &mut Frame, area: Rect, profile: &ProfileViewModel
     frame.render_widget(Paragraph::new(stats), chunks[3]);
 }
 
+fn draw_feed_tabs(frame: &mut Frame, area: Rect, active: AuthorFeedTab, own: bool) {
+    let spans: Vec<Span> = AuthorFeedTab::available(own)
+        .iter()
+        .map(|&tab| {
+            let style = if tab == active {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Span::styled(format!(" {} ", tab.title()), style)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn relationship_badges(profile: &ProfileViewModel) -> Vec<(&'static str, Color)> {
+    let viewer = &profile.viewer;
+    let mut badges = Vec::new();
+    if viewer.blocking {
+        badges.push(("Blocked", Color::Red));
+    }
+    if viewer.blocked_by {
+        badges.push(("Blocks you", Color::Red));
+    }
+    if viewer.muted {
+        badges.push(("Muted", Color::Yellow));
+    }
+    if viewer.following {
+        badges.push(("Following", Color::Cyan));
+    }
+    if viewer.followed_by {
+        badges.push(("Follows you", Color::Gray));
+    }
+    badges
+}
+
 fn draw_author_feed(frame: &mut Frame, area: Rect, feed: &FeedState) {
     if feed.loading && feed.posts.is_empty() {
         let loading = Paragraph::new("Loading posts...")
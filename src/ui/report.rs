@@ -0,0 +1,260 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tui_textarea::TextArea;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::action::Action;
+use crate::models::moderation::{ModerationService, Report, ReportSubject, REPORT_REASONS};
+use crate::ui::Component;
+
+/// `com.atproto.moderation.createReport` rejects longer comments.
+const MAX_COMMENT_GRAPHEMES: usize = 2000;
+
+/// Report modal for a post or an account, opened with `!`.
+pub struct ReportDialog {
+    /// The selected post and its author, or just the profile's account.
+    subjects: Vec<ReportSubject>,
+    subject: usize,
+    services: Vec<ModerationService>,
+    service: usize,
+    /// Index into `reasons()`.
+    reason: usize,
+    comment: TextArea<'static>,
+    sending: bool,
+    sent: bool,
+    error: Option<String>,
+}
+
+impl ReportDialog {
+    pub fn new(subjects: Vec<ReportSubject>, mut services: Vec<ModerationService>) -> Self {
+        if services.is_empty() {
+            services.push(ModerationService::bluesky());
+        }
+        let mut comment = TextArea::default();
+        comment.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(" Details (optional) "),
+        );
+        comment.set_cursor_line_style(Style::default());
+        comment.set_placeholder_text("Anything the moderators should know");
+        ReportDialog {
+            subjects,
+            subject: 0,
+            services,
+            service: 0,
+            reason: 0,
+            comment,
+            sending: false,
+            sent: false,
+            error: None,
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        if !self.sending && !self.sent {
+            self.comment.insert_str(text);
+        }
+    }
+
+    pub fn set_sent(&mut self) {
+        self.sending = false;
+        self.sent = true;
+    }
+
+    pub fn set_failed(&mut self, error: String) {
+        self.sending = false;
+        self.error = Some(error);
+    }
+
+    /// Reasons the selected service reviews, falling back to all of them.
+    fn reasons(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        let service = &self.services[self.service];
+        let accepted: Vec<_> = REPORT_REASONS
+            .into_iter()
+            .filter(|(reason_type, _, _)| service.accepts(reason_type))
+            .collect();
+        if accepted.is_empty() {
+            REPORT_REASONS.to_vec()
+        } else {
+            accepted
+        }
+    }
+
+    fn comment_text(&self) -> String {
+        self.comment.lines().join("\n").trim().to_string()
+    }
+
+    fn report(&self) -> Option<Report> {
+        let subject = self.subjects.get(self.subject)?.clone();
+        let (reason_type, _, _) = *self.reasons().get(self.reason)?;
+        let comment = self.comment_text();
+        Some(Report {
+            subject,
+            reason_type: reason_type.to_string(),
+            comment: (!comment.is_empty()).then_some(comment),
+            service_did: self.services[self.service].did.clone(),
+        })
+    }
+}
+
+impl Component for ReportDialog {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if self.sent {
+            return Some(Action::CloseReport);
+        }
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Esc) => Some(Action::CloseReport),
+            _ if self.sending => None,
+            (_, KeyCode::Enter) => {
+                if self.comment_text().graphemes(true).count() > MAX_COMMENT_GRAPHEMES {
+                    return None;
+                }
+                let report = self.report()?;
+                self.sending = true;
+                self.error = None;
+                Some(Action::SubmitReport(report))
+            }
+            (_, KeyCode::Up) => {
+                self.reason = self.reason.saturating_sub(1);
+                None
+            }
+            (_, KeyCode::Down) => {
+                self.reason = (self.reason + 1).min(self.reasons().len().saturating_sub(1));
+                None
+            }
+            (_, KeyCode::Tab) => {
+                self.subject = (self.subject + 1) % self.subjects.len().max(1);
+                None
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('l')) => {
+                self.service = (self.service + 1) % self.services.len();
+                self.reason = self.reason.min(self.reasons().len().saturating_sub(1));
+                None
+            }
+            _ => {
+                self.comment.input(key);
+                None
+            }
+        }
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let reasons = self.reasons();
+        let modal_width = 70.min(area.width.saturating_sub(4));
+        let modal_height = (reasons.len() as u16 + 11).min(area.height.saturating_sub(2));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: (area.height.saturating_sub(modal_height)) / 2 + area.y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let subject = self
+            .subjects
+            .get(self.subject)
+            .map_or_else(String::new, |s| s.describe());
+        let block = Block::default()
+            .title(format!(" Report {} ", subject))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        if self.sent {
+            let thanks = vec![
+                Line::from(Span::styled(
+                    "Report sent",
+                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!("Thanks. {} will review it.", self.services[self.service].name),
+                    Style::default().fg(Color::White),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "Press any key to close",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ];
+            frame.render_widget(
+                Paragraph::new(thanks).alignment(Alignment::Center),
+                inner,
+            );
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(reasons.len() as u16 + 1),
+                Constraint::Min(3),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let mut lines = vec![Line::from(Span::styled(
+            "Why should this be reviewed?",
+            Style::default().fg(Color::Gray),
+        ))];
+        for (i, (_, name, description)) in reasons.iter().enumerate() {
+            let selected = i == self.reason;
+            let name_style = if selected {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(if selected { "▸ " } else { "  " }, name_style),
+                Span::styled(*name, name_style),
+                Span::styled(format!("  {}", description), Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        let length = self.comment_text().graphemes(true).count();
+        let mut comment = self.comment.clone();
+        if length > MAX_COMMENT_GRAPHEMES {
+            comment.set_block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red))
+                    .title(format!(" Details {}/{} ", length, MAX_COMMENT_GRAPHEMES)),
+            );
+        }
+        frame.render_widget(&comment, chunks[1]);
+
+        let mut service = vec![
+            Span::styled("Send to ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                self.services[self.service].name.clone(),
+                Style::default().fg(Color::White),
+            ),
+        ];
+        if self.services.len() > 1 {
+            service.push(Span::styled("  Ctrl+L: change", Style::default().fg(Color::DarkGray)));
+        }
+        frame.render_widget(Paragraph::new(Line::from(service)), chunks[2]);
+
+        let status = if let Some(ref error) = self.error {
+            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red)))
+        } else if self.sending {
+            Line::from(Span::styled("Sending report...", Style::default().fg(Color::Yellow)))
+        } else {
+            let mut hints = String::from("↑/↓: reason | Enter: send | Esc: cancel");
+            if self.subjects.len() > 1 {
+                hints.push_str(" | Tab: post / account");
+            }
+            Line::from(Span::styled(hints, Style::default().fg(Color::DarkGray)))
+        };
+        frame.render_widget(Paragraph::new(status), chunks[3]);
+    }
+}
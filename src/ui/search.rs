@@ -0,0 +1,172 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::models::search::{SearchMode, SearchSort, SearchState};
+use crate::ui::timeline::draw_feed_posts;
+use crate::ui::Component;
+
+/// One-line search input, opened with `/`.
+pub struct SearchPrompt {
+    textarea: TextArea<'static>,
+}
+
+impl SearchPrompt {
+    pub fn new(initial: &str) -> Self {
+        let mut textarea = TextArea::new(vec![initial.to_string()]);
+        textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(" Search "),
+        );
+        textarea.set_cursor_line_style(Style::default());
+        textarea.set_placeholder_text("Search posts and people");
+        textarea.move_cursor(tui_textarea::CursorMove::End);
+        SearchPrompt { textarea }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) {
+        self.textarea.insert_str(text.replace('\n', " "));
+    }
+}
+
+impl Component for SearchPrompt {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => Some(Action::CloseSearchPrompt),
+            KeyCode::Enter => {
+                let input = self.textarea.lines().join(" ").trim().to_string();
+                if input.is_empty() {
+                    return None;
+                }
+                Some(Action::SubmitSearch(input))
+            }
+            _ => {
+                self.textarea.input(key);
+                None
+            }
+        }
+    }
+
+    fn update(&mut self, _action: &Action) {}
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let modal_width = 70.min(area.width.saturating_sub(4));
+        let modal_height = 4.min(area.height.saturating_sub(2));
+        let modal_area = Rect {
+            x: (area.width.saturating_sub(modal_width)) / 2 + area.x,
+            y: area.y + 3,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        frame.render_widget(Clear, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(modal_area);
+
+        frame.render_widget(&self.textarea, chunks[0]);
+        frame.render_widget(
+            Paragraph::new(" from:handle  since:YYYY-MM-DD  until:YYYY-MM-DD  lang:en")
+                .style(Style::default().fg(Color::DarkGray).bg(Color::Black)),
+            chunks[1],
+        );
+    }
+}
+
+pub fn draw_search(frame: &mut Frame, area: Rect, search: &SearchState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let tab_style = |active: bool| {
+        if active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    let sort = match search.sort {
+        SearchSort::Top => "Top",
+        SearchSort::Latest => "Latest",
+    };
+    let header = vec![
+        Line::from(vec![
+            Span::styled(
+                format!(" Posts ({}) ", sort),
+                tab_style(search.mode == SearchMode::Posts),
+            ),
+            Span::styled(" People ", tab_style(search.mode == SearchMode::People)),
+        ]),
+        Line::from(Span::styled(
+            format!(" \"{}\"", search.input),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+
+    match search.mode {
+        SearchMode::Posts => draw_post_results(frame, chunks[1], search),
+        SearchMode::People => draw_actor_results(frame, chunks[1], search),
+    }
+}
+
+fn draw_post_results(frame: &mut Frame, area: Rect, search: &SearchState) {
+    if search.posts.loading && search.posts.posts.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Searching...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if search.posts.posts.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No posts found.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    draw_feed_posts(frame, area, &search.posts);
+}
+
+fn draw_actor_results(frame: &mut Frame, area: Rect, search: &SearchState) {
+    if search.actors.loading && search.actors.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Searching...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    if search.actors.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No people found.")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    crate::ui::actors::draw_actor_list(
+        frame,
+        area,
+        &search.actors.items,
+        search.actors.selected,
+        search.actors.loading,
+    );
+}
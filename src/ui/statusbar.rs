@@ -18,18 +18,42 @@ pub fn draw_statusbar(
     }
 
     let hints = if in_composer {
-        "Enter: post | Esc: cancel"
+        "Enter: post | Ctrl+L: add to thread | Ctrl+Z: split long post | Ctrl+Q: $EDITOR | Esc: cancel"
     } else {
         match screen {
-            Screen::Login => "Tab: switch fields | Enter: login | Esc: quit",
+            Screen::Login => "Tab: switch fields | Enter: login | Ctrl+P: OAuth / app password | Esc: quit",
             Screen::Timeline => {
-                "j/k: navigate | Enter: thread | n: post | r: reply | l: like | t: repost | R: refresh | a: about | q: quit"
+                "j/k: navigate | Enter: thread | n: post | D: drafts | r: reply | Q: quote | l: like | t: repost | [/]: feed | f: feeds | R: refresh | a: about | q: quit"
             }
             Screen::Thread => {
-                "Esc: back | r: reply | l: like | t: repost | u: profile | a: about | q: quit"
+                "j/k: navigate | space: expand/collapse | Esc: back | r: reply | Q: quote | l: like | t: repost | d: delete | u: profile | a: about | q: quit"
             }
             Screen::Profile => {
-                "j/k: navigate | Enter: thread | Esc: back | a: about | q: quit"
+                "j/k: navigate | Enter: thread | [/]: tab | o/O: followers/following | F: follow | M: mute | B: block | L: lists | !: report | Esc: back | q: quit"
+            }
+            Screen::FollowList => {
+                "j/k: navigate | Enter: profile | o: followers | O: following | Esc: back | q: quit"
+            }
+            Screen::Lists => {
+                "j/k: navigate | Enter: open | M: mute list | B: block list | Esc: back | q: quit"
+            }
+            Screen::ListDetail => {
+                "j/k: navigate | Enter: open | [/]: posts/members | M: mute list | B: block list | i: add to list | Esc: back"
+            }
+            Screen::Notifications => {
+                "j/k: navigate | Enter: open | u: profile | R: refresh | a: about | q: quit"
+            }
+            Screen::Search => {
+                "j/k: navigate | Enter: open | Tab: posts/people | s: top/latest | /: new search | u: profile | Esc: back"
+            }
+            Screen::Messages => {
+                "j/k: navigate | Enter: open | m: mute | x: leave | R: refresh | a: about | q: quit"
+            }
+            Screen::Conversation => {
+                "j/k: scroll | r: write | Enter: send | Alt+Enter: newline | m: mute | x: leave | Esc: back"
+            }
+            Screen::MutedWords => {
+                "j/k: navigate | n: mute a word | d: unmute | Esc: back | q: quit"
             }
             Screen::About => {
                 "Esc: back | Enter: open App Store | q: quit"
@@ -1,13 +1,39 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Tabs as RatatuiTabs};
 
-pub fn draw_tabs(frame: &mut Frame, area: Rect, active: usize) {
-    let titles = vec!["[1] Timeline", "[2] Profile"];
+pub fn draw_tabs(
+    frame: &mut Frame,
+    area: Rect,
+    active: usize,
+    handle: Option<&str>,
+    unread: i64,
+    unread_messages: i64,
+) {
+    let notifications = if unread > 0 {
+        format!("[3] Notifications ({})", unread)
+    } else {
+        "[3] Notifications".to_string()
+    };
+    let messages = if unread_messages > 0 {
+        format!("[4] Messages ({})", unread_messages)
+    } else {
+        "[4] Messages".to_string()
+    };
+    let titles = vec![
+        "[1] Timeline".to_string(),
+        "[2] Profile".to_string(),
+        notifications,
+        messages,
+    ];
+    let title = match handle {
+        Some(handle) => format!(" Skyscraper · @{} ", handle),
+        None => " Skyscraper ".to_string(),
+    };
     let tabs = RatatuiTabs::new(titles)
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .title(" Skyscraper "),
+                .title(title),
         )
         .select(active)
         .style(Style::default().fg(Color::DarkGray))
@@ -1,10 +1,37 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
-use crate::models::thread::ThreadViewModel;
+use crate::models::thread::{ReplyNode, ThreadPost, ThreadViewModel};
 use crate::ui::post_widget;
 
-pub fn draw_thread(frame: &mut Frame, area: Rect, thread: Option<&ThreadViewModel>) {
+/// Deeper replies keep this indent so they don't run out of width.
+const MAX_INDENT_LEVELS: usize = 8;
+const GUIDE_WIDTH: u16 = 2;
+
+enum Row<'a> {
+    Parent(&'a ThreadPost),
+    Connector,
+    Focal,
+    Separator,
+    Header,
+    Reply {
+        index: usize,
+        /// Per shown level, whether a later sibling at that level still follows.
+        guides: Vec<bool>,
+    },
+}
+
+pub fn draw_thread(
+    frame: &mut Frame,
+    area: Rect,
+    thread: Option<&ThreadViewModel>,
+    error: Option<&str>,
+) {
+    if let Some(error) = error {
+        draw_unavailable_thread(frame, area, error);
+        return;
+    }
+
     let thread = match thread {
         Some(t) => t,
         None => {
@@ -16,67 +43,250 @@ pub fn draw_thread(frame: &mut Frame, area: Rect, thread: Option<&ThreadViewMode
         }
     };
 
+    let mut rows: Vec<(Row, u16)> = Vec::new();
+    for parent in &thread.parents {
+        rows.push((Row::Parent(parent), slot_height(parent, area.width)));
+        rows.push((Row::Connector, 1));
+    }
+    rows.push((Row::Focal, post_widget::post_height(&thread.focal, area.width)));
+    rows.push((Row::Separator, 1));
+    if !thread.replies.is_empty() {
+        rows.push((Row::Header, 1));
+    }
+    for (index, guides) in reply_guides(thread) {
+        let node = &thread.replies[index];
+        let indent = guides.len() as u16 * GUIDE_WIDTH;
+        let h = slot_height(&node.post, area.width.saturating_sub(indent))
+            + u16::from(node_footer(thread, index).is_some());
+        rows.push((Row::Reply { index, guides }, h));
+    }
+
+    // Scroll whole rows until the selected post fits
+    let selected_row = rows
+        .iter()
+        .position(|(row, _)| match (row, thread.selected) {
+            (Row::Focal, None) => true,
+            (Row::Reply { index, .. }, Some(s)) => *index == s,
+            _ => false,
+        })
+        .unwrap_or(0);
+    let mut start = 0;
+    while start < selected_row
+        && rows[start..=selected_row]
+            .iter()
+            .map(|(_, h)| *h as usize)
+            .sum::<usize>()
+            > area.height as usize
+    {
+        start += 1;
+    }
+
     let mut y = area.y;
     let max_y = area.bottom();
 
-    // Parent chain
-    for parent in &thread.parents {
+    for (row, height) in &rows[start..] {
         if y >= max_y {
             break;
         }
-        let h = post_widget::post_height(parent, area.width).min(max_y - y);
-        let post_area = Rect::new(area.x, y, area.width, h);
-        post_widget::draw_post(frame, post_area, parent, false);
+        let h = (*height).min(max_y - y);
+        match row {
+            Row::Parent(parent) => {
+                let post_area = Rect::new(area.x, y, area.width, h);
+                draw_slot(frame, post_area, parent, false);
+            }
+            Row::Connector => {
+                let connector = Paragraph::new("│")
+                    .style(Style::default().fg(Color::DarkGray));
+                frame.render_widget(connector, Rect::new(area.x + 1, y, 1, 1));
+            }
+            Row::Focal => {
+                let post_area = Rect::new(area.x, y, area.width, h);
+                post_widget::draw_post(frame, post_area, &thread.focal, thread.selected.is_none());
+            }
+            Row::Separator => {
+                let sep = Block::default()
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(Color::DarkGray));
+                frame.render_widget(sep, Rect::new(area.x, y, area.width, 1));
+            }
+            Row::Header => {
+                let count = thread.replies.len();
+                let header = Paragraph::new(format!(
+                    " {} {}",
+                    count,
+                    if count == 1 { "reply" } else { "replies" }
+                ))
+                .style(Style::default().fg(Color::Gray));
+                frame.render_widget(header, Rect::new(area.x, y, area.width, 1));
+            }
+            Row::Reply { index, guides } => {
+                draw_reply(
+                    frame,
+                    Rect::new(area.x, y, area.width, h),
+                    thread,
+                    *index,
+                    guides,
+                );
+            }
+        }
         y += h;
+    }
+}
 
-        // Thread connector
-        if y < max_y {
-            let connector = Paragraph::new("│")
-                .style(Style::default().fg(Color::DarkGray));
-            frame.render_widget(connector, Rect::new(area.x + 1, y, 1, 1));
-            y += 1;
+/// Visible replies with their branch guides, computed bottom-up so each level knows
+/// whether a sibling is still to come.
+fn reply_guides(thread: &ThreadViewModel) -> Vec<(usize, Vec<bool>)> {
+    let visible = thread.visible_replies();
+    let max_depth = visible
+        .iter()
+        .map(|&i| thread.replies[i].depth)
+        .max()
+        .unwrap_or(0);
+    let mut open = vec![false; max_depth + 1];
+    let mut out = Vec::with_capacity(visible.len());
+    for &i in visible.iter().rev() {
+        let depth = thread.replies[i].depth;
+        let shown = depth.min(MAX_INDENT_LEVELS);
+        let guides = open[depth + 1 - shown..=depth].to_vec();
+        open[depth] = true;
+        for level in open.iter_mut().skip(depth + 1) {
+            *level = false;
         }
+        out.push((i, guides));
     }
+    out.reverse();
+    out
+}
 
-    // Focal post (highlighted)
-    if y < max_y {
-        let h = post_widget::post_height(&thread.focal, area.width).min(max_y - y);
-        let post_area = Rect::new(area.x, y, area.width, h);
-        post_widget::draw_post(frame, post_area, &thread.focal, true);
-        y += h;
+fn node_footer(thread: &ThreadViewModel, index: usize) -> Option<String> {
+    let node: &ReplyNode = &thread.replies[index];
+    if node.collapsed {
+        let hidden = thread.descendant_count(index);
+        return Some(format!(
+            "▸ {} hidden {} (space to expand)",
+            hidden,
+            if hidden == 1 { "reply" } else { "replies" }
+        ));
+    }
+    if node.loading {
+        return Some("Loading replies...".to_string());
+    }
+    if node.has_more {
+        let count = node.post.post().map_or(0, |p| p.reply_count);
+        return Some(format!(
+            "▸ Load {} more {} (space)",
+            count,
+            if count == 1 { "reply" } else { "replies" }
+        ));
     }
+    None
+}
 
-    // Separator
-    if y < max_y {
-        let sep = Block::default()
-            .borders(Borders::TOP)
-            .border_style(Style::default().fg(Color::DarkGray));
-        frame.render_widget(sep, Rect::new(area.x, y, area.width, 1));
-        y += 1;
+fn slot_height(slot: &ThreadPost, width: u16) -> u16 {
+    match slot {
+        ThreadPost::Post(post) => post_widget::post_height(post, width),
+        ThreadPost::Unavailable { .. } => 1,
     }
+}
 
-    // Replies header
-    if y < max_y && !thread.replies.is_empty() {
-        let header = Paragraph::new(format!(
-            " {} {}",
-            thread.replies.len(),
-            if thread.replies.len() == 1 { "reply" } else { "replies" }
-        ))
-        .style(Style::default().fg(Color::Gray));
-        frame.render_widget(header, Rect::new(area.x, y, area.width, 1));
-        y += 1;
+/// A post, or a one-line placeholder keeping its place in the conversation.
+fn draw_slot(frame: &mut Frame, area: Rect, slot: &ThreadPost, selected: bool) {
+    let reason = match slot {
+        ThreadPost::Post(post) => {
+            post_widget::draw_post(frame, area, post, selected);
+            return;
+        }
+        ThreadPost::Unavailable { reason, .. } => reason,
+    };
+    let border_style = if selected {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(border_style);
+    frame.render_widget(
+        Paragraph::new(format!(" ⊘ {}", reason.label()))
+            .style(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            )
+            .block(block),
+        area,
+    );
+}
+
+fn draw_unavailable_thread(frame: &mut Frame, area: Rect, error: &str) {
+    let [_, message_area] =
+        Layout::vertical([Constraint::Length(area.height / 3), Constraint::Min(1)]).areas(area);
+    let message = vec![
+        Line::from(Span::styled(
+            "Thread unavailable",
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(error, Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(Span::styled("Esc to go back", Style::default().fg(Color::DarkGray))),
+    ];
+    frame.render_widget(
+        Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true }),
+        message_area,
+    );
+}
+
+fn draw_reply(
+    frame: &mut Frame,
+    area: Rect,
+    thread: &ThreadViewModel,
+    index: usize,
+    guides: &[bool],
+) {
+    let guide_style = Style::default().fg(Color::DarkGray);
+    let levels = guides.len();
+    for row in 0..area.height {
+        let mut line = String::new();
+        for (level, &continues) in guides.iter().enumerate() {
+            let own = level + 1 == levels;
+            line.push_str(match (own, row == 0, continues) {
+                (true, true, true) => "├─",
+                (true, true, false) => "└─",
+                (_, _, true) => "│ ",
+                (_, _, false) => "  ",
+            });
+        }
+        frame.render_widget(
+            Paragraph::new(line).style(guide_style),
+            Rect::new(area.x, area.y + row, area.width, 1),
+        );
     }
 
-    // Replies
-    for reply in &thread.replies {
-        if y >= max_y {
-            break;
+    let indent = levels as u16 * GUIDE_WIDTH;
+    let content = Rect::new(
+        area.x + indent,
+        area.y,
+        area.width.saturating_sub(indent),
+        area.height,
+    );
+    let footer = node_footer(thread, index);
+    let post_h = content.height.saturating_sub(u16::from(footer.is_some()));
+    let node = &thread.replies[index];
+    draw_slot(
+        frame,
+        Rect::new(content.x, content.y, content.width, post_h),
+        &node.post,
+        thread.selected == Some(index),
+    );
+    if let Some(footer) = footer {
+        if post_h < content.height {
+            frame.render_widget(
+                Paragraph::new(format!("  {}", footer)).style(Style::default().fg(Color::Cyan)),
+                Rect::new(content.x, content.y + post_h, content.width, 1),
+            );
         }
-        let indented_x = area.x + 2;
-        let indented_w = area.width.saturating_sub(2);
-        let h = post_widget::post_height(reply, indented_w).min(max_y - y);
-        let reply_area = Rect::new(indented_x, y, indented_w, h);
-        post_widget::draw_post(frame, reply_area, reply, false);
-        y += h;
     }
 }
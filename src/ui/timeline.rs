@@ -1,7 +1,7 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
-use crate::models::feed::FeedState;
+use crate::models::feed::{FeedState, SavedFeed};
 use crate::ui::post_widget;
 
 pub fn draw_timeline(frame: &mut Frame, area: Rect, feed: &FeedState) {
@@ -22,6 +22,11 @@ pub fn draw_timeline(frame: &mut Frame, area: Rect, feed: &FeedState) {
         return;
     }
 
+    draw_feed_posts(frame, area, feed);
+}
+
+/// Render a non-empty feed with virtual scrolling, plus a "Loading more..." footer.
+pub fn draw_feed_posts(frame: &mut Frame, area: Rect, feed: &FeedState) {
     // Virtual scrolling: only render visible posts
     let mut y = area.y;
     let max_y = area.bottom();
@@ -78,14 +83,33 @@ pub fn draw_timeline(frame: &mut Frame, area: Rect, feed: &FeedState) {
     }
 
     // Loading indicator at bottom
-    if feed.loading {
-        if y < max_y {
-            frame.render_widget(
-                Paragraph::new("Loading more...")
-                    .style(Style::default().fg(Color::Yellow))
-                    .alignment(Alignment::Center),
-                Rect::new(area.x, y, area.width, 1),
-            );
+    if feed.loading && y < max_y {
+        frame.render_widget(
+            Paragraph::new("Loading more...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center),
+            Rect::new(area.x, y, area.width, 1),
+        );
+    }
+}
+
+/// One-line strip of pinned feeds above the timeline; `[`/`]` cycle through them.
+pub fn draw_feed_bar(frame: &mut Frame, area: Rect, feeds: &[SavedFeed], active: usize) {
+    let mut spans = Vec::new();
+    for (i, feed) in feeds.iter().enumerate() {
+        if !feed.pinned && i != active {
+            continue;
         }
+        let style = if i == active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        spans.push(Span::styled(format!(" {} ", feed.name), style));
     }
+    spans.push(Span::styled(
+        "  [ ] switch · f all feeds",
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
 }
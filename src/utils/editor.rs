@@ -0,0 +1,45 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Result};
+
+/// Open `text` in `$VISUAL` or `$EDITOR` (falling back to `vi`) and return what
+/// was saved. The caller must hand over the terminal first.
+pub fn edit(text: &str) -> Result<String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+
+    let path = std::env::temp_dir().join(format!("skyscraper-{}.txt", uuid::Uuid::new_v4()));
+    write_private(&path, text).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+
+    // Allow editors with arguments, e.g. `code --wait`
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = Command::new(program).args(parts).arg(&path).status();
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let status = status.map_err(|e| anyhow!("Could not start {}: {}", program, e))?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}; draft left unchanged", program, status));
+    }
+    let edited = edited.map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+    Ok(edited.trim_end().to_string())
+}
+
+/// Create `path` readable only by us; the temp dir is shared and drafts may be
+/// unsent replies or messages.
+fn write_private(path: &Path, text: &str) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(text.as_bytes())
+}
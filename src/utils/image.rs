@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use imagesize::ImageType;
+
+use crate::api::client::ImageAttachment;
+
+/// Largest blob `app.bsky.embed.images` accepts.
+pub const MAX_IMAGE_BYTES: usize = 1_000_000;
+pub const MAX_IMAGES: usize = 4;
+
+/// Turn typed or pasted input into a path. Terminals deliver drag-and-drop as a
+/// quoted, backslash-escaped or `file://` string.
+pub fn parse_path(input: &str) -> PathBuf {
+    let input = input.trim();
+    let input = input
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| input.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+        .unwrap_or(input);
+    let input = input.strip_prefix("file://").unwrap_or(input);
+    let unescaped = input.replace("\\ ", " ");
+
+    match unescaped.strip_prefix("~/") {
+        Some(rest) => std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .unwrap_or_default()
+            .join(rest),
+        None => PathBuf::from(unescaped),
+    }
+}
+
+/// Whether pasted text looks like a path to an image file on disk.
+pub fn is_image_path(input: &str) -> bool {
+    let path = parse_path(input);
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| {
+                matches!(
+                    e.to_ascii_lowercase().as_str(),
+                    "jpg" | "jpeg" | "png" | "gif" | "webp"
+                )
+            })
+            .unwrap_or(false)
+}
+
+/// Read an image, sniffing its type from the file header and checking it fits
+/// within Bluesky's blob limit.
+pub fn load_image(path: &Path) -> Result<ImageAttachment> {
+    let data = std::fs::read(path).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+
+    // Only checked here; the PDS sniffs the type of uploaded blobs itself
+    if !matches!(
+        imagesize::image_type(&data),
+        Ok(ImageType::Jpeg | ImageType::Png | ImageType::Gif | ImageType::Webp)
+    ) {
+        return Err(anyhow!(
+            "{} is not a JPEG, PNG, GIF or WebP image",
+            path.display()
+        ));
+    }
+
+    if data.len() > MAX_IMAGE_BYTES {
+        return Err(anyhow!(
+            "{} is {} KB; images must be under {} KB",
+            path.display(),
+            data.len() / 1000,
+            MAX_IMAGE_BYTES / 1000
+        ));
+    }
+
+    let size = imagesize::blob_size(&data)
+        .map_err(|e| anyhow!("Could not read dimensions of {}: {}", path.display(), e))?;
+
+    Ok(ImageAttachment {
+        path: path.to_path_buf(),
+        name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        data,
+        width: size.width as u64,
+        height: size.height as u64,
+        alt: String::new(),
+    })
+}
@@ -1,2 +1,4 @@
+pub mod editor;
+pub mod image;
 pub mod text;
 pub mod time;
@@ -1,9 +1,36 @@
 use ratatui::prelude::*;
 use ratatui::text::Line;
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::models::post::{Facet, FacetKind};
 
+/// `app.bsky.feed.post` text limits: 300 grapheme clusters and 3000 UTF-8
+/// bytes. Facets index into the same bytes, so both are checked on the exact
+/// text that gets posted.
+pub const MAX_POST_GRAPHEMES: usize = 300;
+pub const MAX_POST_BYTES: usize = 3000;
+
+/// Post length as the server measures it.
+#[derive(Debug, Clone, Copy)]
+pub struct PostLength {
+    pub graphemes: usize,
+    pub bytes: usize,
+}
+
+impl PostLength {
+    pub fn of(text: &str) -> Self {
+        PostLength {
+            graphemes: text.graphemes(true).count(),
+            bytes: text.len(),
+        }
+    }
+
+    pub fn exceeds_limit(&self) -> bool {
+        self.graphemes > MAX_POST_GRAPHEMES || self.bytes > MAX_POST_BYTES
+    }
+}
+
 /// Count how many terminal rows `text` occupies when wrapped at `max_width` columns.
 /// Uses Unicode display width so emoji and CJK characters are measured correctly.
 pub fn wrapped_line_count(text: &str, max_width: u16) -> u16 {
@@ -95,3 +122,148 @@ pub fn styled_text<'a>(text: &str, facets: &[Facet]) -> Vec<Line<'a>> {
 
     lines
 }
+
+/// Split `text` into posts that each fit the post limits, for a thread. Breaks
+/// after a sentence where possible, then at whitespace, and mid-word only as a
+/// last resort.
+pub fn split_thread(text: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut rest = text.trim();
+
+    while PostLength::of(rest).exceeds_limit() {
+        let limit = fit_limit(rest);
+        let cut = sentence_break(rest, limit)
+            .or_else(|| word_break(rest, limit))
+            .unwrap_or(limit);
+        segments.push(rest[..cut].trim_end().to_string());
+        rest = rest[cut..].trim_start();
+    }
+    if !rest.is_empty() {
+        segments.push(rest.to_string());
+    }
+    segments
+}
+
+/// Byte offset of the longest prefix of `rest` that fits, on a grapheme boundary.
+fn fit_limit(rest: &str) -> usize {
+    rest.grapheme_indices(true)
+        .map(|(i, g)| i + g.len())
+        .take(MAX_POST_GRAPHEMES)
+        .take_while(|&end| end <= MAX_POST_BYTES)
+        .last()
+        .unwrap_or_else(|| rest.graphemes(true).next().map_or(rest.len(), str::len))
+}
+
+/// Last sentence end within `rest[..limit]`, ignoring ones so early they'd
+/// leave a stub of a post.
+fn sentence_break(rest: &str, limit: usize) -> Option<usize> {
+    let mut chars = rest.char_indices().peekable();
+    let mut found = None;
+    while let Some((i, c)) = chars.next() {
+        let end = i + c.len_utf8();
+        if end > limit {
+            break;
+        }
+        let next_is_space = chars.peek().is_none_or(|(_, n)| n.is_whitespace());
+        if matches!(c, '\n' | '。' | '！' | '？')
+            || (matches!(c, '.' | '!' | '?' | '…') && next_is_space)
+        {
+            found = Some(end);
+        }
+    }
+    found.filter(|&end| end >= limit / 3)
+}
+
+fn word_break(rest: &str, limit: usize) -> Option<usize> {
+    if rest[limit..].starts_with(char::is_whitespace) {
+        return Some(limit);
+    }
+    rest[..limit].rfind(char::is_whitespace).filter(|&i| i > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+
+    fn fits(segment: &str) -> bool {
+        !PostLength::of(segment).exceeds_limit()
+    }
+
+    #[test]
+    fn post_length_counts_graphemes_and_bytes() {
+        let cjk = PostLength::of("日本語");
+        assert_eq!((cjk.graphemes, cjk.bytes), (3, 9));
+
+        let family = PostLength::of(FAMILY);
+        assert_eq!((family.graphemes, family.bytes), (1, 18));
+    }
+
+    #[test]
+    fn post_length_limit_is_inclusive() {
+        assert!(!PostLength::of(&"a".repeat(MAX_POST_GRAPHEMES)).exceeds_limit());
+        assert!(PostLength::of(&"a".repeat(MAX_POST_GRAPHEMES + 1)).exceeds_limit());
+        assert!(!PostLength::of(&"語".repeat(MAX_POST_GRAPHEMES)).exceeds_limit());
+        // 167 families fit the grapheme limit but not the byte limit
+        assert!(PostLength::of(&FAMILY.repeat(167)).exceeds_limit());
+    }
+
+    #[test]
+    fn split_leaves_short_text_alone() {
+        assert_eq!(split_thread("  hello world \n"), vec!["hello world"]);
+        assert!(split_thread("   ").is_empty());
+    }
+
+    #[test]
+    fn split_keeps_text_exactly_at_the_limit() {
+        let text = "a".repeat(MAX_POST_GRAPHEMES);
+        assert_eq!(split_thread(&text), vec![text]);
+    }
+
+    #[test]
+    fn split_prefers_sentence_ends() {
+        let first = format!("{}.", "word ".repeat(40).trim_end());
+        let second = "more words ".repeat(20);
+        let segments = split_thread(&format!("{} {}", first, second));
+        assert_eq!(segments[0], first);
+        assert!(segments.iter().all(|s| fits(s)));
+    }
+
+    #[test]
+    fn split_breaks_at_whitespace_without_sentences() {
+        let text = "word ".repeat(100);
+        let segments = split_thread(&text);
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().all(|s| fits(s) && !s.ends_with(' ')));
+        assert_eq!(segments.join(" "), text.trim_end());
+    }
+
+    #[test]
+    fn split_keeps_urls_whole() {
+        let url = "https://example.com/a/very/long/path?with=query.string";
+        let text = format!("{} {} and then some", "x".repeat(280), url);
+        let segments = split_thread(&text);
+        assert_eq!(segments.len(), 2);
+        assert!(segments[1].starts_with(url));
+    }
+
+    #[test]
+    fn split_cjk_at_full_stops() {
+        let sentence = "これは日本語の文です。";
+        let text = sentence.repeat(40);
+        let segments = split_thread(&text);
+        assert!(segments.len() > 1);
+        assert!(segments.iter().all(|s| fits(s) && s.ends_with('。')));
+        assert_eq!(segments.concat(), text);
+    }
+
+    #[test]
+    fn split_never_cuts_inside_a_grapheme() {
+        let text = FAMILY.repeat(400);
+        let segments = split_thread(&text);
+        assert!(segments.iter().all(|s| fits(s)));
+        assert!(segments.iter().all(|s| s.len() % FAMILY.len() == 0));
+        assert_eq!(segments.concat(), text);
+    }
+}